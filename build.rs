@@ -20,6 +20,7 @@ struct Features {
     pub net: bool,
     pub request: bool,
     pub ssl: bool,
+    pub websockets: bool,
 }
 
 impl Features {
@@ -33,6 +34,7 @@ impl Features {
             net: Self::env("NET"),
             request: Self::env("REQUEST"),
             ssl: Self::env("SSL"),
+            websockets: Self::env("WEBSOCKETS"),
         }
     }
 
@@ -50,6 +52,9 @@ impl Features {
         if self.ssl {
             definitions.push_str("#define CXX_QT_IO_SSL_FEATURE\n");
         }
+        if self.websockets {
+            definitions.push_str("#define CXX_QT_IO_WEBSOCKETS_FEATURE\n");
+        }
         definitions
     }
 }
@@ -94,10 +99,17 @@ fn main() {
     if features.net {
         qt_modules.push("Network".to_owned());
     }
+    if features.websockets {
+        qt_modules.push("WebSockets".to_owned());
+    }
 
     let qtbuild = QtBuild::new(qt_modules).expect("Could not find Qt installation");
     let version = qtbuild.version();
 
+    if features.websockets && !version.at_least(6, 2) {
+        panic!("The websockets feature requires Qt 6.2 or later, but found Qt {version}");
+    }
+
     let header_dir = PathBuf::from(env::var("OUT_DIR").unwrap())
         .join("include")
         .join("cxx-qt-io");
@@ -132,6 +144,7 @@ fn main() {
         include_header!("include/core/qpair/qpair_private.h"),
         include_header!("include/core/qpair/qpair_qbytearray_qbytearray.h"),
         include_header!("include/core/qpair/qpair.h"),
+        include_header!("include/core/qprocess.h"),
         include_header!("include/core/qset/qset_private.h"),
         include_header!("include/core/qset/qset.h"),
         include_header!("include/core/qvariant/qvariant.h"),
@@ -154,6 +167,7 @@ fn main() {
             "src/core/qvariant/qvariant.cpp",
         ])
         .files(&[
+            "src/core/io_device_backend.rs",
             "src/core/qbuffer.rs",
             "src/core/qdeadlinetimer.rs",
             "src/core/qhash/qhash_i32_qvariant.rs",
@@ -162,32 +176,50 @@ fn main() {
             "src/core/qlist/qlist_qpair_qbytearray_qbytearray.rs",
             "src/core/qmap/qmap_qbytearray_qvariant.rs",
             "src/core/qobject.rs",
+            "src/core/qprocess.rs",
             "src/core/qt.rs",
         ]);
 
     if features.fs {
         headers.extend_from_slice(&[
+            include_header!("include/core/qlist/qlist_qfileinfo.h"),
             include_header!("include/core/qdir.h"),
+            include_header!("include/core/qdiriterator.h"),
             include_header!("include/core/qfile.h"),
             include_header!("include/core/qfiledevice.h"),
+            include_header!("include/core/qfileinfo.h"),
+            include_header!("include/core/qfilesystemwatcher.h"),
+            include_header!("include/core/qlockfile.h"),
             include_header!("include/core/qsavefile.h"),
             include_header!("include/core/qstandardpaths.h"),
             include_header!("include/core/qtemporaryfile.h"),
         ]);
 
-        builder = builder.cpp_files(&["src/fs/qdir.cpp"]).files(&[
-            "src/fs/qdir.rs",
-            "src/fs/qfile.rs",
-            "src/fs/qfiledevice.rs",
-            "src/fs/qsavefile.rs",
-            "src/fs/qstandardpaths/mod.rs",
-            &version.find("src/fs/qstandardpaths", &[(6, 7), (6, 4)]),
-            "src/fs/qtemporaryfile.rs",
-        ]);
+        builder = builder
+            .cpp_files(&["src/fs/qdir.cpp", "src/fs/qfileinfo.cpp"])
+            .files(&[
+                "src/core/qlist/qlist_qfileinfo.rs",
+                "src/fs/qdir.rs",
+                "src/fs/qdiriterator.rs",
+                "src/fs/qfile.rs",
+                "src/fs/qfiledevice.rs",
+                "src/fs/qfileinfo.rs",
+                "src/fs/qfilesystemwatcher.rs",
+                "src/fs/qlockfile.rs",
+                "src/fs/qsavefile.rs",
+                "src/fs/qstandardpaths/mod.rs",
+                &version.find("src/fs/qstandardpaths", &[(6, 7), (6, 4)]),
+                "src/fs/qtemporaryfile.rs",
+            ]);
     }
 
     if features.net {
         headers.extend_from_slice(&[
+            include_header!("include/core/qlist/qlist_qdnsdomainnamerecord.h"),
+            include_header!("include/core/qlist/qlist_qdnshostaddressrecord.h"),
+            include_header!("include/core/qlist/qlist_qdnsmailexchangerecord.h"),
+            include_header!("include/core/qlist/qlist_qdnsservicerecord.h"),
+            include_header!("include/core/qlist/qlist_qdnstextrecord.h"),
             include_header!("include/core/qlist/qlist_qhostaddress.h"),
             include_header!("include/core/qlist/qlist_qnetworkaddressentry.h"),
             include_header!("include/core/qlist/qlist_qnetworkdatagram.h"),
@@ -197,12 +229,23 @@ fn main() {
             include_header!("include/core/qpair/qpair_qhostaddress_i32.h"),
             include_header!("include/network/qabstractsocket.h"),
             include_header!("include/network/qauthenticator.h"),
+            include_header!("include/network/qdnsdomainnamerecord.h"),
+            include_header!("include/network/qdnshostaddressrecord.h"),
+            include_header!("include/network/qdnslookup.h"),
+            include_header!("include/network/qdnsmailexchangerecord.h"),
+            include_header!("include/network/qdnsservicerecord.h"),
+            include_header!("include/network/qdnstextrecord.h"),
             include_header!("include/network/qhostaddress.h"),
+            include_header!("include/network/qhostinfo.h"),
+            include_header!("include/network/qlocalserver.h"),
             include_header!("include/network/qlocalsocket.h"),
             include_header!("include/network/qnetworkaddressentry.h"),
             include_header!("include/network/qnetworkdatagram.h"),
+            include_header!("include/network/qnetworkinformation.h"),
             include_header!("include/network/qnetworkinterface.h"),
             include_header!("include/network/qnetworkproxy.h"),
+            include_header!("include/network/qnetworkproxyfactory.h"),
+            include_header!("include/network/qnetworkproxyquery.h"),
             include_header!("include/network/qnetworkrequest.h"),
             include_header!("include/network/qtcpserver.h"),
             include_header!("include/network/qtcpsocket.h"),
@@ -212,13 +255,27 @@ fn main() {
         builder = builder
             .qt_module("Network")
             .cpp_files(&[
+                "src/net/qdnsdomainnamerecord.cpp",
+                "src/net/qdnshostaddressrecord.cpp",
+                "src/net/qdnsmailexchangerecord.cpp",
+                "src/net/qdnsservicerecord.cpp",
+                "src/net/qdnstextrecord.cpp",
                 "src/net/qhostaddress.cpp",
+                "src/net/qhostinfo.cpp",
                 "src/net/qnetworkaddressentry.cpp",
                 "src/net/qnetworkdatagram.cpp",
+                "src/net/qnetworkinformation.cpp",
                 "src/net/qnetworkinterface.cpp",
                 "src/net/qnetworkproxy.cpp",
+                "src/net/qnetworkproxyfactory.cpp",
+                "src/net/qnetworkproxyquery.cpp",
             ])
             .files(&[
+                "src/core/qlist/qlist_qdnsdomainnamerecord.rs",
+                "src/core/qlist/qlist_qdnshostaddressrecord.rs",
+                "src/core/qlist/qlist_qdnsmailexchangerecord.rs",
+                "src/core/qlist/qlist_qdnsservicerecord.rs",
+                "src/core/qlist/qlist_qdnstextrecord.rs",
                 "src/core/qlist/qlist_qhostaddress.rs",
                 "src/core/qlist/qlist_qnetworkaddressentry.rs",
                 "src/core/qlist/qlist_qnetworkdatagram.rs",
@@ -228,18 +285,36 @@ fn main() {
                 "src/net/raw_header_list.rs",
                 "src/net/qabstractsocket.rs",
                 "src/net/qauthenticator.rs",
+                "src/net/qdnsdomainnamerecord.rs",
+                "src/net/qdnshostaddressrecord.rs",
+                "src/net/qdnslookup/mod.rs",
+                &version.find("src/net/qdnslookup/error", &[(6, 6)]),
+                "src/net/qdnsmailexchangerecord.rs",
+                "src/net/qdnsservicerecord.rs",
+                "src/net/qdnstextrecord.rs",
                 "src/net/qhostaddress.rs",
+                "src/net/qhostinfo.rs",
+                "src/net/qlocalserver/mod.rs",
+                &version.find("src/net/qlocalserver/socket_option", &[(6, 2)]),
                 "src/net/qlocalsocket.rs",
                 "src/net/qnetworkaddressentry.rs",
                 "src/net/qnetworkdatagram.rs",
+                "src/net/qnetworkinformation/mod.rs",
+                &version.find("src/net/qnetworkinformation/feature", &[(6, 3), (6, 2)]),
                 "src/net/qnetworkinterface.rs",
                 "src/net/qnetworkproxy.rs",
+                "src/net/qnetworkproxyfactory.rs",
+                "src/net/qnetworkproxyquery.rs",
                 "src/net/qnetworkrequestknownheaders.rs",
                 "src/net/qtcpserver.rs",
                 "src/net/qtcpsocket.rs",
                 "src/net/qudpsocket.rs",
             ]);
 
+        if version.at_least(6, 3) {
+            builder = builder.files(&["src/net/qnetworkinformation/transport_medium.rs"]);
+        }
+
         if version.at_least(6, 7) {
             headers.extend_from_slice(&[
                 include_header!("include/core/qlist/qlist_qhttpheaders.h"),
@@ -283,6 +358,7 @@ fn main() {
                 "src/request/qhttppart.cpp",
                 "src/request/qnetworkcachemetadata.cpp",
                 "src/request/qnetworkcookie.cpp",
+                "src/request/qnetworkcookiejar.cpp",
                 "src/request/qnetworkrequest/qnetworkrequest.cpp",
             ])
             .files(&[
@@ -293,6 +369,10 @@ fn main() {
                 "src/core/qlist/qlist_qnetworkcookie.rs",
                 "src/core/qlist/qlist_qnetworkrequest.rs",
                 "src/core/qvariant/qvariant_qnetworkcookie.rs",
+                "src/request/mock_response.rs",
+                "src/request/network_access_handler.rs",
+                "src/request/network_cache_backend.rs",
+                "src/request/network_cookie_jar_policy.rs",
                 "src/request/qabstractnetworkcache.rs",
                 "src/request/qhstspolicy.rs",
                 "src/request/qhttp2configuration.rs",
@@ -325,6 +405,24 @@ fn main() {
                     "src/request/qhttp1configuration.rs",
                 ]);
         }
+
+        if version.at_least(6, 7) {
+            headers.extend_from_slice(&[
+                include_header!("include/network/qnetworkrequestfactory.h"),
+                include_header!("include/network/qrestaccessmanager.h"),
+                include_header!("include/network/qrestreply.h"),
+            ]);
+            builder = builder
+                .cpp_files(&[
+                    "src/request/qnetworkrequestfactory.cpp",
+                    "src/request/qrestaccessmanager.cpp",
+                ])
+                .files(&[
+                    "src/request/qnetworkrequestfactory.rs",
+                    "src/request/qrestaccessmanager.rs",
+                    "src/request/qrestreply.rs",
+                ]);
+        }
     }
 
     if features.ssl {
@@ -346,10 +444,12 @@ fn main() {
             include_header!("include/core/qset/qset_qssldiffiehellmanparameters.h"),
             include_header!("include/core/qset/qset_qsslellipticcurve.h"),
             include_header!("include/core/qset/qset_qsslerror.h"),
+            include_header!("include/core/qmessageauthenticationcode.h"),
             include_header!("include/network/qdtls.h"),
             include_header!("include/network/qdtlsclientverifier.h"),
             include_header!("include/network/qdtlsgeneratorparameters.h"),
             include_header!("include/network/qocspresponse.h"),
+            include_header!("include/network/qpassworddigestor.h"),
             include_header!("include/network/qssl.h"),
             include_header!("include/network/qsslcertificate.h"),
             include_header!("include/network/qsslcertificateextension.h"),
@@ -366,7 +466,9 @@ fn main() {
         builder = builder
             .qt_module("Network")
             .cpp_files(&[
+                "src/ssl/qcryptographichash.cpp",
                 "src/ssl/qdtlsgeneratorparameters.cpp",
+                "src/ssl/qmessageauthenticationcode.cpp",
                 "src/ssl/qocspresponse.cpp",
                 "src/ssl/qsslcertificate.cpp",
                 "src/ssl/qsslcertificateextension.cpp",
@@ -400,7 +502,9 @@ fn main() {
                 "src/ssl/qdtls.rs",
                 "src/ssl/qdtlsclientverifier.rs",
                 "src/ssl/qdtlsgeneratorparameters.rs",
+                "src/ssl/qmessageauthenticationcode.rs",
                 "src/ssl/qocspresponse.rs",
+                "src/ssl/qpassworddigestor.rs",
                 "src/ssl/qssl/mod.rs",
                 &version.find("src/ssl/qssl/implemented_class", &[(6, 2)]),
                 "src/ssl/qsslcertificate.rs",
@@ -421,6 +525,22 @@ fn main() {
         }
     }
 
+    if features.websockets {
+        headers.extend_from_slice(&[
+            include_header!("include/websockets/qwebsocket.h"),
+            include_header!("include/websockets/qwebsocketcorsauthenticator.h"),
+            include_header!("include/websockets/qwebsocketprotocol.h"),
+            include_header!("include/websockets/qwebsocketserver.h"),
+        ]);
+
+        builder = builder.qt_module("WebSockets").files(&[
+            "src/websockets/qwebsocket.rs",
+            "src/websockets/qwebsocketcorsauthenticator.rs",
+            "src/websockets/qwebsocketprotocol.rs",
+            "src/websockets/qwebsocketserver.rs",
+        ]);
+    }
+
     for &(file_contents, file_name) in &headers {
         let out_path = header_dir.join(file_name);
         let mut header = File::create(out_path).expect("Could not create header");
@@ -2,8 +2,26 @@
 
 #include <QtCore/QCryptographicHash>
 
+#include "rust/cxx.h"
+
 namespace rust {
 namespace cxxqtio1 {
 using QCryptographicHashAlgorithm = QCryptographicHash::Algorithm;
+
+void
+qcryptographichashAddData(QCryptographicHash& hash,
+                          ::rust::Slice<const ::std::uint8_t> data);
+
+QByteArray
+qcryptographichashHash(::rust::Slice<const ::std::uint8_t> data,
+                       QCryptographicHashAlgorithm method);
+
+inline int (*qcryptographichashHashLength)(QCryptographicHashAlgorithm) =
+  QCryptographicHash::hashLength;
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 5, 0))
+inline bool (*qcryptographichashSupportsAlgorithm)(
+  QCryptographicHashAlgorithm) = QCryptographicHash::supportsAlgorithm;
+#endif
 }
 }
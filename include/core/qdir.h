@@ -8,4 +8,11 @@ namespace rust {
 template<>
 struct IsRelocatable<QDir> : ::std::true_type
 {};
+
+namespace cxxqtio1 {
+using QDirFilter = QDir::Filter;
+using QDirFilters = QDir::Filters;
+using QDirSortFlag = QDir::SortFlag;
+using QDirSortFlags = QDir::SortFlags;
+}
 }
@@ -0,0 +1,10 @@
+#pragma once
+
+#include <QtCore/QDirIterator>
+
+namespace rust {
+namespace cxxqtio1 {
+using QDirIteratorIteratorFlag = QDirIterator::IteratorFlag;
+using QDirIteratorIteratorFlags = QDirIterator::IteratorFlags;
+}
+}
@@ -0,0 +1,15 @@
+#pragma once
+
+#include <QtCore/QFileInfo>
+
+#include "rust/cxx.h"
+
+namespace rust {
+template<>
+struct IsRelocatable<QFileInfo> : ::std::true_type
+{};
+
+namespace cxxqtio1 {
+inline bool (*qfileinfoExists)(const QString&) = QFileInfo::exists;
+}
+}
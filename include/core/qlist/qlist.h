@@ -9,11 +9,20 @@
 #include "qlist_qdeadlinetimer.h"
 #include "qlist_qpair_qbytearray_qbytearray.h"
 
+#ifdef CXX_QT_IO_FS_FEATURE
+#include "qlist_qfileinfo.h"
+#endif
+
 #ifdef CXX_QT_IO_NET_FEATURE
 #include "qlist_qhostaddress.h"
 #include "qlist_qnetworkaddressentry.h"
 #include "qlist_qnetworkproxy.h"
 
+#include "qlist_qdnsdomainnamerecord.h"
+#include "qlist_qdnshostaddressrecord.h"
+#include "qlist_qdnsmailexchangerecord.h"
+#include "qlist_qdnsservicerecord.h"
+#include "qlist_qdnstextrecord.h"
 #include "qlist_qnetworkdatagram.h"
 #include "qlist_qnetworkinterface.h"
 
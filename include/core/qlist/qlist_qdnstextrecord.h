@@ -0,0 +1,8 @@
+//! This is an auto-generated file. Do not edit.
+//! Edit instead: src/core/qlist/generate.sh
+
+#pragma once
+#include "qlist_private.h"
+#include <cxx-qt-io/qdnstextrecord.h>
+
+using QList_QDnsTextRecord = QList<QDnsTextRecord>;
@@ -0,0 +1,9 @@
+#pragma once
+
+#include <QtCore/QLockFile>
+
+namespace rust {
+namespace cxxqtio1 {
+using QLockFileLockError = QLockFile::LockError;
+}
+}
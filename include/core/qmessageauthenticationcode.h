@@ -0,0 +1,22 @@
+#pragma once
+
+#include <QtCore/QMessageAuthenticationCode>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtio1 {
+void
+qmessageauthenticationcodeAddData(QMessageAuthenticationCode& code,
+                                  ::rust::Slice<const ::std::uint8_t> data);
+
+QByteArray
+qmessageauthenticationcodeHash(::rust::Slice<const ::std::uint8_t> message,
+                               ::rust::Slice<const ::std::uint8_t> key,
+                               QCryptographicHash::Algorithm method);
+
+void
+qmessageauthenticationcodeSetKey(QMessageAuthenticationCode& code,
+                                 ::rust::Slice<const ::std::uint8_t> key);
+}
+}
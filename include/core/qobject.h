@@ -10,5 +10,8 @@ qobjectClassName(const QObject& obj);
 bool
 qobjectThreadEq(const QObject& lhs, const QObject& rhs);
 
+void
+qobjectSetParent(QObject& obj, QObject& parent);
+
 }
 }
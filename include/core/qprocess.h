@@ -0,0 +1,19 @@
+#pragma once
+
+#include <QtCore/QProcess>
+
+namespace rust {
+namespace cxxqtio1 {
+using QProcessExitStatus = QProcess::ExitStatus;
+using QProcessInputChannelMode = QProcess::InputChannelMode;
+using QProcessProcessChannel = QProcess::ProcessChannel;
+using QProcessProcessChannelMode = QProcess::ProcessChannelMode;
+using QProcessProcessError = QProcess::ProcessError;
+using QProcessProcessState = QProcess::ProcessState;
+
+inline bool (*qprocessStartDetached)(const QString&,
+                                     const QStringList&,
+                                     const QString&,
+                                     qint64*) = QProcess::startDetached;
+}
+}
@@ -0,0 +1,11 @@
+#pragma once
+
+#include <QtNetwork/QDnsLookup>
+
+#include "rust/cxx.h"
+
+namespace rust {
+template<>
+struct IsRelocatable<QDnsHostAddressRecord> : ::std::true_type
+{};
+}
@@ -0,0 +1,10 @@
+#pragma once
+
+#include <QtNetwork/QDnsLookup>
+
+namespace rust {
+namespace cxxqtio1 {
+using QDnsLookupError = QDnsLookup::Error;
+using QDnsLookupType = QDnsLookup::Type;
+}
+}
@@ -0,0 +1,11 @@
+#pragma once
+
+#include <QtNetwork/QDnsLookup>
+
+#include "rust/cxx.h"
+
+namespace rust {
+template<>
+struct IsRelocatable<QDnsServiceRecord> : ::std::true_type
+{};
+}
@@ -0,0 +1,11 @@
+#pragma once
+
+#include <QtNetwork/QDnsLookup>
+
+#include "rust/cxx.h"
+
+namespace rust {
+template<>
+struct IsRelocatable<QDnsTextRecord> : ::std::true_type
+{};
+}
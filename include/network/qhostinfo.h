@@ -0,0 +1,29 @@
+#pragma once
+
+#include <QtNetwork/QHostInfo>
+
+#include <memory>
+
+#include "rust/cxx.h"
+
+namespace rust {
+template<>
+struct IsRelocatable<QHostInfo> : ::std::true_type
+{};
+
+namespace cxxqtio1 {
+using QHostInfoHostInfoError = QHostInfo::HostInfoError;
+
+// The callback type is defined in Rust, so the invocation is resolved when the
+// template is instantiated by the generated bridge.
+template<typename T>
+int
+qhostinfoLookupHost(const QString& name, ::rust::Box<T> callback)
+{
+  auto shared = ::std::make_shared<::rust::Box<T>>(::std::move(callback));
+  return QHostInfo::lookupHost(name, [shared](const QHostInfo& info) {
+    qhostinfoLookupCallbackInvoke(**shared, info);
+  });
+}
+}
+}
@@ -0,0 +1,14 @@
+#pragma once
+
+#include <QtNetwork/QLocalServer>
+#include <QtNetwork/QLocalSocket>
+
+namespace rust {
+namespace cxxqtio1 {
+using QLocalServerSocketOption = QLocalServer::SocketOption;
+using QLocalServerSocketOptions = QLocalServer::SocketOptions;
+
+inline bool (*qlocalserverRemoveServer)(const QString&) =
+  QLocalServer::removeServer;
+}
+}
@@ -1,28 +1,15 @@
 #pragma once
 
+#include <QtCore/QIODevice>
+#include <QtCore/QPointer>
+#include <QtCore/QTimer>
 #include <QtNetwork/QNetworkAccessManager>
+#include <QtNetwork/QNetworkReply>
 
 namespace rust {
 namespace cxxqtio1 {
 using QNetworkAccessManagerOperation = QNetworkAccessManager::Operation;
 
-QNetworkReply*
-qnetworkaccessmanagerOptions(QNetworkAccessManager& manager,
-                             const QNetworkRequest& request);
-
-QNetworkReply*
-qnetworkaccessmanagerPatch(QNetworkAccessManager& manager,
-                           const QNetworkRequest& request,
-                           const QByteArray& data);
-QNetworkReply*
-qnetworkaccessmanagerPatch(QNetworkAccessManager& manager,
-                           const QNetworkRequest& request,
-                           QIODevice* data);
-QNetworkReply*
-qnetworkaccessmanagerPatch(QNetworkAccessManager& manager,
-                           const QNetworkRequest& request,
-                           QHttpMultiPart* multiPart);
-
 void
 qnetworkaccessmanagerSetTransferTimeoutMsecs(QNetworkAccessManager& manaager,
                                              ::std::int64_t timeout);
@@ -30,5 +17,34 @@ qnetworkaccessmanagerSetTransferTimeoutMsecs(QNetworkAccessManager& manaager,
 ::std::int64_t
 qnetworkaccessmanagerTransferTimeoutMsecs(const QNetworkAccessManager& manager);
 
+// The manager type is defined in Rust, so the invocation is resolved when the
+// template is instantiated by the generated bridge. The device is read once
+// control returns to the event loop and whenever it has new data. The
+// connections are removed if the reply is destroyed first.
+template<typename T>
+void
+qnetworkaccessmanagerWatchUpload(T& manager,
+                                 QNetworkReply& reply,
+                                 QIODevice& device)
+{
+  QPointer<T> target(&manager);
+  QPointer<QIODevice> source(&device);
+  QNetworkReply* receiver = &reply;
+  auto receive = [target, source, receiver](bool finished) {
+    if (target) {
+      target->receiveUpload(receiver, source.data(), finished);
+    }
+  };
+  QObject::connect(
+    &device, &QIODevice::readyRead, receiver, [receive]() { receive(false); });
+  QObject::connect(&device,
+                   &QIODevice::readChannelFinished,
+                   receiver,
+                   [receive]() { receive(true); });
+  QObject::connect(
+    &device, &QObject::destroyed, receiver, [receive]() { receive(true); });
+  QTimer::singleShot(0, receiver, [receive]() { receive(false); });
+}
+
 }
 }
@@ -1,3 +1,15 @@
 #pragma once
 
 #include <QtNetwork/QNetworkCookieJar>
+
+namespace rust {
+namespace cxxqtio1 {
+QList<QNetworkCookie>
+qnetworkcookiejarAllCookies(const QNetworkCookieJar& jar);
+
+void
+qnetworkcookiejarSetAllCookies(QNetworkCookieJar& jar,
+                               const QList<QNetworkCookie>& cookieList);
+
+}
+}
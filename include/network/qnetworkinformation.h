@@ -0,0 +1,31 @@
+#pragma once
+
+#include <QtNetwork/QNetworkInformation>
+
+namespace rust {
+namespace cxxqtio1 {
+using QNetworkInformationFeature = QNetworkInformation::Feature;
+using QNetworkInformationFeatures = QNetworkInformation::Features;
+using QNetworkInformationReachability = QNetworkInformation::Reachability;
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+using QNetworkInformationTransportMedium = QNetworkInformation::TransportMedium;
+#endif
+
+inline QStringList (*qnetworkinformationAvailableBackends)() =
+  QNetworkInformation::availableBackends;
+
+inline QNetworkInformation* (*qnetworkinformationInstance)() =
+  QNetworkInformation::instance;
+
+bool
+qnetworkinformationLoadBackendByFeatures(QNetworkInformationFeatures features);
+
+bool
+qnetworkinformationLoadBackendByName(const QString& backend);
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+inline bool (*qnetworkinformationLoadDefaultBackend)() =
+  QNetworkInformation::loadDefaultBackend;
+#endif
+}
+}
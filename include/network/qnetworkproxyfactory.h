@@ -0,0 +1,65 @@
+#pragma once
+
+#include <QtNetwork/QNetworkAccessManager>
+#include <QtNetwork/QNetworkProxyFactory>
+
+#include "rust/cxx.h"
+
+namespace rust {
+namespace cxxqtio1 {
+// The factory type is defined in Rust, so the invocation is resolved when the
+// template is instantiated by the generated bridge.
+template<typename T>
+class RustNetworkProxyFactory : public QNetworkProxyFactory
+{
+public:
+  explicit RustNetworkProxyFactory(::rust::Box<T> factory)
+    : factory(::std::move(factory))
+  {
+  }
+
+  QList<QNetworkProxy> queryProxy(
+    const QNetworkProxyQuery& query = QNetworkProxyQuery()) override
+  {
+    return networkProxyFactoryQueryProxy(*factory, query);
+  }
+
+private:
+  ::rust::Box<T> factory;
+};
+
+template<typename T>
+void
+qnetworkproxyfactorySetApplicationProxyFactory(::rust::Box<T> factory)
+{
+  QNetworkProxyFactory::setApplicationProxyFactory(
+    new RustNetworkProxyFactory<T>(::std::move(factory)));
+}
+
+template<typename T>
+void
+qnetworkaccessmanagerSetProxyFactory(QNetworkAccessManager& manager,
+                                     ::rust::Box<T> factory)
+{
+  manager.setProxyFactory(new RustNetworkProxyFactory<T>(::std::move(factory)));
+}
+
+void
+qnetworkproxyfactoryClearApplicationProxyFactory();
+
+inline QList<QNetworkProxy> (*qnetworkproxyfactoryProxyForQuery)(
+  const QNetworkProxyQuery&) = QNetworkProxyFactory::proxyForQuery;
+
+inline void (*qnetworkproxyfactorySetUseSystemConfiguration)(bool) =
+  QNetworkProxyFactory::setUseSystemConfiguration;
+
+inline QList<QNetworkProxy> (*qnetworkproxyfactorySystemProxyForQuery)(
+  const QNetworkProxyQuery&) = QNetworkProxyFactory::systemProxyForQuery;
+
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 8, 0))
+inline bool (*qnetworkproxyfactoryUsesSystemConfiguration)() =
+  QNetworkProxyFactory::usesSystemConfiguration;
+#endif
+
+}
+}
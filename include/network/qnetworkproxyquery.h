@@ -0,0 +1,15 @@
+#pragma once
+
+#include <QtNetwork/QNetworkProxyQuery>
+
+#include "rust/cxx.h"
+
+namespace rust {
+template<>
+struct IsRelocatable<QNetworkProxyQuery> : ::std::true_type
+{};
+
+namespace cxxqtio1 {
+using QNetworkProxyQueryQueryType = QNetworkProxyQuery::QueryType;
+}
+}
@@ -1,9 +1,20 @@
 #pragma once
 
+#include <QtCore/QTimer>
 #include <QtNetwork/QNetworkReply>
 
 namespace rust {
 namespace cxxqtio1 {
 using QNetworkReplyNetworkError = QNetworkReply::NetworkError;
+
+// The reply type is defined in Rust, so the invocation is resolved when the
+// template is instantiated by the generated bridge. The timer is cancelled if
+// the reply is destroyed first.
+template<typename T>
+void
+qnetworkreplyScheduleDelivery(T& reply, ::std::int32_t msecs)
+{
+  QTimer::singleShot(msecs, &reply, [&reply]() { reply.deliverResponse(); });
+}
 }
 }
@@ -0,0 +1,35 @@
+#pragma once
+
+#include <QtNetwork/QNetworkRequestFactory>
+
+#include "rust/cxx.h"
+
+namespace rust {
+template<>
+struct IsRelocatable<QNetworkRequestFactory> : ::std::true_type
+{};
+
+namespace cxxqtio1 {
+QNetworkRequest
+qnetworkrequestfactoryCreateRequest(const QNetworkRequestFactory& factory,
+                                    const QString& path,
+                                    const QString& query);
+
+QString
+qnetworkrequestfactoryQueryParameters(const QNetworkRequestFactory& factory);
+
+void
+qnetworkrequestfactorySetQueryParameters(QNetworkRequestFactory& factory,
+                                         const QString& query);
+
+void
+qnetworkrequestfactorySetTransferTimeoutMsecs(QNetworkRequestFactory& factory,
+                                              ::std::int64_t timeout);
+
+::std::int64_t
+qnetworkrequestfactoryTransferTimeoutMsecs(
+  const QNetworkRequestFactory& factory);
+
+}
+
+}
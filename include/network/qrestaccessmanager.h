@@ -0,0 +1,13 @@
+#pragma once
+
+#include <QtNetwork/QRestAccessManager>
+
+#include <memory>
+
+namespace rust {
+namespace cxxqtio1 {
+::std::unique_ptr<QRestAccessManager>
+qrestaccessmanagerNew(::std::unique_ptr<QNetworkAccessManager> manager);
+
+}
+}
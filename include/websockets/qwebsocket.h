@@ -0,0 +1,16 @@
+#pragma once
+
+#include <QtWebSockets/QWebSocket>
+
+namespace rust {
+namespace cxxqtio1 {
+inline quint64 (*qwebsocketMaxIncomingFrameSize)() =
+  QWebSocket::maxIncomingFrameSize;
+
+inline quint64 (*qwebsocketMaxIncomingMessageSize)() =
+  QWebSocket::maxIncomingMessageSize;
+
+inline quint64 (*qwebsocketMaxOutgoingFrameSize)() =
+  QWebSocket::maxOutgoingFrameSize;
+}
+}
@@ -0,0 +1,10 @@
+#pragma once
+
+#include <QtWebSockets/QWebSocketProtocol>
+
+namespace rust {
+namespace cxxqtio1 {
+using QWebSocketProtocolCloseCode = QWebSocketProtocol::CloseCode;
+using QWebSocketProtocolVersion = QWebSocketProtocol::Version;
+}
+}
@@ -0,0 +1,9 @@
+#pragma once
+
+#include <QtWebSockets/QWebSocketServer>
+
+namespace rust {
+namespace cxxqtio1 {
+using QWebSocketServerSslMode = QWebSocketServer::SslMode;
+}
+}
@@ -0,0 +1,188 @@
+use std::fmt;
+use std::io;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker, ready};
+
+use cxx_qt::QMetaObjectConnectionGuard;
+use futures_io::{AsyncRead, AsyncWrite};
+
+use crate::QIODevice;
+
+/// Number of pending bytes at which writes wait for the device to make progress.
+const WRITE_BUFFER_LIMIT: i64 = 64 * 1024;
+
+#[derive(Default)]
+struct DeviceState {
+    read_finished: bool,
+    read_waker: Option<Waker>,
+    write_waker: Option<Waker>,
+}
+
+impl DeviceState {
+    fn wake_read(&mut self) {
+        if let Some(waker) = self.read_waker.take() {
+            waker.wake();
+        }
+    }
+
+    fn wake_write(&mut self) {
+        if let Some(waker) = self.write_waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+fn register(slot: &mut Option<Waker>, waker: &Waker) {
+    match slot {
+        Some(existing) => existing.clone_from(waker),
+        None => *slot = Some(waker.clone()),
+    }
+}
+
+/// Non-blocking adapter that implements [`AsyncRead`] and [`AsyncWrite`] for a [`QIODevice`].
+///
+/// Tasks waiting on the device are woken by its [`ready_read`](QIODevice::ready_read), [`bytes_written`](QIODevice::bytes_written), [`read_channel_finished`](QIODevice::read_channel_finished), and [`about_to_close`](QIODevice::about_to_close) signals. As such, the thread that owns the device must be running a Qt event loop, or pending operations will never complete.
+///
+/// Writes are buffered by the device. Once 64 KiB or more are waiting to be written, [`poll_write`](AsyncWrite::poll_write) returns [`Poll::Pending`] until the device reports progress through [`bytes_written`](QIODevice::bytes_written).
+///
+/// Any subclass of `QIODevice` can be adapted through its `as_io_device_mut` method, for example:
+///
+/// ```ignore
+/// let mut socket = AsyncIODevice::new(tcp_socket.as_io_device_mut());
+/// socket.write_all(b"request").await?;
+/// ```
+///
+/// The signal connections are disconnected when the adapter is dropped.
+pub struct AsyncIODevice<'a> {
+    device: Pin<&'a mut QIODevice>,
+    state: Arc<Mutex<DeviceState>>,
+    _connections: [QMetaObjectConnectionGuard; 4],
+}
+
+impl<'a> AsyncIODevice<'a> {
+    /// Wraps `device`, connecting to the signals required to wake pending tasks.
+    pub fn new(mut device: Pin<&'a mut QIODevice>) -> Self {
+        let state = Arc::new(Mutex::new(DeviceState::default()));
+
+        let ready_read_state = Arc::clone(&state);
+        let ready_read = device.as_mut().on_ready_read(move |_| {
+            lock(&ready_read_state).wake_read();
+        });
+
+        let read_channel_finished_state = Arc::clone(&state);
+        let read_channel_finished = device.as_mut().on_read_channel_finished(move |_| {
+            let mut state = lock(&read_channel_finished_state);
+            state.read_finished = true;
+            state.wake_read();
+        });
+
+        let bytes_written_state = Arc::clone(&state);
+        let bytes_written = device.as_mut().on_bytes_written(move |_, _| {
+            lock(&bytes_written_state).wake_write();
+        });
+
+        let about_to_close_state = Arc::clone(&state);
+        let about_to_close = device.as_mut().on_about_to_close(move |_| {
+            let mut state = lock(&about_to_close_state);
+            state.read_finished = true;
+            state.wake_read();
+            state.wake_write();
+        });
+
+        Self {
+            device,
+            state,
+            _connections: [
+                ready_read,
+                read_channel_finished,
+                bytes_written,
+                about_to_close,
+            ],
+        }
+    }
+
+    /// Returns a reference to the underlying device.
+    pub fn get_ref(&self) -> &QIODevice {
+        &self.device
+    }
+
+    /// Returns a pinned mutable reference to the underlying device.
+    pub fn get_mut(&mut self) -> Pin<&mut QIODevice> {
+        self.device.as_mut()
+    }
+
+    /// Disconnects the adapter's signal connections and returns the underlying device.
+    pub fn into_inner(self) -> Pin<&'a mut QIODevice> {
+        self.device
+    }
+}
+
+fn lock(state: &Mutex<DeviceState>) -> MutexGuard<'_, DeviceState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl fmt::Debug for AsyncIODevice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("AsyncIODevice").field(&*self.device).finish()
+    }
+}
+
+impl AsyncRead for AsyncIODevice<'_> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if buf.is_empty() || !this.device.is_open() {
+            return Poll::Ready(Ok(0));
+        }
+        if !this.device.is_sequential() || this.device.bytes_available() > 0 {
+            return Poll::Ready(this.device.as_mut().read(buf));
+        }
+        let mut state = lock(&this.state);
+        if state.read_finished {
+            // The peer has closed its end, so report EOF rather than Qt's read error.
+            return Poll::Ready(Ok(0));
+        }
+        register(&mut state.read_waker, cx.waker());
+        Poll::Pending
+    }
+}
+
+impl AsyncWrite for AsyncIODevice<'_> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        if !buf.is_empty()
+            && this.device.is_open()
+            && this.device.bytes_to_write() >= WRITE_BUFFER_LIMIT
+        {
+            register(&mut lock(&this.state).write_waker, cx.waker());
+            return Poll::Pending;
+        }
+        Poll::Ready(this.device.as_mut().write(buf))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        let this = self.get_mut();
+        if this.device.bytes_to_write() == 0 {
+            return Poll::Ready(Ok(()));
+        }
+        if !this.device.is_open() {
+            return Poll::Ready(Err(this.device.get_error()));
+        }
+        register(&mut lock(&this.state).write_waker, cx.waker());
+        Poll::Pending
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        ready!(self.as_mut().poll_flush(cx))?;
+        self.get_mut().device.as_mut().close();
+        Poll::Ready(Ok(()))
+    }
+}
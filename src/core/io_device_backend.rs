@@ -0,0 +1,544 @@
+use std::ffi::c_char;
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::ops::Deref;
+use std::pin::Pin;
+use std::slice;
+
+use cxx::UniquePtr;
+use cxx_qt::casting::Upcast;
+use cxx_qt::{CxxQtThread, CxxQtType, Threading};
+use cxx_qt_lib::{QString, qint64};
+
+use crate::qobject::debug_qobject;
+use crate::{QIODevice, QIODeviceOpenModeFlag};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = cxx_qt_lib::qint64;
+
+        include!("cxx-qt-io/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "RustQt" {
+        /// A [`QIODevice`](crate::QIODevice) subclass that forwards reads and writes to an [`IODeviceBackend`](crate::IODeviceBackend) implemented in Rust.
+        ///
+        /// A `RustIODevice` can be passed to any Qt API that accepts a `QIODevice`, such as [`QNetworkAccessManager::put`](crate::QNetworkAccessManager::put) or [`QHttpPart::set_body_device`](crate::QHttpPart::set_body_device). Use [`RustIODevice::from_reader`] or [`RustIODevice::from_writer`] to wrap a [`Read`](std::io::Read) or [`Write`](std::io::Write) implementation.
+        ///
+        /// The device must be opened with [`QIODevice::open`](crate::QIODevice::open) before it can be used, unless it was constructed by `from_reader` or `from_writer`.
+        #[qobject]
+        #[base = QIODevice]
+        type RustIODevice = super::RustIODeviceRust;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "atEnd"]
+        fn backend_at_end(self: &RustIODevice) -> bool;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "bytesAvailable"]
+        fn backend_bytes_available(self: &RustIODevice) -> qint64;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "isSequential"]
+        fn backend_is_sequential(self: &RustIODevice) -> bool;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "readData"]
+        unsafe fn backend_read_data(
+            self: Pin<&mut RustIODevice>,
+            data: *mut c_char,
+            max_size: qint64,
+        ) -> qint64;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "seek"]
+        fn backend_seek(self: Pin<&mut RustIODevice>, pos: qint64) -> bool;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "size"]
+        fn backend_size(self: &RustIODevice) -> qint64;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "writeData"]
+        unsafe fn backend_write_data(
+            self: Pin<&mut RustIODevice>,
+            data: *const c_char,
+            max_size: qint64,
+        ) -> qint64;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "atEnd"]
+        fn base_at_end(self: &RustIODevice) -> bool;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "bytesAvailable"]
+        fn base_bytes_available(self: &RustIODevice) -> qint64;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "seek"]
+        fn base_seek(self: Pin<&mut RustIODevice>, pos: qint64) -> bool;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setErrorString"]
+        fn base_set_error_string(self: Pin<&mut RustIODevice>, str: &QString);
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "size"]
+        fn base_size(self: &RustIODevice) -> qint64;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[qsignal]
+        #[cxx_name = "readChannelFinished"]
+        fn base_read_channel_finished(self: Pin<&mut RustIODevice>);
+
+        #[doc(hidden)]
+        #[inherit]
+        #[qsignal]
+        #[cxx_name = "readyRead"]
+        fn base_ready_read(self: Pin<&mut RustIODevice>);
+    }
+
+    impl cxx_qt::Threading for RustIODevice {}
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "rustiodevice_init_default"]
+        fn make_unique() -> UniquePtr<RustIODevice>;
+    }
+}
+
+pub use ffi::RustIODevice;
+
+/// A data source or sink that can be exposed to Qt as a [`QIODevice`] through [`RustIODevice`].
+///
+/// Each method corresponds to a virtual function of `QIODevice`. By default, a backend is a sequential device that supports neither reading nor writing, so implementations only need to override the operations they support.
+pub trait IODeviceBackend: 'static {
+    /// Reads up to `buf.len()` bytes into `buf` and returns the number of bytes read.
+    ///
+    /// Return `Ok(0)` when the end of the stream has been reached, along with `true` from [`at_end`](IODeviceBackend::at_end) for sequential devices; the device then emits [`QIODevice::read_channel_finished`]. Return an error of kind [`io::ErrorKind::WouldBlock`] if no data is currently available but more may arrive later, and call [`IODeviceNotifier::ready_read`] once it does so that Qt knows to read again. Other errors are reported to Qt through [`QIODevice::error_string`](crate::QIODevice::error_string).
+    ///
+    /// The default implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let _ = buf;
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    /// Writes up to `buf.len()` bytes from `buf` and returns the number of bytes written.
+    ///
+    /// The default implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let _ = buf;
+        Err(io::ErrorKind::Unsupported.into())
+    }
+
+    /// Flushes any data buffered by the backend. This is called when the device is flushed through its [`Write`] implementation.
+    ///
+    /// The default implementation does nothing.
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Returns the number of bytes that can be read without blocking. This is added to the size of the device's internal buffer.
+    ///
+    /// The default implementation returns 0.
+    fn bytes_available(&self) -> u64 {
+        0
+    }
+
+    /// Returns `true` if no more data can be read from a sequential device, i.e. after [`read`](IODeviceBackend::read) has returned `Ok(0)`. Random-access devices are at the end when the current position reaches [`size`](IODeviceBackend::size).
+    ///
+    /// The default implementation returns `false`.
+    fn at_end(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the device is sequential, i.e. it has no concept of a current position or a size.
+    ///
+    /// The default implementation returns `true`.
+    fn is_sequential(&self) -> bool {
+        true
+    }
+
+    /// Returns the size of a random-access device. This is not called for sequential devices.
+    ///
+    /// The default implementation returns 0.
+    fn size(&self) -> u64 {
+        0
+    }
+
+    /// Moves the position of a random-access device to `pos`, so that the next call to [`read`](IODeviceBackend::read) or [`write`](IODeviceBackend::write) starts there. This is not called for sequential devices.
+    ///
+    /// The default implementation returns an error of kind [`io::ErrorKind::Unsupported`].
+    fn seek(&mut self, pos: u64) -> io::Result<()> {
+        let _ = pos;
+        Err(io::ErrorKind::Unsupported.into())
+    }
+}
+
+/// A handle for notifying Qt that new data can be read from a [`RustIODevice`], obtained from [`RustIODevice::notifier`].
+///
+/// Qt only reads from a device again after it emits [`QIODevice::ready_read`]. A backend that returns [`io::ErrorKind::WouldBlock`] from [`IODeviceBackend::read`] must therefore be paired with a notifier, which whatever produces the data uses to signal its arrival. Notifiers can be sent to other threads; the signals are emitted in the device's thread. Notifying a device that has been destroyed does nothing.
+#[derive(Clone)]
+pub struct IODeviceNotifier {
+    thread: CxxQtThread<RustIODevice>,
+}
+
+impl fmt::Debug for IODeviceNotifier {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("IODeviceNotifier").finish_non_exhaustive()
+    }
+}
+
+impl IODeviceNotifier {
+    /// Emits [`QIODevice::ready_read`] from the device, indicating that new data is available from the backend.
+    pub fn ready_read(&self) {
+        let _ = self.thread.queue(RustIODevice::base_ready_read);
+    }
+
+    /// Emits [`QIODevice::read_channel_finished`] from the device, indicating that the backend has reached the end of its stream.
+    ///
+    /// Call this after the backend has started returning `Ok(0)` from [`IODeviceBackend::read`] and `true` from [`IODeviceBackend::at_end`]. The signal is emitted at most once, so this does nothing if the device has already emitted it.
+    pub fn read_channel_finished(&self) {
+        let _ = self.thread.queue(RustIODevice::finish_read_channel);
+    }
+}
+
+struct ReaderBackend<R> {
+    reader: R,
+    at_end: bool,
+}
+
+impl<R: Read + 'static> IODeviceBackend for ReaderBackend<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.reader.read(buf)?;
+        if n == 0 && !buf.is_empty() {
+            self.at_end = true;
+        }
+        Ok(n)
+    }
+
+    fn at_end(&self) -> bool {
+        self.at_end
+    }
+}
+
+struct WriterBackend<W> {
+    writer: W,
+}
+
+impl<W: Write + 'static> IODeviceBackend for WriterBackend<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.writer.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+
+#[doc(hidden)]
+#[derive(Default)]
+pub struct RustIODeviceRust {
+    backend: Option<Box<dyn IODeviceBackend>>,
+    read_channel_finished: bool,
+}
+
+impl fmt::Debug for RustIODevice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self.as_io_device())
+    }
+}
+
+impl RustIODevice {
+    /// Constructs a device that forwards its operations to `backend`. The device must be opened before it can be used.
+    pub fn new<B: IODeviceBackend>(backend: B) -> UniquePtr<Self> {
+        let mut device = ffi::rustiodevice_init_default();
+        device.pin_mut().rust_mut().get_mut().backend = Some(Box::new(backend));
+        device
+    }
+
+    /// Constructs a sequential device that reads from `reader`, opened in [`QIODeviceOpenModeFlag::ReadOnly`] mode.
+    pub fn from_reader<R: Read + 'static>(reader: R) -> UniquePtr<Self> {
+        let mut device = Self::new(ReaderBackend {
+            reader,
+            at_end: false,
+        });
+        device
+            .pin_mut()
+            .as_io_device_mut()
+            .open(QIODeviceOpenModeFlag::ReadOnly.into());
+        device
+    }
+
+    /// Constructs a sequential device that writes to `writer`, opened in [`QIODeviceOpenModeFlag::WriteOnly`] mode.
+    ///
+    /// Writes are forwarded to `writer` as they happen, and flushing the device flushes `writer`.
+    pub fn from_writer<W: Write + 'static>(writer: W) -> UniquePtr<Self> {
+        let mut device = Self::new(WriterBackend { writer });
+        device
+            .pin_mut()
+            .as_io_device_mut()
+            .open(QIODeviceOpenModeFlag::WriteOnly.into());
+        device
+    }
+
+    /// Returns a handle for emitting [`QIODevice::ready_read`] and [`QIODevice::read_channel_finished`] from this device when data arrives at its backend.
+    pub fn notifier(&self) -> IODeviceNotifier {
+        IODeviceNotifier {
+            thread: self.qt_thread(),
+        }
+    }
+
+    /// Casts this object to `QIODevice`.
+    pub fn as_io_device(&self) -> &QIODevice {
+        self.upcast()
+    }
+
+    /// Mutably casts this object to `QIODevice`.
+    pub fn as_io_device_mut<'a>(self: &'a mut Pin<&mut Self>) -> Pin<&'a mut QIODevice> {
+        self.as_mut().upcast_pin()
+    }
+
+    fn is_backend_sequential(&self) -> bool {
+        match &self.rust().backend {
+            Some(backend) => backend.is_sequential(),
+            None => true,
+        }
+    }
+
+    fn backend_at_end(&self) -> bool {
+        if !self.base_at_end() {
+            return false;
+        }
+        match &self.rust().backend {
+            Some(backend) if backend.is_sequential() => backend.at_end(),
+            _ => true,
+        }
+    }
+
+    fn backend_bytes_available(&self) -> qint64 {
+        let available = match &self.rust().backend {
+            Some(backend) if backend.is_sequential() => backend.bytes_available(),
+            _ => 0,
+        };
+        let available = i64::try_from(available).unwrap_or(i64::MAX);
+        qint64::from(i64::from(self.base_bytes_available()).saturating_add(available))
+    }
+
+    fn backend_is_sequential(&self) -> bool {
+        self.is_backend_sequential()
+    }
+
+    unsafe fn backend_read_data(
+        mut self: Pin<&mut Self>,
+        data: *mut c_char,
+        max_size: qint64,
+    ) -> qint64 {
+        let Ok(len) = usize::try_from(i64::from(max_size)) else {
+            return qint64::from(-1);
+        };
+        let result = match &mut self.as_mut().rust_mut().get_mut().backend {
+            // SAFETY: Qt guarantees that `data` is valid for writes of `max_size` bytes.
+            Some(backend) => backend.read(unsafe { slice::from_raw_parts_mut(data.cast(), len) }),
+            None => Err(io::ErrorKind::Unsupported.into()),
+        };
+        match result {
+            Ok(0)
+                if len > 0
+                    && self
+                        .rust()
+                        .backend
+                        .as_ref()
+                        .is_some_and(|backend| backend.at_end()) =>
+            {
+                // The signal is queued so that it is not emitted from within a read.
+                let _ = self.qt_thread().queue(Self::finish_read_channel);
+                qint64::from(0)
+            }
+            Ok(n) => qint64::from(i64::try_from(n).unwrap_or(i64::MAX)),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => qint64::from(0),
+            Err(e) => {
+                self.base_set_error_string(&QString::from(&e.to_string()));
+                qint64::from(-1)
+            }
+        }
+    }
+
+    fn finish_read_channel(mut self: Pin<&mut Self>) {
+        let this = self.as_mut().rust_mut().get_mut();
+        if this.read_channel_finished {
+            return;
+        }
+        this.read_channel_finished = true;
+        self.base_read_channel_finished();
+    }
+
+    fn backend_seek(mut self: Pin<&mut Self>, pos: qint64) -> bool {
+        if self.is_backend_sequential() {
+            return self.base_seek(pos);
+        }
+        let Ok(offset) = u64::try_from(i64::from(pos)) else {
+            return false;
+        };
+        let result = match &mut self.as_mut().rust_mut().get_mut().backend {
+            Some(backend) => backend.seek(offset),
+            None => Err(io::ErrorKind::Unsupported.into()),
+        };
+        match result {
+            Ok(()) => self.base_seek(pos),
+            Err(e) => {
+                self.base_set_error_string(&QString::from(&e.to_string()));
+                false
+            }
+        }
+    }
+
+    fn backend_size(&self) -> qint64 {
+        match &self.rust().backend {
+            Some(backend) if !backend.is_sequential() => {
+                qint64::from(i64::try_from(backend.size()).unwrap_or(i64::MAX))
+            }
+            _ => self.base_size(),
+        }
+    }
+
+    unsafe fn backend_write_data(
+        mut self: Pin<&mut Self>,
+        data: *const c_char,
+        max_size: qint64,
+    ) -> qint64 {
+        let Ok(len) = usize::try_from(i64::from(max_size)) else {
+            return qint64::from(-1);
+        };
+        let result = match &mut self.as_mut().rust_mut().get_mut().backend {
+            // SAFETY: Qt guarantees that `data` is valid for reads of `max_size` bytes.
+            Some(backend) => backend.write(unsafe { slice::from_raw_parts(data.cast(), len) }),
+            None => Err(io::ErrorKind::Unsupported.into()),
+        };
+        match result {
+            Ok(n) => qint64::from(i64::try_from(n).unwrap_or(i64::MAX)),
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => qint64::from(0),
+            Err(e) => {
+                self.base_set_error_string(&QString::from(&e.to_string()));
+                qint64::from(-1)
+            }
+        }
+    }
+}
+
+impl Deref for RustIODevice {
+    type Target = QIODevice;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+impl Read for Pin<&mut RustIODevice> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_io_device_mut().read(buf)
+    }
+}
+
+impl Write for Pin<&mut RustIODevice> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_io_device_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match &mut self.as_mut().rust_mut().get_mut().backend {
+            Some(backend) => backend.flush(),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    use super::*;
+
+    #[test]
+    fn from_reader() {
+        let mut device = RustIODevice::from_reader(&b"hello world"[..]);
+        let mut data = Vec::new();
+        device.pin_mut().read_to_end(&mut data).unwrap();
+        assert_eq!(
+            (data.as_slice(), device.at_end()),
+            (&b"hello world"[..], true)
+        );
+    }
+
+    #[test]
+    fn from_writer() {
+        #[derive(Clone, Default)]
+        struct Shared(Rc<RefCell<Vec<u8>>>);
+
+        impl Write for Shared {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.borrow_mut().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let shared = Shared::default();
+        let mut device = RustIODevice::from_writer(shared.clone());
+        device.pin_mut().write_all(b"hello world").unwrap();
+        assert_eq!(shared.0.borrow().as_slice(), b"hello world");
+    }
+
+    #[test]
+    fn flush_reaches_backend() {
+        #[derive(Clone, Default)]
+        struct Flushed(Rc<Cell<bool>>);
+
+        impl Write for Flushed {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                self.0.set(true);
+                Ok(())
+            }
+        }
+
+        let flushed = Flushed::default();
+        let mut device = RustIODevice::from_writer(flushed.clone());
+        device.pin_mut().flush().unwrap();
+        assert!(flushed.0.get());
+    }
+}
@@ -1,3 +1,11 @@
+#[cfg(feature = "futures_io")]
+mod async_io_device;
+#[cfg(feature = "futures_io")]
+pub use async_io_device::AsyncIODevice;
+
+mod io_device_backend;
+pub use io_device_backend::{IODeviceBackend, IODeviceNotifier, RustIODevice};
+
 mod qbuffer;
 pub use qbuffer::QBuffer;
 
@@ -20,6 +28,12 @@ pub(crate) use qpair::QPair;
 
 pub(crate) mod qobject;
 
+mod qprocess;
+pub use qprocess::{
+    QProcess, QProcessExitStatus, QProcessInputChannelMode, QProcessProcessChannel,
+    QProcessProcessChannelMode, QProcessProcessError, QProcessProcessState,
+};
+
 mod qset;
 
 mod qt;
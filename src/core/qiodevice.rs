@@ -7,9 +7,7 @@ use std::ptr;
 use std::time::Duration;
 
 use cxx_qt::QObject;
-#[cfg(any(feature = "fs", feature = "net"))]
-use cxx_qt::casting::Downcast;
-use cxx_qt::casting::Upcast;
+use cxx_qt::casting::{Downcast, Upcast};
 use cxx_qt_lib::{QByteArray, QFlags};
 
 use crate::qobject::debug_qobject;
@@ -682,7 +680,6 @@ impl QIODevice {
         io::Error::new(self.get_error_kind(), String::from(&self.error_string()))
     }
 
-    #[allow(clippy::unused_self)]
     fn get_error_kind(&self) -> io::ErrorKind {
         #[cfg(feature = "fs")]
         if let Some(file_device) = self.downcast::<crate::QFileDevice>() {
@@ -696,6 +693,9 @@ impl QIODevice {
         if let Some(local_socket) = self.downcast::<crate::QLocalSocket>() {
             return local_socket.error().into();
         }
+        if let Some(process) = self.downcast::<crate::QProcess>() {
+            return process.error().into();
+        }
         io::ErrorKind::Other
     }
 }
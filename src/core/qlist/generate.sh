@@ -282,6 +282,8 @@ EOF
 
 generate_bridge "QDeadlineTimer"
 
+generate_bridge "QFileInfo"
+
 generate_bridge "QPair_QByteArray_QByteArray" "QPair<cxx_qt_lib::QByteArray, cxx_qt_lib::QByteArray>"
 
 generate_bridge "QHostAddress"
@@ -295,6 +297,11 @@ generate_bridge "QNetworkCookie"
 generate_bridge "QNetworkProxy"
 generate_bridge "QNetworkRequest"
 
+generate_bridge_neq "QDnsDomainNameRecord"
+generate_bridge_neq "QDnsHostAddressRecord"
+generate_bridge_neq "QDnsMailExchangeRecord"
+generate_bridge_neq "QDnsServiceRecord"
+generate_bridge_neq "QDnsTextRecord"
 generate_bridge_neq "QNetworkDatagram"
 generate_bridge_neq "QHttpHeaders"
 generate_bridge_neq "QNetworkInterface"
@@ -83,6 +83,9 @@ impl_qlist_element!(
     "QList_QDeadlineTimer"
 );
 
+#[cfg(feature = "fs")]
+impl_qlist_element!(crate::QFileInfo, qlist_qfileinfo, "QList_QFileInfo");
+
 #[cfg(all(feature = "net", cxxqt_qt_version_at_least_6_7))]
 impl_qlist_element!(
     crate::QHttpHeaders,
@@ -90,6 +93,41 @@ impl_qlist_element!(
     "QList_QHttpHeaders",
 );
 
+#[cfg(feature = "net")]
+impl_qlist_element!(
+    crate::QDnsDomainNameRecord,
+    qlist_qdnsdomainnamerecord,
+    "QList_QDnsDomainNameRecord",
+);
+
+#[cfg(feature = "net")]
+impl_qlist_element!(
+    crate::QDnsHostAddressRecord,
+    qlist_qdnshostaddressrecord,
+    "QList_QDnsHostAddressRecord",
+);
+
+#[cfg(feature = "net")]
+impl_qlist_element!(
+    crate::QDnsMailExchangeRecord,
+    qlist_qdnsmailexchangerecord,
+    "QList_QDnsMailExchangeRecord",
+);
+
+#[cfg(feature = "net")]
+impl_qlist_element!(
+    crate::QDnsServiceRecord,
+    qlist_qdnsservicerecord,
+    "QList_QDnsServiceRecord",
+);
+
+#[cfg(feature = "net")]
+impl_qlist_element!(
+    crate::QDnsTextRecord,
+    qlist_qdnstextrecord,
+    "QList_QDnsTextRecord",
+);
+
 #[cfg(feature = "net")]
 impl_qlist_element!(
     crate::QHostAddress,
@@ -24,11 +24,20 @@
 CXX_QT_IO_QLIST_ASSERTS(QDeadlineTimer);
 CXX_QT_IO_QLIST_ASSERTS(QPair_QByteArray_QByteArray);
 
+#ifdef CXX_QT_IO_FS_FEATURE
+CXX_QT_IO_QLIST_ASSERTS(QFileInfo);
+#endif
+
 #ifdef CXX_QT_IO_NET_FEATURE
 CXX_QT_IO_QLIST_ASSERTS(QHostAddress);
 CXX_QT_IO_QLIST_ASSERTS(QNetworkAddressEntry);
 CXX_QT_IO_QLIST_ASSERTS(QNetworkProxy);
 
+CXX_QT_IO_QLIST_ASSERTS(QDnsDomainNameRecord);
+CXX_QT_IO_QLIST_ASSERTS(QDnsHostAddressRecord);
+CXX_QT_IO_QLIST_ASSERTS(QDnsMailExchangeRecord);
+CXX_QT_IO_QLIST_ASSERTS(QDnsServiceRecord);
+CXX_QT_IO_QLIST_ASSERTS(QDnsTextRecord);
 CXX_QT_IO_QLIST_ASSERTS(QNetworkDatagram);
 CXX_QT_IO_QLIST_ASSERTS(QNetworkInterface);
 
@@ -0,0 +1,118 @@
+//! This is an auto-generated file. Do not edit.
+//! Edit instead: src/core/qlist/generate.sh
+
+#[cxx::bridge]
+pub mod ffi {
+    extern "C++" {
+        include!("cxx-qt-io/qdnsdomainnamerecord.h");
+        type QDnsDomainNameRecord = crate::QDnsDomainNameRecord;
+
+        include!("cxx-qt-io/qlist_qdnsdomainnamerecord.h");
+        type QList_QDnsDomainNameRecord = cxx_qt_lib::QList<QDnsDomainNameRecord>;
+    }
+
+    #[namespace = "rust::cxxqtio1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_clear_QDnsDomainNameRecord"]
+        fn qlistClear(list: &mut QList_QDnsDomainNameRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QDnsDomainNameRecord"]
+        fn construct(_: &QList_QDnsDomainNameRecord) -> QList_QDnsDomainNameRecord;
+        #[rust_name = "qlist_default_QDnsDomainNameRecord"]
+        fn construct() -> QList_QDnsDomainNameRecord;
+        #[rust_name = "qlist_drop_QDnsDomainNameRecord"]
+        fn drop(_: &mut QList_QDnsDomainNameRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_reserve_QDnsDomainNameRecord"]
+        fn qlistReserve(_: &mut QList_QDnsDomainNameRecord, size: isize);
+        #[rust_name = "qlist_append_QDnsDomainNameRecord"]
+        fn qlistAppend(_: &mut QList_QDnsDomainNameRecord, _: &QDnsDomainNameRecord);
+        #[rust_name = "qlist_get_unchecked_QDnsDomainNameRecord"]
+        unsafe fn qlistGetUnchecked(
+            set: &QList_QDnsDomainNameRecord,
+            pos: isize,
+        ) -> &QDnsDomainNameRecord;
+        #[rust_name = "qlist_insert_QDnsDomainNameRecord"]
+        fn qlistInsert(_: &mut QList_QDnsDomainNameRecord, _: isize, _: &QDnsDomainNameRecord);
+        #[rust_name = "qlist_remove_QDnsDomainNameRecord"]
+        fn qlistRemove(_: &mut QList_QDnsDomainNameRecord, _: isize);
+        #[rust_name = "qlist_len_QDnsDomainNameRecord"]
+        fn qlistLen(_: &QList_QDnsDomainNameRecord) -> isize;
+    }
+}
+
+pub(crate) fn clear(v: &mut ffi::QList_QDnsDomainNameRecord) {
+    ffi::qlist_clear_QDnsDomainNameRecord(v);
+}
+
+pub(crate) fn contains(_: &ffi::QList_QDnsDomainNameRecord, _: &ffi::QDnsDomainNameRecord) -> bool {
+    false
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QDnsDomainNameRecord, size: isize) {
+    ffi::qlist_reserve_QDnsDomainNameRecord(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QDnsDomainNameRecord, value: &ffi::QDnsDomainNameRecord) {
+    ffi::qlist_append_QDnsDomainNameRecord(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QDnsDomainNameRecord) -> ffi::QList_QDnsDomainNameRecord {
+    ffi::qlist_clone_QDnsDomainNameRecord(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QDnsDomainNameRecord {
+    ffi::qlist_default_QDnsDomainNameRecord()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QDnsDomainNameRecord) {
+    ffi::qlist_drop_QDnsDomainNameRecord(s);
+}
+
+pub(crate) unsafe fn get_unchecked(
+    s: &ffi::QList_QDnsDomainNameRecord,
+    pos: isize,
+) -> &ffi::QDnsDomainNameRecord {
+    unsafe { ffi::qlist_get_unchecked_QDnsDomainNameRecord(s, pos) }
+}
+
+pub(crate) fn index_of(
+    _: &ffi::QList_QDnsDomainNameRecord,
+    _: &ffi::QDnsDomainNameRecord,
+) -> isize {
+    -1
+}
+
+pub(crate) fn insert(
+    s: &mut ffi::QList_QDnsDomainNameRecord,
+    pos: isize,
+    value: &ffi::QDnsDomainNameRecord,
+) {
+    ffi::qlist_insert_QDnsDomainNameRecord(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QDnsDomainNameRecord) -> isize {
+    ffi::qlist_len_QDnsDomainNameRecord(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QDnsDomainNameRecord, pos: isize) {
+    ffi::qlist_remove_QDnsDomainNameRecord(s, pos);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn len() {
+        let empty = super::default();
+        assert_eq!(super::len(&empty), 0);
+        std::mem::drop(empty);
+    }
+}
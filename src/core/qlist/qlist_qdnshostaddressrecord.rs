@@ -0,0 +1,121 @@
+//! This is an auto-generated file. Do not edit.
+//! Edit instead: src/core/qlist/generate.sh
+
+#[cxx::bridge]
+pub mod ffi {
+    extern "C++" {
+        include!("cxx-qt-io/qdnshostaddressrecord.h");
+        type QDnsHostAddressRecord = crate::QDnsHostAddressRecord;
+
+        include!("cxx-qt-io/qlist_qdnshostaddressrecord.h");
+        type QList_QDnsHostAddressRecord = cxx_qt_lib::QList<QDnsHostAddressRecord>;
+    }
+
+    #[namespace = "rust::cxxqtio1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_clear_QDnsHostAddressRecord"]
+        fn qlistClear(list: &mut QList_QDnsHostAddressRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QDnsHostAddressRecord"]
+        fn construct(_: &QList_QDnsHostAddressRecord) -> QList_QDnsHostAddressRecord;
+        #[rust_name = "qlist_default_QDnsHostAddressRecord"]
+        fn construct() -> QList_QDnsHostAddressRecord;
+        #[rust_name = "qlist_drop_QDnsHostAddressRecord"]
+        fn drop(_: &mut QList_QDnsHostAddressRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_reserve_QDnsHostAddressRecord"]
+        fn qlistReserve(_: &mut QList_QDnsHostAddressRecord, size: isize);
+        #[rust_name = "qlist_append_QDnsHostAddressRecord"]
+        fn qlistAppend(_: &mut QList_QDnsHostAddressRecord, _: &QDnsHostAddressRecord);
+        #[rust_name = "qlist_get_unchecked_QDnsHostAddressRecord"]
+        unsafe fn qlistGetUnchecked(
+            set: &QList_QDnsHostAddressRecord,
+            pos: isize,
+        ) -> &QDnsHostAddressRecord;
+        #[rust_name = "qlist_insert_QDnsHostAddressRecord"]
+        fn qlistInsert(_: &mut QList_QDnsHostAddressRecord, _: isize, _: &QDnsHostAddressRecord);
+        #[rust_name = "qlist_remove_QDnsHostAddressRecord"]
+        fn qlistRemove(_: &mut QList_QDnsHostAddressRecord, _: isize);
+        #[rust_name = "qlist_len_QDnsHostAddressRecord"]
+        fn qlistLen(_: &QList_QDnsHostAddressRecord) -> isize;
+    }
+}
+
+pub(crate) fn clear(v: &mut ffi::QList_QDnsHostAddressRecord) {
+    ffi::qlist_clear_QDnsHostAddressRecord(v);
+}
+
+pub(crate) fn contains(
+    _: &ffi::QList_QDnsHostAddressRecord,
+    _: &ffi::QDnsHostAddressRecord,
+) -> bool {
+    false
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QDnsHostAddressRecord, size: isize) {
+    ffi::qlist_reserve_QDnsHostAddressRecord(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QDnsHostAddressRecord, value: &ffi::QDnsHostAddressRecord) {
+    ffi::qlist_append_QDnsHostAddressRecord(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QDnsHostAddressRecord) -> ffi::QList_QDnsHostAddressRecord {
+    ffi::qlist_clone_QDnsHostAddressRecord(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QDnsHostAddressRecord {
+    ffi::qlist_default_QDnsHostAddressRecord()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QDnsHostAddressRecord) {
+    ffi::qlist_drop_QDnsHostAddressRecord(s);
+}
+
+pub(crate) unsafe fn get_unchecked(
+    s: &ffi::QList_QDnsHostAddressRecord,
+    pos: isize,
+) -> &ffi::QDnsHostAddressRecord {
+    unsafe { ffi::qlist_get_unchecked_QDnsHostAddressRecord(s, pos) }
+}
+
+pub(crate) fn index_of(
+    _: &ffi::QList_QDnsHostAddressRecord,
+    _: &ffi::QDnsHostAddressRecord,
+) -> isize {
+    -1
+}
+
+pub(crate) fn insert(
+    s: &mut ffi::QList_QDnsHostAddressRecord,
+    pos: isize,
+    value: &ffi::QDnsHostAddressRecord,
+) {
+    ffi::qlist_insert_QDnsHostAddressRecord(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QDnsHostAddressRecord) -> isize {
+    ffi::qlist_len_QDnsHostAddressRecord(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QDnsHostAddressRecord, pos: isize) {
+    ffi::qlist_remove_QDnsHostAddressRecord(s, pos);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn len() {
+        let empty = super::default();
+        assert_eq!(super::len(&empty), 0);
+        std::mem::drop(empty);
+    }
+}
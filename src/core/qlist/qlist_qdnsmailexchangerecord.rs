@@ -0,0 +1,124 @@
+//! This is an auto-generated file. Do not edit.
+//! Edit instead: src/core/qlist/generate.sh
+
+#[cxx::bridge]
+pub mod ffi {
+    extern "C++" {
+        include!("cxx-qt-io/qdnsmailexchangerecord.h");
+        type QDnsMailExchangeRecord = crate::QDnsMailExchangeRecord;
+
+        include!("cxx-qt-io/qlist_qdnsmailexchangerecord.h");
+        type QList_QDnsMailExchangeRecord = cxx_qt_lib::QList<QDnsMailExchangeRecord>;
+    }
+
+    #[namespace = "rust::cxxqtio1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_clear_QDnsMailExchangeRecord"]
+        fn qlistClear(list: &mut QList_QDnsMailExchangeRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QDnsMailExchangeRecord"]
+        fn construct(_: &QList_QDnsMailExchangeRecord) -> QList_QDnsMailExchangeRecord;
+        #[rust_name = "qlist_default_QDnsMailExchangeRecord"]
+        fn construct() -> QList_QDnsMailExchangeRecord;
+        #[rust_name = "qlist_drop_QDnsMailExchangeRecord"]
+        fn drop(_: &mut QList_QDnsMailExchangeRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_reserve_QDnsMailExchangeRecord"]
+        fn qlistReserve(_: &mut QList_QDnsMailExchangeRecord, size: isize);
+        #[rust_name = "qlist_append_QDnsMailExchangeRecord"]
+        fn qlistAppend(_: &mut QList_QDnsMailExchangeRecord, _: &QDnsMailExchangeRecord);
+        #[rust_name = "qlist_get_unchecked_QDnsMailExchangeRecord"]
+        unsafe fn qlistGetUnchecked(
+            set: &QList_QDnsMailExchangeRecord,
+            pos: isize,
+        ) -> &QDnsMailExchangeRecord;
+        #[rust_name = "qlist_insert_QDnsMailExchangeRecord"]
+        fn qlistInsert(_: &mut QList_QDnsMailExchangeRecord, _: isize, _: &QDnsMailExchangeRecord);
+        #[rust_name = "qlist_remove_QDnsMailExchangeRecord"]
+        fn qlistRemove(_: &mut QList_QDnsMailExchangeRecord, _: isize);
+        #[rust_name = "qlist_len_QDnsMailExchangeRecord"]
+        fn qlistLen(_: &QList_QDnsMailExchangeRecord) -> isize;
+    }
+}
+
+pub(crate) fn clear(v: &mut ffi::QList_QDnsMailExchangeRecord) {
+    ffi::qlist_clear_QDnsMailExchangeRecord(v);
+}
+
+pub(crate) fn contains(
+    _: &ffi::QList_QDnsMailExchangeRecord,
+    _: &ffi::QDnsMailExchangeRecord,
+) -> bool {
+    false
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QDnsMailExchangeRecord, size: isize) {
+    ffi::qlist_reserve_QDnsMailExchangeRecord(v, size);
+}
+
+pub(crate) fn append(
+    v: &mut ffi::QList_QDnsMailExchangeRecord,
+    value: &ffi::QDnsMailExchangeRecord,
+) {
+    ffi::qlist_append_QDnsMailExchangeRecord(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QDnsMailExchangeRecord) -> ffi::QList_QDnsMailExchangeRecord {
+    ffi::qlist_clone_QDnsMailExchangeRecord(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QDnsMailExchangeRecord {
+    ffi::qlist_default_QDnsMailExchangeRecord()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QDnsMailExchangeRecord) {
+    ffi::qlist_drop_QDnsMailExchangeRecord(s);
+}
+
+pub(crate) unsafe fn get_unchecked(
+    s: &ffi::QList_QDnsMailExchangeRecord,
+    pos: isize,
+) -> &ffi::QDnsMailExchangeRecord {
+    unsafe { ffi::qlist_get_unchecked_QDnsMailExchangeRecord(s, pos) }
+}
+
+pub(crate) fn index_of(
+    _: &ffi::QList_QDnsMailExchangeRecord,
+    _: &ffi::QDnsMailExchangeRecord,
+) -> isize {
+    -1
+}
+
+pub(crate) fn insert(
+    s: &mut ffi::QList_QDnsMailExchangeRecord,
+    pos: isize,
+    value: &ffi::QDnsMailExchangeRecord,
+) {
+    ffi::qlist_insert_QDnsMailExchangeRecord(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QDnsMailExchangeRecord) -> isize {
+    ffi::qlist_len_QDnsMailExchangeRecord(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QDnsMailExchangeRecord, pos: isize) {
+    ffi::qlist_remove_QDnsMailExchangeRecord(s, pos);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn len() {
+        let empty = super::default();
+        assert_eq!(super::len(&empty), 0);
+        std::mem::drop(empty);
+    }
+}
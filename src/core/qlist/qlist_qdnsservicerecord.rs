@@ -0,0 +1,115 @@
+//! This is an auto-generated file. Do not edit.
+//! Edit instead: src/core/qlist/generate.sh
+
+#[cxx::bridge]
+pub mod ffi {
+    extern "C++" {
+        include!("cxx-qt-io/qdnsservicerecord.h");
+        type QDnsServiceRecord = crate::QDnsServiceRecord;
+
+        include!("cxx-qt-io/qlist_qdnsservicerecord.h");
+        type QList_QDnsServiceRecord = cxx_qt_lib::QList<QDnsServiceRecord>;
+    }
+
+    #[namespace = "rust::cxxqtio1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_clear_QDnsServiceRecord"]
+        fn qlistClear(list: &mut QList_QDnsServiceRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QDnsServiceRecord"]
+        fn construct(_: &QList_QDnsServiceRecord) -> QList_QDnsServiceRecord;
+        #[rust_name = "qlist_default_QDnsServiceRecord"]
+        fn construct() -> QList_QDnsServiceRecord;
+        #[rust_name = "qlist_drop_QDnsServiceRecord"]
+        fn drop(_: &mut QList_QDnsServiceRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_reserve_QDnsServiceRecord"]
+        fn qlistReserve(_: &mut QList_QDnsServiceRecord, size: isize);
+        #[rust_name = "qlist_append_QDnsServiceRecord"]
+        fn qlistAppend(_: &mut QList_QDnsServiceRecord, _: &QDnsServiceRecord);
+        #[rust_name = "qlist_get_unchecked_QDnsServiceRecord"]
+        unsafe fn qlistGetUnchecked(
+            set: &QList_QDnsServiceRecord,
+            pos: isize,
+        ) -> &QDnsServiceRecord;
+        #[rust_name = "qlist_insert_QDnsServiceRecord"]
+        fn qlistInsert(_: &mut QList_QDnsServiceRecord, _: isize, _: &QDnsServiceRecord);
+        #[rust_name = "qlist_remove_QDnsServiceRecord"]
+        fn qlistRemove(_: &mut QList_QDnsServiceRecord, _: isize);
+        #[rust_name = "qlist_len_QDnsServiceRecord"]
+        fn qlistLen(_: &QList_QDnsServiceRecord) -> isize;
+    }
+}
+
+pub(crate) fn clear(v: &mut ffi::QList_QDnsServiceRecord) {
+    ffi::qlist_clear_QDnsServiceRecord(v);
+}
+
+pub(crate) fn contains(_: &ffi::QList_QDnsServiceRecord, _: &ffi::QDnsServiceRecord) -> bool {
+    false
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QDnsServiceRecord, size: isize) {
+    ffi::qlist_reserve_QDnsServiceRecord(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QDnsServiceRecord, value: &ffi::QDnsServiceRecord) {
+    ffi::qlist_append_QDnsServiceRecord(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QDnsServiceRecord) -> ffi::QList_QDnsServiceRecord {
+    ffi::qlist_clone_QDnsServiceRecord(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QDnsServiceRecord {
+    ffi::qlist_default_QDnsServiceRecord()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QDnsServiceRecord) {
+    ffi::qlist_drop_QDnsServiceRecord(s);
+}
+
+pub(crate) unsafe fn get_unchecked(
+    s: &ffi::QList_QDnsServiceRecord,
+    pos: isize,
+) -> &ffi::QDnsServiceRecord {
+    unsafe { ffi::qlist_get_unchecked_QDnsServiceRecord(s, pos) }
+}
+
+pub(crate) fn index_of(_: &ffi::QList_QDnsServiceRecord, _: &ffi::QDnsServiceRecord) -> isize {
+    -1
+}
+
+pub(crate) fn insert(
+    s: &mut ffi::QList_QDnsServiceRecord,
+    pos: isize,
+    value: &ffi::QDnsServiceRecord,
+) {
+    ffi::qlist_insert_QDnsServiceRecord(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QDnsServiceRecord) -> isize {
+    ffi::qlist_len_QDnsServiceRecord(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QDnsServiceRecord, pos: isize) {
+    ffi::qlist_remove_QDnsServiceRecord(s, pos);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn len() {
+        let empty = super::default();
+        assert_eq!(super::len(&empty), 0);
+        std::mem::drop(empty);
+    }
+}
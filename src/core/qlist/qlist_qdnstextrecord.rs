@@ -0,0 +1,108 @@
+//! This is an auto-generated file. Do not edit.
+//! Edit instead: src/core/qlist/generate.sh
+
+#[cxx::bridge]
+pub mod ffi {
+    extern "C++" {
+        include!("cxx-qt-io/qdnstextrecord.h");
+        type QDnsTextRecord = crate::QDnsTextRecord;
+
+        include!("cxx-qt-io/qlist_qdnstextrecord.h");
+        type QList_QDnsTextRecord = cxx_qt_lib::QList<QDnsTextRecord>;
+    }
+
+    #[namespace = "rust::cxxqtio1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_clear_QDnsTextRecord"]
+        fn qlistClear(list: &mut QList_QDnsTextRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QDnsTextRecord"]
+        fn construct(_: &QList_QDnsTextRecord) -> QList_QDnsTextRecord;
+        #[rust_name = "qlist_default_QDnsTextRecord"]
+        fn construct() -> QList_QDnsTextRecord;
+        #[rust_name = "qlist_drop_QDnsTextRecord"]
+        fn drop(_: &mut QList_QDnsTextRecord);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_reserve_QDnsTextRecord"]
+        fn qlistReserve(_: &mut QList_QDnsTextRecord, size: isize);
+        #[rust_name = "qlist_append_QDnsTextRecord"]
+        fn qlistAppend(_: &mut QList_QDnsTextRecord, _: &QDnsTextRecord);
+        #[rust_name = "qlist_get_unchecked_QDnsTextRecord"]
+        unsafe fn qlistGetUnchecked(set: &QList_QDnsTextRecord, pos: isize) -> &QDnsTextRecord;
+        #[rust_name = "qlist_insert_QDnsTextRecord"]
+        fn qlistInsert(_: &mut QList_QDnsTextRecord, _: isize, _: &QDnsTextRecord);
+        #[rust_name = "qlist_remove_QDnsTextRecord"]
+        fn qlistRemove(_: &mut QList_QDnsTextRecord, _: isize);
+        #[rust_name = "qlist_len_QDnsTextRecord"]
+        fn qlistLen(_: &QList_QDnsTextRecord) -> isize;
+    }
+}
+
+pub(crate) fn clear(v: &mut ffi::QList_QDnsTextRecord) {
+    ffi::qlist_clear_QDnsTextRecord(v);
+}
+
+pub(crate) fn contains(_: &ffi::QList_QDnsTextRecord, _: &ffi::QDnsTextRecord) -> bool {
+    false
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QDnsTextRecord, size: isize) {
+    ffi::qlist_reserve_QDnsTextRecord(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QDnsTextRecord, value: &ffi::QDnsTextRecord) {
+    ffi::qlist_append_QDnsTextRecord(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QDnsTextRecord) -> ffi::QList_QDnsTextRecord {
+    ffi::qlist_clone_QDnsTextRecord(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QDnsTextRecord {
+    ffi::qlist_default_QDnsTextRecord()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QDnsTextRecord) {
+    ffi::qlist_drop_QDnsTextRecord(s);
+}
+
+pub(crate) unsafe fn get_unchecked(
+    s: &ffi::QList_QDnsTextRecord,
+    pos: isize,
+) -> &ffi::QDnsTextRecord {
+    unsafe { ffi::qlist_get_unchecked_QDnsTextRecord(s, pos) }
+}
+
+pub(crate) fn index_of(_: &ffi::QList_QDnsTextRecord, _: &ffi::QDnsTextRecord) -> isize {
+    -1
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QDnsTextRecord, pos: isize, value: &ffi::QDnsTextRecord) {
+    ffi::qlist_insert_QDnsTextRecord(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QDnsTextRecord) -> isize {
+    ffi::qlist_len_QDnsTextRecord(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QDnsTextRecord, pos: isize) {
+    ffi::qlist_remove_QDnsTextRecord(s, pos);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn len() {
+        let empty = super::default();
+        assert_eq!(super::len(&empty), 0);
+        std::mem::drop(empty);
+    }
+}
@@ -0,0 +1,109 @@
+//! This is an auto-generated file. Do not edit.
+//! Edit instead: src/core/qlist/generate.sh
+
+#[cxx::bridge]
+pub mod ffi {
+    extern "C++" {
+        include!("cxx-qt-io/qfileinfo.h");
+        type QFileInfo = crate::QFileInfo;
+
+        include!("cxx-qt-io/qlist_qfileinfo.h");
+        type QList_QFileInfo = cxx_qt_lib::QList<QFileInfo>;
+    }
+
+    #[namespace = "rust::cxxqtio1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_clear_QFileInfo"]
+        fn qlistClear(list: &mut QList_QFileInfo);
+        #[rust_name = "qlist_contains_QFileInfo"]
+        fn qlistContains(list: &QList_QFileInfo, _: &QFileInfo) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlist_clone_QFileInfo"]
+        fn construct(_: &QList_QFileInfo) -> QList_QFileInfo;
+        #[rust_name = "qlist_default_QFileInfo"]
+        fn construct() -> QList_QFileInfo;
+        #[rust_name = "qlist_drop_QFileInfo"]
+        fn drop(_: &mut QList_QFileInfo);
+    }
+
+    #[namespace = "rust::cxxqtlib1::qlist"]
+    unsafe extern "C++" {
+        #[rust_name = "qlist_reserve_QFileInfo"]
+        fn qlistReserve(_: &mut QList_QFileInfo, size: isize);
+        #[rust_name = "qlist_append_QFileInfo"]
+        fn qlistAppend(_: &mut QList_QFileInfo, _: &QFileInfo);
+        #[rust_name = "qlist_get_unchecked_QFileInfo"]
+        unsafe fn qlistGetUnchecked(set: &QList_QFileInfo, pos: isize) -> &QFileInfo;
+        #[rust_name = "qlist_index_of_QFileInfo"]
+        fn qlistIndexOf(_: &QList_QFileInfo, _: &QFileInfo) -> isize;
+        #[rust_name = "qlist_insert_QFileInfo"]
+        fn qlistInsert(_: &mut QList_QFileInfo, _: isize, _: &QFileInfo);
+        #[rust_name = "qlist_remove_QFileInfo"]
+        fn qlistRemove(_: &mut QList_QFileInfo, _: isize);
+        #[rust_name = "qlist_len_QFileInfo"]
+        fn qlistLen(_: &QList_QFileInfo) -> isize;
+    }
+}
+
+pub(crate) fn clear(v: &mut ffi::QList_QFileInfo) {
+    ffi::qlist_clear_QFileInfo(v);
+}
+
+pub(crate) fn contains(v: &ffi::QList_QFileInfo, item: &ffi::QFileInfo) -> bool {
+    ffi::qlist_contains_QFileInfo(v, item)
+}
+
+pub(crate) fn reserve(v: &mut ffi::QList_QFileInfo, size: isize) {
+    ffi::qlist_reserve_QFileInfo(v, size);
+}
+
+pub(crate) fn append(v: &mut ffi::QList_QFileInfo, value: &ffi::QFileInfo) {
+    ffi::qlist_append_QFileInfo(v, value);
+}
+
+pub(crate) fn clone(s: &ffi::QList_QFileInfo) -> ffi::QList_QFileInfo {
+    ffi::qlist_clone_QFileInfo(s)
+}
+
+pub(crate) fn default() -> ffi::QList_QFileInfo {
+    ffi::qlist_default_QFileInfo()
+}
+
+pub(crate) fn drop(s: &mut ffi::QList_QFileInfo) {
+    ffi::qlist_drop_QFileInfo(s);
+}
+
+pub(crate) unsafe fn get_unchecked(s: &ffi::QList_QFileInfo, pos: isize) -> &ffi::QFileInfo {
+    unsafe { ffi::qlist_get_unchecked_QFileInfo(s, pos) }
+}
+
+pub(crate) fn index_of(v: &ffi::QList_QFileInfo, value: &ffi::QFileInfo) -> isize {
+    ffi::qlist_index_of_QFileInfo(v, value)
+}
+
+pub(crate) fn insert(s: &mut ffi::QList_QFileInfo, pos: isize, value: &ffi::QFileInfo) {
+    ffi::qlist_insert_QFileInfo(s, pos, value);
+}
+
+pub(crate) fn len(s: &ffi::QList_QFileInfo) -> isize {
+    ffi::qlist_len_QFileInfo(s)
+}
+
+pub(crate) fn remove(s: &mut ffi::QList_QFileInfo, pos: isize) {
+    ffi::qlist_remove_QFileInfo(s, pos);
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn len() {
+        let empty = super::default();
+        assert_eq!(super::len(&empty), 0);
+        std::mem::drop(empty);
+    }
+}
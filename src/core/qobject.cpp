@@ -15,5 +15,11 @@ qobjectThreadEq(const QObject& lhs, const QObject& rhs)
   return lhs.thread() == rhs.thread();
 }
 
+void
+qobjectSetParent(QObject& obj, QObject& parent)
+{
+  obj.setParent(&parent);
+}
+
 }
 }
@@ -1,6 +1,7 @@
 #![allow(dead_code)]
 use std::ffi::CStr;
 use std::fmt;
+use std::pin::Pin;
 use std::ptr;
 
 use cxx_qt::QObject;
@@ -18,6 +19,9 @@ mod ffi {
         #[rust_name = "qobject_class_name"]
         fn qobjectClassName(obj: &QObject) -> *const c_char;
 
+        #[rust_name = "qobject_set_parent"]
+        fn qobjectSetParent(obj: Pin<&mut QObject>, parent: Pin<&mut QObject>);
+
         #[rust_name = "qobject_thread_eq"]
         fn qobjectThreadEq(lhs: &QObject, rhs: &QObject) -> bool;
     }
@@ -55,3 +59,12 @@ where
 {
     ffi::qobject_thread_eq(lhs.upcast(), rhs.upcast())
 }
+
+/// Makes `parent` the parent of `obj`, so that `obj` is destroyed along with `parent`.
+pub(crate) fn set_parent<T, P>(obj: Pin<&mut T>, parent: Pin<&mut P>)
+where
+    T: Upcast<QObject>,
+    P: Upcast<QObject>,
+{
+    ffi::qobject_set_parent(obj.upcast_pin(), parent.upcast_pin());
+}
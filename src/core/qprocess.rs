@@ -0,0 +1,630 @@
+use std::fmt;
+use std::io::{self, Read, Write};
+use std::ops::Deref;
+use std::pin::Pin;
+use std::time::Duration;
+
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QString, QStringList, qint64};
+
+use crate::QIODevice;
+use crate::qobject::debug_qobject;
+use crate::util::MSecs;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// This enum describes the different exit statuses of [`QProcess`].
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QProcessExitStatus {
+        /// The process exited normally.
+        NormalExit,
+        /// The process crashed.
+        CrashExit,
+    }
+
+    /// This enum describes the process input channel modes of [`QProcess`]. Pass one of these values to [`QProcess::set_input_channel_mode`] to set the current write channel mode.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QProcessInputChannelMode {
+        /// `QProcess` manages the input of the running process. This is the default input channel mode of `QProcess`.
+        ManagedInputChannel,
+        /// `QProcess` forwards the input of the main process onto the running process. The child process reads its standard input from the same source as the main process. Note that the main process must not try to read its standard input while the child process is running.
+        ForwardedInputChannel,
+    }
+
+    /// This enum describes the process channels used by the running process. Pass one of these values to [`QProcess::set_read_channel`] to set the current read channel of [`QProcess`].
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QProcessProcessChannel {
+        /// The standard output (stdout) of the running process.
+        StandardOutput,
+        /// The standard error (stderr) of the running process.
+        StandardError,
+    }
+
+    /// This enum describes the process output channel modes of [`QProcess`]. Pass one of these values to [`QProcess::set_process_channel_mode`] to set the current read channel mode.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QProcessProcessChannelMode {
+        /// `QProcess` manages the output of the running process, keeping standard output and standard error data in separate internal buffers. You can select the `QProcess`'s current read channel by calling [`QProcess::set_read_channel`]. This is the default channel mode of `QProcess`.
+        SeparateChannels,
+        /// `QProcess` merges the output of the running process into the standard output channel (stdout). The standard error channel (stderr) will not receive any data. The standard output and standard error data of the running process are interleaved.
+        MergedChannels,
+        /// `QProcess` forwards the output of the running process onto the main process. Anything the child process writes to its standard output and standard error will be written to the standard output and standard error of the main process.
+        ForwardedChannels,
+        /// `QProcess` forwards the output of the running process onto the main process. Anything the child process writes to its standard output will be written to the standard output of the main process. Standard error is read by `QProcess`.
+        ForwardedOutputChannel,
+        /// `QProcess` manages the standard output of the running process, but forwards its standard error onto the main process. This reflects the typical use of command line tools as filters, where the standard output is redirected to another process or a file, while standard error is printed to the console for diagnostic purposes.
+        ForwardedErrorChannel,
+    }
+
+    /// This enum describes the different types of errors that are reported by [`QProcess`].
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QProcessProcessError {
+        /// The process failed to start. Either the invoked program is missing, or you may have insufficient permissions or resources to invoke the program.
+        FailedToStart,
+        /// The process crashed some time after starting successfully.
+        Crashed,
+        /// The last `wait_for...` function timed out. The state of `QProcess` is unchanged, and you can try calling `wait_for...` again.
+        Timedout,
+        /// An error occurred when attempting to write to the process. For example, the process may not be running, or it may have closed its input channel.
+        WriteError,
+        /// An error occurred when attempting to read from the process. For example, the process may not be running.
+        ReadError,
+        /// An unknown error occurred.
+        UnknownError,
+    }
+
+    /// This enum describes the different states of [`QProcess`].
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QProcessProcessState {
+        /// The process is not running.
+        NotRunning,
+        /// The process is starting, but the program has not yet been invoked.
+        Starting,
+        /// The process is running and is ready for reading and writing.
+        Running,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = cxx_qt_lib::qint64;
+
+        include!("cxx-qt-io/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        type QIODeviceOpenMode = crate::QIODeviceOpenMode;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qprocess.h");
+        type QProcessExitStatus;
+        type QProcessInputChannelMode;
+        type QProcessProcessChannel;
+        type QProcessProcessChannelMode;
+        type QProcessProcessError;
+        type QProcessProcessState;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QProcess` class is used to start external programs and to communicate with them.
+        ///
+        /// Qt Documentation: [QProcess](https://doc.qt.io/qt-6/qprocess.html#details)
+        #[qobject]
+        #[base = QIODevice]
+        type QProcess;
+
+        /// Returns the command line arguments the process was last started with.
+        fn arguments(self: &QProcess) -> QStringList;
+
+        /// Closes the read channel `channel`. After calling this function, `QProcess` will no longer receive data on the channel. Any data that has already been received is still available for reading.
+        ///
+        /// Call this function to save memory, if you are not interested in the output of the process.
+        #[rust_name = "close_read_channel"]
+        fn closeReadChannel(self: Pin<&mut QProcess>, channel: QProcessProcessChannel);
+
+        /// Schedules the write channel of `QProcess` to be closed. The channel will close once all data has been written to the process. After calling this function, any attempts to write to the process will fail.
+        ///
+        /// Closing the write channel is necessary for programs that read input data until the channel has been closed.
+        #[rust_name = "close_write_channel"]
+        fn closeWriteChannel(self: Pin<&mut QProcess>);
+
+        /// Returns the environment that `QProcess` will pass to its child process. If no environment has been set using [`set_environment`](QProcess::set_environment), this method returns an empty list, and the environment of the calling process is used.
+        ///
+        /// The environment is a list of key=value pairs.
+        fn environment(self: &QProcess) -> QStringList;
+
+        /// Returns the type of error that occurred last.
+        fn error(self: &QProcess) -> QProcessProcessError;
+
+        /// Returns the exit code of the last process that finished.
+        ///
+        /// This value is not valid unless [`exit_status`](QProcess::exit_status) returns [`QProcessExitStatus::NormalExit`].
+        #[rust_name = "exit_code"]
+        fn exitCode(self: &QProcess) -> i32;
+
+        /// Returns the exit status of the last process that finished.
+        ///
+        /// On Windows, if the process was terminated with `TerminateProcess()` from another application, this function will still return [`QProcessExitStatus::NormalExit`] unless the exit code is less than 0.
+        #[rust_name = "exit_status"]
+        fn exitStatus(self: &QProcess) -> QProcessExitStatus;
+
+        /// Returns the channel mode of the `QProcess` standard input channel.
+        #[rust_name = "input_channel_mode"]
+        fn inputChannelMode(self: &QProcess) -> QProcessInputChannelMode;
+
+        /// Kills the current process, causing it to exit immediately.
+        ///
+        /// On Windows, `kill` uses `TerminateProcess`, and on Unix and macOS, the `SIGKILL` signal is sent to the process.
+        fn kill(self: Pin<&mut QProcess>);
+
+        /// Returns the channel mode of the `QProcess` standard output and standard error channels.
+        #[rust_name = "process_channel_mode"]
+        fn processChannelMode(self: &QProcess) -> QProcessProcessChannelMode;
+
+        #[doc(hidden)]
+        #[rust_name = "process_id_qint64"]
+        fn processId(self: &QProcess) -> qint64;
+
+        /// Returns the program the process was last started with.
+        fn program(self: &QProcess) -> QString;
+
+        /// Regardless of the current read channel, this function returns all data available from the standard error of the process.
+        #[rust_name = "read_all_standard_error"]
+        fn readAllStandardError(self: Pin<&mut QProcess>) -> QByteArray;
+
+        /// Regardless of the current read channel, this function returns all data available from the standard output of the process.
+        #[rust_name = "read_all_standard_output"]
+        fn readAllStandardOutput(self: Pin<&mut QProcess>) -> QByteArray;
+
+        /// Returns the current read channel of the `QProcess`.
+        #[rust_name = "read_channel"]
+        fn readChannel(self: &QProcess) -> QProcessProcessChannel;
+
+        /// Set the `arguments` to pass to the called program when starting the process. This function must be called before [`start`](QProcess::start).
+        #[rust_name = "set_arguments"]
+        fn setArguments(self: Pin<&mut QProcess>, arguments: &QStringList);
+
+        /// Sets the environment that `QProcess` will pass to the child process. The parameter `environment` is a list of key=value pairs.
+        #[rust_name = "set_environment"]
+        fn setEnvironment(self: Pin<&mut QProcess>, environment: &QStringList);
+
+        /// Sets the channel mode of the `QProcess` standard input channel to the `mode` specified. This mode will be used the next time [`start`](QProcess::start) is called.
+        #[rust_name = "set_input_channel_mode"]
+        fn setInputChannelMode(self: Pin<&mut QProcess>, mode: QProcessInputChannelMode);
+
+        /// Sets the channel mode of the `QProcess` standard output and standard error channels to the `mode` specified. This mode will be used the next time [`start`](QProcess::start) is called.
+        #[rust_name = "set_process_channel_mode"]
+        fn setProcessChannelMode(self: Pin<&mut QProcess>, mode: QProcessProcessChannelMode);
+
+        /// Set the `program` to use when starting the process. This function must be called before [`start`](QProcess::start).
+        ///
+        /// If `program` is an absolute path, it specifies the exact executable that will be launched. Relative paths will be resolved in a platform-specific manner.
+        #[rust_name = "set_program"]
+        fn setProgram(self: Pin<&mut QProcess>, program: &QString);
+
+        /// Sets the current read channel of the `QProcess` to the given `channel`. The current input channel is used by the functions [`read`](QIODevice::read), [`read_all`](QIODevice::read_all), [`read_line`](QIODevice::read_line), and [`get_char`](QIODevice::get_char). It also determines which channel triggers `QProcess` to emit [`ready_read`](QIODevice::ready_read).
+        #[rust_name = "set_read_channel"]
+        fn setReadChannel(self: Pin<&mut QProcess>, channel: QProcessProcessChannel);
+
+        /// Redirects the process' standard error to the file `file_name`. When the redirection is in place, the standard error read channel is closed: reading from it using [`read`](QIODevice::read) will always fail, as will [`read_all_standard_error`](QProcess::read_all_standard_error). The file will be appended to if `mode` is [`QIODeviceOpenModeFlag::Append`](crate::QIODeviceOpenModeFlag::Append), otherwise, it will be truncated.
+        ///
+        /// If [`process_channel_mode`](QProcess::process_channel_mode) is [`QProcessProcessChannelMode::MergedChannels`], this function has no effect.
+        #[rust_name = "set_standard_error_file"]
+        fn setStandardErrorFile(
+            self: Pin<&mut QProcess>,
+            file_name: &QString,
+            mode: QIODeviceOpenMode,
+        );
+
+        /// Redirects the process' standard input to the file indicated by `file_name`. When an input redirection is in place, the `QProcess` object will be in read-only mode (calling [`write`](QIODevice::write) will result in error).
+        ///
+        /// If the file `file_name` does not exist at the moment [`start`](QProcess::start) is called or is not readable, starting the process will fail.
+        #[rust_name = "set_standard_input_file"]
+        fn setStandardInputFile(self: Pin<&mut QProcess>, file_name: &QString);
+
+        /// Redirects the process' standard output to the file `file_name`. When the redirection is in place, the standard output read channel is closed: reading from it using [`read`](QIODevice::read) will always fail, as will [`read_all_standard_output`](QProcess::read_all_standard_output). The file will be appended to if `mode` is [`QIODeviceOpenModeFlag::Append`](crate::QIODeviceOpenModeFlag::Append), otherwise, it will be truncated.
+        #[rust_name = "set_standard_output_file"]
+        fn setStandardOutputFile(
+            self: Pin<&mut QProcess>,
+            file_name: &QString,
+            mode: QIODeviceOpenMode,
+        );
+
+        /// Sets the working directory to `dir`. `QProcess` will start the process in this directory. The default behavior is to start the process in the working directory of the calling process.
+        #[rust_name = "set_working_directory"]
+        fn setWorkingDirectory(self: Pin<&mut QProcess>, dir: &QString);
+
+        /// Starts the given `program` in a new process, passing the command line arguments in `arguments`, and opens the `QProcess` in the given `mode`.
+        ///
+        /// The `QProcess` object will immediately enter the [`QProcessProcessState::Starting`] state. If the process starts successfully, `QProcess` will emit [`started`](QProcess::started); otherwise, [`error_occurred`](QProcess::error_occurred) will be emitted.
+        ///
+        /// **Note:** Processes are started asynchronously, which means the [`started`](QProcess::started) and [`error_occurred`](QProcess::error_occurred) signals may be delayed. Call [`wait_for_started`](QProcess::wait_for_started) to make sure the process has started (or has failed to start) and those signals have been emitted.
+        fn start(
+            self: Pin<&mut QProcess>,
+            program: &QString,
+            arguments: &QStringList,
+            mode: QIODeviceOpenMode,
+        );
+
+        /// Starts the program set by [`set_program`](QProcess::set_program) with arguments set by [`set_arguments`](QProcess::set_arguments). The `QProcess` is opened in the given `mode`.
+        #[rust_name = "start_current"]
+        fn start(self: Pin<&mut QProcess>, mode: QIODeviceOpenMode);
+
+        /// Starts the command `command` in a new process, and opens the `QProcess` in the given `mode`. `command` is a single string of text containing both the program name and its arguments. The arguments are separated by one or more spaces.
+        #[rust_name = "start_command"]
+        fn startCommand(self: Pin<&mut QProcess>, command: &QString, mode: QIODeviceOpenMode);
+
+        /// # Safety
+        ///
+        /// `pid` must be valid or null.
+        #[doc(hidden)]
+        #[rust_name = "start_detached_raw"]
+        unsafe fn startDetached(self: Pin<&mut QProcess>, pid: *mut qint64) -> bool;
+
+        /// Returns the current state of the process.
+        fn state(self: &QProcess) -> QProcessProcessState;
+
+        /// Attempts to terminate the process.
+        ///
+        /// The process may not exit as a result of calling this function (it is given the chance to prompt the user for any unsaved files, etc).
+        ///
+        /// On Windows, `terminate` posts a `WM_CLOSE` message to all top-level windows of the process and then to the main thread of the process itself. On Unix and macOS the `SIGTERM` signal is sent.
+        fn terminate(self: Pin<&mut QProcess>);
+
+        #[doc(hidden)]
+        #[rust_name = "wait_for_finished_msecs"]
+        fn waitForFinished(self: Pin<&mut QProcess>, msecs: i32) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "wait_for_started_msecs"]
+        fn waitForStarted(self: Pin<&mut QProcess>, msecs: i32) -> bool;
+
+        /// If `QProcess` has been assigned a working directory, this function returns the working directory that the `QProcess` will enter before the program has started. Otherwise, (i.e., no directory has been assigned,) an empty string is returned, and `QProcess` will use the application's current working directory instead.
+        #[rust_name = "working_directory"]
+        fn workingDirectory(self: &QProcess) -> QString;
+
+        /// This signal is emitted when an error occurs with the process. The specified `error` describes the type of error that occurred.
+        #[qsignal]
+        #[rust_name = "error_occurred"]
+        fn errorOccurred(self: Pin<&mut QProcess>, error: QProcessProcessError);
+
+        /// This signal is emitted when the process finishes. `exit_code` is the exit code of the process (only valid for normal exits), and `exit_status` is the exit status. After the process has finished, the buffers in `QProcess` are still intact. You can still read any data that the process may have written before it finished.
+        #[qsignal]
+        fn finished(self: Pin<&mut QProcess>, exit_code: i32, exit_status: QProcessExitStatus);
+
+        /// This signal is emitted when the process has made new data available through its standard error channel (stderr). It is emitted regardless of the current read channel.
+        #[qsignal]
+        #[rust_name = "ready_read_standard_error"]
+        fn readyReadStandardError(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted when the process has made new data available through its standard output channel (stdout). It is emitted regardless of the current read channel.
+        #[qsignal]
+        #[rust_name = "ready_read_standard_output"]
+        fn readyReadStandardOutput(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted by `QProcess` when the process has started, and [`state`](QProcess::state) returns [`QProcessProcessState::Running`].
+        #[qsignal]
+        fn started(self: Pin<&mut QProcess>);
+
+        /// This signal is emitted whenever the state of `QProcess` changes. The `new_state` argument is the state `QProcess` changed to.
+        #[qsignal]
+        #[rust_name = "state_changed"]
+        fn stateChanged(self: Pin<&mut QProcess>, new_state: QProcessProcessState);
+    }
+
+    unsafe extern "C++" {
+        /// Starts the program `program` with the arguments `arguments` in a new process, waits for it to finish, and then returns the exit code of the process. Any data the new process writes to the console is forwarded to the calling process.
+        ///
+        /// The environment and working directory are inherited from the calling process.
+        ///
+        /// If the process cannot be started, -2 is returned. If the process crashes, -1 is returned. Otherwise, the process' exit code is returned.
+        #[Self = "QProcess"]
+        fn execute(program: &QString, arguments: &QStringList) -> i32;
+
+        /// The null device of the operating system.
+        ///
+        /// The returned file path uses native directory separators.
+        #[Self = "QProcess"]
+        #[rust_name = "null_device"]
+        fn nullDevice() -> QString;
+
+        /// Returns the environment of the calling process as a list of key=value pairs.
+        ///
+        /// This function does not cache the system environment. Therefore, it's possible to obtain an updated version of the environment if low-level C library functions like `setenv` or `putenv` have been called.
+        #[Self = "QProcess"]
+        #[rust_name = "system_environment"]
+        fn systemEnvironment() -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qprocess_start_detached"]
+        unsafe fn qprocessStartDetached(
+            program: &QString,
+            arguments: &QStringList,
+            working_directory: &QString,
+            pid: *mut qint64,
+        ) -> bool;
+    }
+
+    #[namespace = "rust::cxxqt1"]
+    unsafe extern "C++" {
+        include!("cxx-qt/casting.h");
+
+        #[rust_name = "upcast_qprocess_qobject"]
+        unsafe fn upcastPtr(process: *const QProcess) -> *const QObject;
+        #[rust_name = "downcast_qobject_qprocess"]
+        unsafe fn downcastPtr(process: *const QObject) -> *const QProcess;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qprocess_new"]
+        fn make_unique() -> UniquePtr<QProcess>;
+    }
+}
+
+pub use ffi::{
+    QProcess, QProcessExitStatus, QProcessInputChannelMode, QProcessProcessChannel,
+    QProcessProcessChannelMode, QProcessProcessError, QProcessProcessState,
+};
+
+impl fmt::Debug for QProcess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QProcess {
+    /// Constructs a `QProcess` object.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qprocess_new()
+    }
+
+    /// Returns the native process identifier for the running process, if available. If no process is currently running, `None` is returned.
+    pub fn process_id(&self) -> Option<i64> {
+        let pid: i64 = self.process_id_qint64().into();
+        if pid == 0 { None } else { Some(pid) }
+    }
+
+    /// Starts the program set by [`set_program`](QProcess::set_program) with arguments set by [`set_arguments`](QProcess::set_arguments) in a new process, and detaches from it. Returns the process ID on success; otherwise returns `None`. If the calling process exits, the detached process will continue to run unaffected.
+    ///
+    /// Only the following property setters are supported by `start_detached`: [`set_arguments`](QProcess::set_arguments), [`set_environment`](QProcess::set_environment), [`set_program`](QProcess::set_program), [`set_standard_error_file`](QProcess::set_standard_error_file), [`set_standard_input_file`](QProcess::set_standard_input_file), [`set_standard_output_file`](QProcess::set_standard_output_file), and [`set_working_directory`](QProcess::set_working_directory). All other properties of the `QProcess` object are ignored.
+    pub fn start_detached(self: Pin<&mut Self>) -> Option<i64> {
+        let mut pid = qint64::from(0);
+        // SAFETY: `pid` is valid.
+        if unsafe { self.start_detached_raw(&raw mut pid) } {
+            Some(pid.into())
+        } else {
+            None
+        }
+    }
+
+    /// Starts the program `program` with the arguments `arguments` in a new process, and detaches from it. Returns the process ID on success; otherwise returns `None`. If the calling process exits, the detached process will continue to run unaffected.
+    ///
+    /// The process will be started in the directory `working_directory`. If `working_directory` is empty, the working directory is inherited from the calling process.
+    pub fn start_program_detached(
+        program: &QString,
+        arguments: &QStringList,
+        working_directory: &QString,
+    ) -> Option<i64> {
+        let mut pid = qint64::from(0);
+        // SAFETY: `pid` is valid.
+        if unsafe {
+            ffi::qprocess_start_detached(program, arguments, working_directory, &raw mut pid)
+        } {
+            Some(pid.into())
+        } else {
+            None
+        }
+    }
+
+    /// Blocks until the process has finished and the [`finished`](QProcess::finished) signal has been emitted, or until `duration` has passed.
+    ///
+    /// Returns `true` if the process finished; otherwise returns `false` (if the operation timed out, if an error occurred, or if this `QProcess` is already finished).
+    ///
+    /// If `duration` is `None`, this function will not time out.
+    ///
+    /// **Warning:** Calling this function from the main (GUI) thread might cause your user interface to freeze.
+    pub fn wait_for_finished(self: Pin<&mut Self>, duration: Option<Duration>) -> bool {
+        self.wait_for_finished_msecs(duration.msecs())
+    }
+
+    /// Blocks until the process has started and the [`started`](QProcess::started) signal has been emitted, or until `duration` has passed.
+    ///
+    /// Returns `true` if the process was started successfully; otherwise returns `false` (if the operation timed out or if an error occurred). If the process had already started successfully before this function was called, it returns immediately.
+    ///
+    /// If `duration` is `None`, this function will not time out.
+    pub fn wait_for_started(self: Pin<&mut Self>, duration: Option<Duration>) -> bool {
+        self.wait_for_started_msecs(duration.msecs())
+    }
+
+    /// Casts this object to `QIODevice`.
+    pub fn as_io_device(&self) -> &QIODevice {
+        self.upcast()
+    }
+
+    /// Mutably casts this object to `QIODevice`.
+    pub fn as_io_device_mut<'a>(self: &'a mut Pin<&mut Self>) -> Pin<&'a mut QIODevice> {
+        self.as_mut().upcast_pin()
+    }
+}
+
+impl Deref for QProcess {
+    type Target = QIODevice;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+// SAFETY: qobject_cast
+unsafe impl Upcast<QObject> for QProcess {
+    unsafe fn upcast_ptr(this: *const Self) -> *const QObject {
+        // SAFETY: static_upcast
+        unsafe { ffi::upcast_qprocess_qobject(this) }
+    }
+
+    unsafe fn from_base_ptr(base: *const QObject) -> *const Self {
+        // SAFETY: qobject_cast
+        unsafe { ffi::downcast_qobject_qprocess(base) }
+    }
+}
+
+impl Read for Pin<&mut QProcess> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.as_io_device_mut().read(buf)
+    }
+}
+
+impl Write for Pin<&mut QProcess> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_io_device_mut().write(buf)
+    }
+
+    /// Blocks until all buffered data has been written to the standard input of the process. Returns an error if the process stops running first.
+    fn flush(&mut self) -> io::Result<()> {
+        let mut device = self.as_io_device_mut();
+        while device.bytes_to_write() > 0 {
+            if !device.as_mut().wait_for_bytes_written(None) {
+                return Err(device.get_error());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for QProcessExitStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            Self::NormalExit => "normal exit",
+            Self::CrashExit => "crash exit",
+            _ => "unknown",
+        })
+    }
+}
+
+impl fmt::Display for QProcessProcessError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            Self::FailedToStart => "failed to start",
+            Self::Crashed => "crashed",
+            Self::Timedout => "timed out",
+            Self::WriteError => "write error",
+            Self::ReadError => "read error",
+            _ => "unknown error",
+        })
+    }
+}
+
+impl From<QProcessProcessError> for io::ErrorKind {
+    fn from(value: QProcessProcessError) -> Self {
+        match value {
+            QProcessProcessError::Timedout => Self::TimedOut,
+            _ => Self::Other,
+        }
+    }
+}
+
+impl fmt::Display for QProcessProcessState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            Self::NotRunning => "not running",
+            Self::Starting => "starting",
+            Self::Running => "running",
+            _ => "unknown",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cxx_qt_lib::QList;
+
+    use super::*;
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QProcessProps {
+            arguments: QList<QString>,
+            environment: QList<QString>,
+            input_channel_mode: QProcessInputChannelMode,
+            process_channel_mode: QProcessProcessChannelMode,
+            program: QString,
+            read_channel: QProcessProcessChannel,
+            working_directory: QString,
+        }
+
+        let mut arguments = QList::default();
+        arguments.append(QString::from("--verbose"));
+        let mut environment = QList::default();
+        environment.append(QString::from("KEY=value"));
+
+        let props = QProcessProps {
+            arguments,
+            environment,
+            input_channel_mode: QProcessInputChannelMode::ForwardedInputChannel,
+            process_channel_mode: QProcessProcessChannelMode::MergedChannels,
+            program: QString::from("program"),
+            read_channel: QProcessProcessChannel::StandardError,
+            working_directory: QString::from("/tmp"),
+        };
+
+        let mut process = QProcess::new();
+
+        process
+            .pin_mut()
+            .set_arguments(&QStringList::from(&props.arguments));
+        process
+            .pin_mut()
+            .set_environment(&QStringList::from(&props.environment));
+        process
+            .pin_mut()
+            .set_input_channel_mode(props.input_channel_mode);
+        process
+            .pin_mut()
+            .set_process_channel_mode(props.process_channel_mode);
+        process.pin_mut().set_program(&props.program);
+        process.pin_mut().set_read_channel(props.read_channel);
+        process
+            .pin_mut()
+            .set_working_directory(&props.working_directory);
+
+        let actual_props = QProcessProps {
+            arguments: QList::from(&process.arguments()),
+            environment: QList::from(&process.environment()),
+            input_channel_mode: process.input_channel_mode(),
+            process_channel_mode: process.process_channel_mode(),
+            program: process.program(),
+            read_channel: process.read_channel(),
+            working_directory: process.working_directory(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+}
@@ -2,7 +2,10 @@ mod file_descriptor;
 pub use file_descriptor::FileDescriptor;
 
 mod qdir;
-pub use qdir::QDir;
+pub use qdir::{QDir, QDirFilter, QDirFilters, QDirSortFlag, QDirSortFlags};
+
+mod qdiriterator;
+pub use qdiriterator::{QDirIterator, QDirIteratorIteratorFlag, QDirIteratorIteratorFlags};
 
 mod qfiledevice;
 pub use qfiledevice::{
@@ -14,6 +17,15 @@ pub use qfiledevice::{
 mod qfile;
 pub use qfile::QFile;
 
+mod qfileinfo;
+pub use qfileinfo::QFileInfo;
+
+mod qfilesystemwatcher;
+pub use qfilesystemwatcher::QFileSystemWatcher;
+
+mod qlockfile;
+pub use qlockfile::{LockInfo, QLockFile, QLockFileGuard, QLockFileLockError};
+
 mod qsavefile;
 pub use qsavefile::QSaveFile;
 
@@ -1,18 +1,105 @@
+use std::fmt;
 use std::mem::MaybeUninit;
 
 use cxx::{ExternType, type_id};
+use cxx_qt_lib::{QFlags, QString};
+
+use crate::util::IsNonNull;
 
 #[cxx::bridge]
 mod ffi {
+    /// This enum describes the filtering options available to [`QDir`]; e.g. for [`QDir::entry_list`] and [`QDir::entry_info_list`]. The filter value is specified by combining values from the following list using the bitwise OR operator.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QDirFilter {
+        /// List directories that match the filters.
+        Dirs = 0x001,
+        /// List files.
+        Files = 0x002,
+        /// List disk drives (ignored under Unix).
+        Drives = 0x004,
+        /// Do not list symbolic links (ignored by operating systems that don't support symbolic links).
+        NoSymLinks = 0x008,
+        /// List directories, files, drives and symlinks (this does not list broken symlinks unless you specify [`System`](QDirFilter::System)).
+        AllEntries = 0x007,
+        /// List files for which the application has read access. The `Readable` value needs to be combined with [`Dirs`](QDirFilter::Dirs) or [`Files`](QDirFilter::Files).
+        Readable = 0x010,
+        /// List files for which the application has write access. The `Writable` value needs to be combined with [`Dirs`](QDirFilter::Dirs) or [`Files`](QDirFilter::Files).
+        Writable = 0x020,
+        /// List files for which the application has execute access. The `Executable` value needs to be combined with [`Dirs`](QDirFilter::Dirs) or [`Files`](QDirFilter::Files).
+        Executable = 0x040,
+        /// Only list files that have been modified (ignored on Unix).
+        Modified = 0x080,
+        /// List hidden files (on Unix, files starting with a `"."`).
+        Hidden = 0x100,
+        /// List system files (on Unix, FIFOs, sockets and device files are included; on Windows, `.lnk` files are included).
+        System = 0x200,
+        /// List all directories; i.e. don't apply the filters to directory names.
+        AllDirs = 0x400,
+        /// The filter should be case sensitive.
+        CaseSensitive = 0x800,
+        /// Do not list the special entry `"."`.
+        NoDot = 0x2000,
+        /// Do not list the special entry `".."`.
+        NoDotDot = 0x4000,
+        /// Do not list the special entries `"."` and `".."`.
+        NoDotAndDotDot = 0x6000,
+        /// No filter.
+        NoFilter = -1,
+    }
+
+    /// This enum describes the sort options available to [`QDir`], e.g. for [`QDir::entry_list`] and [`QDir::entry_info_list`]. The sort value is specified by OR-ing together values from the following list.
+    ///
+    /// You can only specify one of the first four.
+    ///
+    /// If you specify both [`DirsFirst`](QDirSortFlag::DirsFirst) and [`Reversed`](QDirSortFlag::Reversed), directories are still put first, but in reverse order; the files will be listed after the directories, again in reverse order.
+    #[repr(i32)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QDirSortFlag {
+        /// Sort by name.
+        Name = 0x00,
+        /// Sort by time (modification time).
+        Time = 0x01,
+        /// Sort by file size.
+        Size = 0x02,
+        /// Do not sort.
+        Unsorted = 0x03,
+        /// Put the directories first, then the files.
+        DirsFirst = 0x04,
+        /// Reverse the sort order.
+        Reversed = 0x08,
+        /// Sort case-insensitively.
+        IgnoreCase = 0x10,
+        /// Put the files first, then the directories.
+        DirsLast = 0x20,
+        /// Sort items appropriately using the current locale settings.
+        LocaleAware = 0x40,
+        /// Sort by file type (extension).
+        Type = 0x80,
+        /// Not sorted by default.
+        NoSort = -1,
+    }
+
     extern "C++" {
         include!("cxx-qt-lib/qstring.h");
         type QString = cxx_qt_lib::QString;
         include!("cxx-qt-lib/qstringlist.h");
         type QStringList = cxx_qt_lib::QStringList;
+
+        include!("cxx-qt-io/qlist.h");
+        type QList_QFileInfo = cxx_qt_lib::QList<crate::QFileInfo>;
     }
 
+    #[namespace = "rust::cxxqtio1"]
     extern "C++" {
+        include!("cxx-qt-io/qfiledevice.h");
+        type QFileDevicePermissions = crate::QFileDevicePermissions;
+
         include!("cxx-qt-io/qdir.h");
+        type QDirFilter;
+        type QDirFilters = super::QDirFilters;
+        type QDirSortFlag;
+        type QDirSortFlags = super::QDirSortFlags;
     }
 
     unsafe extern "C++" {
@@ -30,11 +117,36 @@ mod ffi {
         #[rust_name = "clean_path"]
         fn cleanPath(path: &QString) -> QString;
 
+        /// Returns the application's current directory.
+        ///
+        /// The directory is constructed using the absolute path of the current directory, ensuring that its [`path`](QDir::path) will be the same as its [`absolute_path`](QDir::absolute_path).
+        #[Self = "QDir"]
+        fn current() -> QDir;
+
         /// Returns the absolute path of the application's current directory. The current directory is the last directory set with [`QDir::set_current()`] or, if that was never called, the directory at which this application was started at by the parent process.
         #[Self = "QDir"]
         #[rust_name = "current_path"]
         fn currentPath() -> QString;
 
+        /// Returns a list of the root directories on this system.
+        ///
+        /// On Windows this returns a list of `QFileInfo` objects containing `"C:/"`, `"D:/"`, etc. This does not return drives with ejectable media that are empty. On other operating systems, it returns a list containing just one root directory (i.e. `"/"`).
+        #[Self = "QDir"]
+        fn drives() -> QList_QFileInfo;
+
+        /// Returns `path_name` using `'/'` as file separator. On Windows, for instance, `from_native_separators("c:\\winnt\\system32")` returns `"c:/winnt/system32"`.
+        ///
+        /// The returned string may be the same as the argument on some operating systems, for example on Unix.
+        #[Self = "QDir"]
+        #[rust_name = "from_native_separators"]
+        fn fromNativeSeparators(path_name: &QString) -> QString;
+
+        /// Returns the user's home directory.
+        ///
+        /// The directory is constructed using the absolute path of the home directory, ensuring that its [`path`](QDir::path) will be the same as its [`absolute_path`](QDir::absolute_path).
+        #[Self = "QDir"]
+        fn home() -> QDir;
+
         /// Returns the absolute path of the user's home directory.
         ///
         /// If the directory of the current user's profile does not exist or cannot be retrieved, the following alternatives will be checked (in the given order) until an existing and available path is found:
@@ -50,6 +162,26 @@ mod ffi {
         #[rust_name = "home_path"]
         fn homePath() -> QString;
 
+        /// Returns `true` if `path` is absolute; returns `false` if it is relative.
+        ///
+        /// **Note:** If `path` starts with `':'`, this function will return `true` because paths starting with `':'` are treated specially as they denote a [Qt Resource](https://doc.qt.io/qt-6/resources.html).
+        #[Self = "QDir"]
+        #[rust_name = "is_absolute_path"]
+        fn isAbsolutePath(path: &QString) -> bool;
+
+        /// Returns `true` if `path` is relative; returns `false` if it is absolute.
+        ///
+        /// **Note:** On Unix-like operating systems (including macOS and iOS), this is the same as `!path.starts_with('/')`. On Windows, it also returns `false` for paths starting with a drive letter and a colon.
+        #[Self = "QDir"]
+        #[rust_name = "is_relative_path"]
+        fn isRelativePath(path: &QString) -> bool;
+
+        /// Returns the root directory.
+        ///
+        /// The directory is constructed using the absolute path of the root directory, ensuring that its [`path`](QDir::path) will be the same as its [`absolute_path`](QDir::absolute_path).
+        #[Self = "QDir"]
+        fn root() -> QDir;
+
         /// Returns the absolute path of the root directory.
         ///
         /// For Unix operating systems this returns `"/"`. For Windows file systems this normally returns `"c:/"`.
@@ -80,38 +212,585 @@ mod ffi {
         #[rust_name = "set_search_paths"]
         fn setSearchPaths(prefix: &QString, search_paths: &QStringList);
 
+        /// Returns the system's temporary directory.
+        ///
+        /// The directory is constructed using the absolute canonical path of the temporary directory, ensuring that its [`path`](QDir::path) will be the same as its [`absolute_path`](QDir::absolute_path).
+        #[Self = "QDir"]
+        fn temp() -> QDir;
+
         /// Returns the absolute canonical path of the system's temporary directory.
         ///
         /// On Unix/Linux systems this is the path in the `TMPDIR` environment variable or `/tmp` if `TMPDIR` is not defined. On Windows this is usually the path in the `TEMP` or `TMP` environment variable. The path returned by this method doesn't end with a directory separator unless it is the root directory (of a drive).
         #[Self = "QDir"]
         #[rust_name = "temp_path"]
         fn tempPath() -> QString;
+
+        /// Returns `path_name` with the `'/'` separators converted to separators that are appropriate for the underlying operating system.
+        ///
+        /// On Windows, `to_native_separators("c:/winnt/system32")` returns `"c:\\winnt\\system32"`.
+        ///
+        /// The returned string may be the same as the argument on some operating systems, for example on Unix.
+        #[Self = "QDir"]
+        #[rust_name = "to_native_separators"]
+        fn toNativeSeparators(path_name: &QString) -> QString;
+
+        /// Returns the absolute path name of a file in the directory. Does not check if the file actually exists in the directory; but see [`exists_file`](QDir::exists_file). Redundant multiple separators or `"."` and `".."` directories in `file_name` are not removed (see [`QDir::clean_path`]).
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(&self, file_name: &QString) -> QString;
+
+        /// Returns the absolute path (a path that starts with `"/"` or with a drive specification), which may contain symbolic links, but never contains redundant `"."`, `".."` or multiple separators.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(&self) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "canonical_path_or_empty"]
+        fn canonicalPath(&self) -> QString;
+
+        /// Changes the directory to `dir_name`.
+        ///
+        /// Returns `true` if the new directory exists; otherwise returns `false`. Note that the logical `cd()` operation is not performed if the new directory does not exist.
+        ///
+        /// Calling `cd("..")` is equivalent to calling [`cd_up`](QDir::cd_up).
+        fn cd(&mut self, dir_name: &QString) -> bool;
+
+        /// Changes directory by moving one directory up from the `QDir`'s current directory.
+        ///
+        /// Returns `true` if the new directory exists; otherwise returns `false`. Note that the logical `cd_up()` operation is not performed if the new directory does not exist.
+        ///
+        /// **Note:** On Android, this is not supported for content URIs.
+        #[rust_name = "cd_up"]
+        fn cdUp(&mut self) -> bool;
+
+        /// Returns the name of the directory; this is not the same as the path, e.g. a directory with the name `"mail"`, might have the path `"/var/spool/mail"`. If the directory has no name (e.g. it is the root directory) an empty string is returned.
+        ///
+        /// No check is made to ensure that a directory with this name actually exists; but see [`exists`](QDir::exists).
+        #[rust_name = "dir_name"]
+        fn dirName(&self) -> QString;
+
+        /// Returns a list of [`QFileInfo`](crate::QFileInfo) objects for all the files and directories in the directory, ordered according to the name and attribute filters previously set with [`set_name_filters`](QDir::set_name_filters) and [`set_filter`](QDir::set_filter), and sorted according to the flags set with [`set_sorting`](QDir::set_sorting).
+        ///
+        /// The attribute filter and sorting specifications can be overridden using the `filters` and `sort` arguments. Pass [`QDirFilter::NoFilter`] and [`QDirSortFlag::NoSort`] to use the ones set on this `QDir`.
+        ///
+        /// Returns an empty list if the directory is unreadable, does not exist, or if nothing matches the specification.
+        #[rust_name = "entry_info_list"]
+        fn entryInfoList(&self, filters: QDirFilters, sort: QDirSortFlags) -> QList_QFileInfo;
+
+        /// Returns a list of [`QFileInfo`](crate::QFileInfo) objects for all the files and directories in the directory, ordered according to the name and attribute filters previously set with [`set_name_filters`](QDir::set_name_filters) and [`set_filter`](QDir::set_filter), and sorted according to the flags set with [`set_sorting`](QDir::set_sorting).
+        ///
+        /// The name filter, file attribute filter, and sorting specification can be overridden using the `name_filters`, `filters`, and `sort` arguments.
+        ///
+        /// Returns an empty list if the directory is unreadable, does not exist, or if nothing matches the specification.
+        #[rust_name = "entry_info_list_matching"]
+        fn entryInfoList(
+            &self,
+            name_filters: &QStringList,
+            filters: QDirFilters,
+            sort: QDirSortFlags,
+        ) -> QList_QFileInfo;
+
+        /// Returns a list of the names of all the files and directories in the directory, ordered according to the name and attribute filters previously set with [`set_name_filters`](QDir::set_name_filters) and [`set_filter`](QDir::set_filter), and sorted according to the flags set with [`set_sorting`](QDir::set_sorting).
+        ///
+        /// The attribute filter and sorting specifications can be overridden using the `filters` and `sort` arguments. Pass [`QDirFilter::NoFilter`] and [`QDirSortFlag::NoSort`] to use the ones set on this `QDir`.
+        ///
+        /// Returns an empty list if the directory is unreadable, does not exist, or if nothing matches the specification.
+        ///
+        /// **Note:** To list symlinks that point to non existing files, [`QDirFilter::System`] must be passed to the filter.
+        #[rust_name = "entry_list"]
+        fn entryList(&self, filters: QDirFilters, sort: QDirSortFlags) -> QStringList;
+
+        /// Returns a list of the names of all the files and directories in the directory, ordered according to the name and attribute filters previously set with [`set_name_filters`](QDir::set_name_filters) and [`set_filter`](QDir::set_filter), and sorted according to the flags set with [`set_sorting`](QDir::set_sorting).
+        ///
+        /// The name filter, file attribute filter, and sorting specification can be overridden using the `name_filters`, `filters`, and `sort` arguments.
+        ///
+        /// Returns an empty list if the directory is unreadable, does not exist, or if nothing matches the specification.
+        #[rust_name = "entry_list_matching"]
+        fn entryList(
+            &self,
+            name_filters: &QStringList,
+            filters: QDirFilters,
+            sort: QDirSortFlags,
+        ) -> QStringList;
+
+        /// Returns `true` if the directory exists; otherwise returns `false`. (If a file with the same name is found this function will return `false`).
+        fn exists(&self) -> bool;
+
+        /// Returns `true` if the file called `name` exists; otherwise returns `false`.
+        ///
+        /// Unless `name` contains an absolute file path, the file name is assumed to be relative to the directory itself, so this function is typically used to check for the presence of files within a directory.
+        #[rust_name = "exists_file"]
+        fn exists(&self, name: &QString) -> bool;
+
+        /// Returns the path name of a file in the directory. Does not check if the file actually exists in the directory; but see [`exists_file`](QDir::exists_file). If the `QDir` is relative the returned path name will also be relative. Redundant multiple separators or `"."` and `".."` directories in `file_name` are not removed (see [`QDir::clean_path`]).
+        #[rust_name = "file_path"]
+        fn filePath(&self, file_name: &QString) -> QString;
+
+        /// Returns the value set by [`set_filter`](QDir::set_filter).
+        fn filter(&self) -> QDirFilters;
+
+        /// Returns `true` if the directory's path is absolute; otherwise returns `false`.
+        ///
+        /// **Note:** Paths starting with a colon `':'` are always considered absolute, as they denote a [Qt Resource](https://doc.qt.io/qt-6/resources.html).
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(&self) -> bool;
+
+        /// Returns whether the directory is empty.
+        ///
+        /// Equivalent to `entry_list(filters, QDirSortFlag::NoSort).is_empty()`, but faster as it just checks whether the directory contains at least one entry.
+        ///
+        /// **Note:** Unless you set the `filters` flags to include [`QDirFilter::NoDotAndDotDot`] (as the default value does), no directory is empty.
+        #[rust_name = "is_empty"]
+        fn isEmpty(&self, filters: QDirFilters) -> bool;
+
+        /// Returns `true` if the directory is readable and we can open files by name; otherwise returns `false`.
+        ///
+        /// **Warning:** A `false` value from this function is not a guarantee that files in the directory are not accessible.
+        #[rust_name = "is_readable"]
+        fn isReadable(&self) -> bool;
+
+        /// Returns `true` if the directory path is relative; otherwise returns `false`. (Under Unix a path is relative if it does not start with a `"/"`).
+        #[rust_name = "is_relative"]
+        fn isRelative(&self) -> bool;
+
+        /// Returns `true` if the directory is the root directory; otherwise returns `false`.
+        ///
+        /// **Note:** If the directory is a symbolic link to the root directory this function returns `false`. If you want to test for this use [`canonical_path`](QDir::canonical_path).
+        #[rust_name = "is_root"]
+        fn isRoot(&self) -> bool;
+
+        /// Converts the directory path to an absolute path. If it is already absolute nothing happens. Returns `true` if the conversion succeeded; otherwise returns `false`.
+        #[rust_name = "make_absolute"]
+        fn makeAbsolute(&mut self) -> bool;
+
+        /// Creates a sub-directory called `dir_name`.
+        ///
+        /// Returns `true` on success; otherwise returns `false`.
+        ///
+        /// If the directory already existed when this function was called, it will return `false`.
+        fn mkdir(&self, dir_name: &QString) -> bool;
+
+        /// Creates a sub-directory called `dir_name` with the given `permissions`.
+        ///
+        /// Returns `true` on success; otherwise returns `false`.
+        ///
+        /// If the directory already existed when this function was called, it will return `false`.
+        ///
+        /// The permissions of the created directory are set to `permissions`.
+        ///
+        /// On POSIX systems the permissions are influenced by the value of `umask`.
+        ///
+        /// On Windows the permissions are emulated using ACLs. These ACLs may be in non-canonical order when the group is granted less permissions than others. Files and directories with such permissions will generate warnings when the Security tab of the Properties dialog is opened. Granting the group all permissions granted to others avoids such warnings.
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[rust_name = "mkdir_with_permissions"]
+        fn mkdir(&self, dir_name: &QString, permissions: QFileDevicePermissions) -> bool;
+
+        /// Creates the directory path `dir_path`.
+        ///
+        /// The function will create all parent directories necessary to create the directory.
+        ///
+        /// Returns `true` if successful; otherwise returns `false`.
+        ///
+        /// If the path already existed when this function was called, it will return `true`.
+        fn mkpath(&self, dir_path: &QString) -> bool;
+
+        /// Returns the string list set by [`set_name_filters`](QDir::set_name_filters).
+        #[rust_name = "name_filters"]
+        fn nameFilters(&self) -> QStringList;
+
+        /// Returns the path. This may contain symbolic links, but never contains redundant `"."`, `".."` or multiple separators.
+        ///
+        /// The returned path can be either absolute or relative (see [`set_path`](QDir::set_path)).
+        fn path(&self) -> QString;
+
+        /// Refreshes the directory information.
+        fn refresh(&self);
+
+        /// Returns the path to `file_name` relative to the directory.
+        #[rust_name = "relative_file_path"]
+        fn relativeFilePath(&self, file_name: &QString) -> QString;
+
+        /// Removes the file, `file_name`.
+        ///
+        /// Returns `true` if the file is removed successfully; otherwise returns `false`.
+        fn remove(&mut self, file_name: &QString) -> bool;
+
+        /// Removes the directory, including all its contents.
+        ///
+        /// Returns `true` if successful, otherwise `false`.
+        ///
+        /// If a file or directory cannot be removed, `remove_recursively()` keeps going and attempts to delete as many files and sub-directories as possible, then returns `false`.
+        ///
+        /// If the directory was already removed, the method returns `true` (expected result already reached).
+        ///
+        /// **Note:** This function is meant for removing a small application-internal directory (such as a temporary directory), but not user-visible directories. For user-visible operations, it is rather recommended to report errors more precisely to the user, to offer solutions in case of errors, to show progress during the deletion since it could take several minutes, etc.
+        #[rust_name = "remove_recursively"]
+        fn removeRecursively(&mut self) -> bool;
+
+        /// Renames a file or directory from `old_name` to `new_name`, and returns `true` if successful; otherwise returns `false`.
+        ///
+        /// On most file systems, `rename()` fails only if `old_name` does not exist, or if a file with the new name already exists. However, there are also other reasons why `rename()` can fail. For example, on at least one file system `rename()` fails if `new_name` points to an open file.
+        ///
+        /// If `old_name` is a file (not a directory) that can't be renamed right away, Qt will try to copy `old_name` to `new_name` and remove `old_name`.
+        fn rename(&mut self, old_name: &QString, new_name: &QString) -> bool;
+
+        /// Removes the directory specified by `dir_name`.
+        ///
+        /// The directory must be empty for `rmdir()` to succeed.
+        ///
+        /// Returns `true` if successful; otherwise returns `false`.
+        fn rmdir(&self, dir_name: &QString) -> bool;
+
+        /// Removes the directory path `dir_path`.
+        ///
+        /// The function will remove all parent directories in `dir_path`, provided that they are empty. This is the opposite of [`mkpath`](QDir::mkpath).
+        ///
+        /// Returns `true` if successful; otherwise returns `false`.
+        fn rmpath(&self, dir_path: &QString) -> bool;
+
+        /// Sets the filter used by [`entry_list`](QDir::entry_list) and [`entry_info_list`](QDir::entry_info_list) to `filters`. The filter is used to specify the kind of files that should be returned by those functions.
+        #[rust_name = "set_filter"]
+        fn setFilter(&mut self, filters: QDirFilters);
+
+        /// Sets the name filters used by [`entry_list`](QDir::entry_list) and [`entry_info_list`](QDir::entry_info_list) to the list of filters specified by `name_filters`.
+        ///
+        /// Each name filter is a wildcard (globbing) filter that understands `*` and `?` wildcards. For example, `"*.cpp"` matches files ending in `.cpp`.
+        #[rust_name = "set_name_filters"]
+        fn setNameFilters(&mut self, name_filters: &QStringList);
+
+        /// Sets the path of the directory to `path`. The path is cleaned of redundant `"."`, `".."` and of multiple separators. No check is made to see whether a directory with this path actually exists; but you can check for yourself using [`exists`](QDir::exists).
+        ///
+        /// The path can be either absolute or relative. Absolute paths begin with the directory separator `"/"` (optionally preceded by a drive specification under Windows). Relative file names begin with a directory name or a file name and specify a path relative to the current directory.
+        #[rust_name = "set_path"]
+        fn setPath(&mut self, path: &QString);
+
+        /// Sets the sort order used by [`entry_list`](QDir::entry_list) and [`entry_info_list`](QDir::entry_info_list).
+        ///
+        /// The `sort` is specified by OR-ing values from [`QDirSortFlag`].
+        #[rust_name = "set_sorting"]
+        fn setSorting(&mut self, sort: QDirSortFlags);
+
+        /// Returns the value set by [`set_sorting`](QDir::set_sorting).
+        fn sorting(&self) -> QDirSortFlags;
     }
 
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         include!("cxx-qt-lib/common.h");
+
         #[rust_name = "qdir_drop"]
         fn drop(dir: &mut QDir);
+
+        #[rust_name = "qdir_init_default"]
+        fn construct() -> QDir;
+        #[rust_name = "qdir_init_path"]
+        fn construct(path: &QString) -> QDir;
+        #[rust_name = "qdir_init_name_filter"]
+        fn construct(
+            path: &QString,
+            name_filter: &QString,
+            sort: QDirSortFlags,
+            filters: QDirFilters,
+        ) -> QDir;
+        #[rust_name = "qdir_clone"]
+        fn construct(other: &QDir) -> QDir;
+
+        #[rust_name = "qdir_eq"]
+        fn operatorEq(a: &QDir, b: &QDir) -> bool;
     }
 }
 
+pub use ffi::{QDirFilter, QDirSortFlag};
+
+/// [`QFlags`] of [`QDirFilter`].
+pub type QDirFilters = QFlags<QDirFilter>;
+unsafe_impl_qflag!(QDirFilter, "rust::cxxqtio1::QDirFilters");
+
+/// [`QFlags`] of [`QDirSortFlag`].
+pub type QDirSortFlags = QFlags<QDirSortFlag>;
+unsafe_impl_qflag!(QDirSortFlag, "rust::cxxqtio1::QDirSortFlags");
+
 /// The `QDir` class provides access to directory structures and their contents.
 ///
+/// A `QDir` is used to manipulate path names, access information regarding paths and files, and manipulate the underlying file system. It can also be used to access Qt's [resource system](https://doc.qt.io/qt-6/resources.html).
+///
 /// Qt Documentation: [QDir](https://doc.qt.io/qt-6/qdir.html#details)
 #[repr(C)]
 pub struct QDir {
     _space: MaybeUninit<usize>,
 }
 
+impl Clone for QDir {
+    fn clone(&self) -> Self {
+        ffi::qdir_clone(self)
+    }
+}
+
+impl Default for QDir {
+    /// Constructs a `QDir` pointing to the application's working directory (`"."`).
+    fn default() -> Self {
+        ffi::qdir_init_default()
+    }
+}
+
 impl Drop for QDir {
     fn drop(&mut self) {
         ffi::qdir_drop(self);
     }
 }
 
+impl PartialEq for QDir {
+    /// Returns `true` if directory `self` and directory `other` have the same path and their sort and filter settings are the same; otherwise returns `false`.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qdir_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QDir {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("QDir").field(&self.path()).finish()
+    }
+}
+
+impl QDir {
+    /// Constructs a `QDir` with path `path`, that filters its entries by name using `name_filter` and by attributes using `filters`. It also sorts the names using `sort`.
+    ///
+    /// If `path` is empty the program's working directory, (`"."`), is used. If `name_filter` is an empty string, `QDir` uses the name filter `"*"` (all files).
+    ///
+    /// `name_filter` may contain multiple patterns separated by spaces or semicolons.
+    pub fn with_name_filter(
+        path: &QString,
+        name_filter: &QString,
+        sort: QDirSortFlags,
+        filters: QDirFilters,
+    ) -> Self {
+        ffi::qdir_init_name_filter(path, name_filter, sort, filters)
+    }
+
+    /// Returns the canonical path, i.e. a path without symbolic links or redundant `"."` or `".."` elements.
+    ///
+    /// On systems that do not have symbolic links this function will always return the same string that [`absolute_path`](QDir::absolute_path) returns. If the canonical path does not exist (normally due to dangling symbolic links) this function returns `None`.
+    pub fn canonical_path(&self) -> Option<QString> {
+        self.canonical_path_or_empty().nonnull()
+    }
+}
+
+impl From<&QString> for QDir {
+    /// Constructs a `QDir` pointing to the given directory `path`. If `path` is empty the program's working directory, (`"."`), is used.
+    fn from(value: &QString) -> Self {
+        ffi::qdir_init_path(value)
+    }
+}
+
 // SAFETY: Static checks on the C++ side to ensure the size is the same.
 unsafe impl ExternType for QDir {
     type Id = type_id!("QDir");
     type Kind = cxx::kind::Trivial;
 }
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use cxx_qt_lib::{QList, QStringList};
+
+    use super::*;
+    use crate::QFileInfo;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        root
+    }
+
+    fn qdir(path: &Path) -> QDir {
+        QDir::from(&QString::from(path.to_str().unwrap()))
+    }
+
+    fn string_list(items: &[&str]) -> QStringList {
+        let mut list = QList::default();
+        for item in items {
+            list.append(QString::from(*item));
+        }
+        QStringList::from(&list)
+    }
+
+    fn to_strings(list: &QStringList) -> Vec<String> {
+        QList::from(list).iter().map(ToString::to_string).collect()
+    }
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QDirProps {
+            dir_name: QString,
+            file_path: QString,
+            is_relative: bool,
+            name_filters: QList<QString>,
+            path: QString,
+            relative_file_path: QString,
+        }
+
+        let mut name_filters = QList::default();
+        name_filters.append(QString::from("*.txt"));
+
+        let props = QDirProps {
+            dir_name: QString::from("dir"),
+            file_path: QString::from("nested/dir/file.txt"),
+            is_relative: true,
+            name_filters,
+            path: QString::from("nested/dir"),
+            relative_file_path: QString::from("file.txt"),
+        };
+
+        let mut dir = QDir::from(&QString::from("nested/dir"));
+        dir.set_name_filters(&QStringList::from(&props.name_filters));
+
+        let actual_props = QDirProps {
+            dir_name: dir.dir_name(),
+            file_path: dir.file_path(&QString::from("file.txt")),
+            is_relative: dir.is_relative(),
+            name_filters: QList::from(&dir.name_filters()),
+            path: dir.path(),
+            relative_file_path: dir.relative_file_path(&QString::from("nested/dir/file.txt")),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+
+    #[test]
+    fn entry_list() {
+        let root = temp_root("cxx-qt-io-qdir-entry-list");
+        fs::create_dir(root.join("sub")).unwrap();
+        fs::write(root.join("a.txt"), b"a").unwrap();
+        fs::write(root.join("b.txt"), b"bbb").unwrap();
+        fs::write(root.join("c.log"), b"cc").unwrap();
+
+        let mut dir = qdir(&root);
+        let txt_files = dir.entry_list_matching(
+            &string_list(&["*.txt"]),
+            QDirFilter::Files.into(),
+            QDirSortFlag::Name.into(),
+        );
+        let reversed = dir.entry_list(
+            QDirFilter::Files.into(),
+            QDirSortFlag::Name | QDirSortFlag::Reversed,
+        );
+        let dirs_first = dir.entry_list(
+            QDirFilter::AllEntries | QDirFilter::NoDotAndDotDot,
+            QDirSortFlag::Name | QDirSortFlag::DirsFirst,
+        );
+        dir.set_name_filters(&string_list(&["*.log"]));
+        dir.set_filter(QDirFilter::Files.into());
+        let from_settings =
+            dir.entry_list(QDirFilter::NoFilter.into(), QDirSortFlag::NoSort.into());
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            (
+                to_strings(&txt_files),
+                to_strings(&reversed),
+                to_strings(&dirs_first),
+                to_strings(&from_settings),
+            ),
+            (
+                vec!["a.txt".to_owned(), "b.txt".to_owned()],
+                vec!["c.log".to_owned(), "b.txt".to_owned(), "a.txt".to_owned()],
+                vec![
+                    "sub".to_owned(),
+                    "a.txt".to_owned(),
+                    "b.txt".to_owned(),
+                    "c.log".to_owned(),
+                ],
+                vec!["c.log".to_owned()],
+            )
+        );
+    }
+
+    #[test]
+    fn entry_info_list() {
+        let root = temp_root("cxx-qt-io-qdir-entry-info-list");
+        fs::write(root.join("a.txt"), b"a").unwrap();
+        fs::write(root.join("b.txt"), b"bbb").unwrap();
+        fs::write(root.join("c.log"), b"cc").unwrap();
+
+        let dir = qdir(&root);
+        let by_size = dir.entry_info_list(QDirFilter::Files.into(), QDirSortFlag::Size.into());
+        let txt_by_size = dir.entry_info_list_matching(
+            &string_list(&["*.txt"]),
+            QDirFilter::Files.into(),
+            QDirSortFlag::Size | QDirSortFlag::Reversed,
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+
+        let summarize = |list: &QList<QFileInfo>| -> Vec<(String, i64)> {
+            list.iter()
+                .map(|info| (info.file_name().to_string(), info.size()))
+                .collect()
+        };
+        assert_eq!(
+            (summarize(&by_size), summarize(&txt_by_size)),
+            (
+                vec![
+                    ("b.txt".to_owned(), 3),
+                    ("c.log".to_owned(), 2),
+                    ("a.txt".to_owned(), 1),
+                ],
+                vec![("a.txt".to_owned(), 1), ("b.txt".to_owned(), 3)],
+            )
+        );
+    }
+
+    #[test]
+    fn mkpath_rmpath() {
+        let root = temp_root("cxx-qt-io-qdir-mkpath");
+        let dir = qdir(&root);
+        let path = QString::from("x/y/z");
+
+        let created = dir.mkpath(&path);
+        let exists_after_mkpath = root.join("x/y/z").is_dir();
+        let removed = dir.rmpath(&path);
+        let exists_after_rmpath = root.join("x").exists();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            (created, exists_after_mkpath, removed, exists_after_rmpath),
+            (true, true, true, false)
+        );
+    }
+
+    #[test]
+    fn rename() {
+        let root = temp_root("cxx-qt-io-qdir-rename");
+        fs::write(root.join("old.txt"), b"contents").unwrap();
+
+        let mut dir = qdir(&root);
+        let renamed = dir.rename(&QString::from("old.txt"), &QString::from("new.txt"));
+        let old_exists = root.join("old.txt").exists();
+        let contents = fs::read(root.join("new.txt")).unwrap();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(
+            (renamed, old_exists, contents.as_slice()),
+            (true, false, &b"contents"[..])
+        );
+    }
+
+    #[test]
+    fn remove_recursively() {
+        let root = temp_root("cxx-qt-io-qdir-remove-recursively");
+        fs::create_dir_all(root.join("nested/deeper")).unwrap();
+        fs::write(root.join("nested/deeper/file.txt"), b"contents").unwrap();
+
+        let mut dir = qdir(&root);
+        let removed = dir.remove_recursively();
+
+        assert_eq!((removed, root.exists()), (true, false));
+    }
+}
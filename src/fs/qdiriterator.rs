@@ -0,0 +1,212 @@
+use std::fmt;
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+use cxx_qt_lib::{QFlags, QString, QStringList};
+
+use crate::{QDir, QDirFilters, QFileInfo};
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes flags that you can combine to configure the behavior of [`QDirIterator`].
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QDirIteratorIteratorFlag {
+        /// The default value, representing no flags. The iterator will return entries for the assigned path.
+        NoIteratorFlags = 0x0,
+        /// When combined with [`Subdirectories`](QDirIteratorIteratorFlag::Subdirectories), this flag enables iterating through all subdirectories of the assigned path, following all symbolic links. Symbolic link loops (e.g., `link => .` or `link => ..`) are automatically detected and ignored.
+        FollowSymlinks = 0x1,
+        /// List entries inside all subdirectories as well.
+        Subdirectories = 0x2,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        include!("cxx-qt-io/qdir.h");
+        type QDir = crate::QDir;
+        include!("cxx-qt-io/qfileinfo.h");
+        type QFileInfo = crate::QFileInfo;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        type QDirFilters = crate::QDirFilters;
+
+        include!("cxx-qt-io/qdiriterator.h");
+        type QDirIteratorIteratorFlag;
+        type QDirIteratorIteratorFlags = super::QDirIteratorIteratorFlags;
+    }
+
+    unsafe extern "C++" {
+        /// The `QDirIterator` class provides an iterator for directory entrylists.
+        ///
+        /// You can use `QDirIterator` to navigate entries of a directory one at a time. Unlike [`QDir`](crate::QDir)'s entry lists, `QDirIterator` is lazy: entries are read from the file system as the iterator advances, which makes it suitable for directories with a large number of entries and for recursive traversal.
+        ///
+        /// A pinned mutable reference to a `QDirIterator` implements [`Iterator`], yielding a [`QFileInfo`](crate::QFileInfo) for each entry.
+        ///
+        /// Qt Documentation: [QDirIterator](https://doc.qt.io/qt-6/qdiriterator.html#details)
+        type QDirIterator;
+
+        /// Returns a [`QFileInfo`] for the current directory entry.
+        #[rust_name = "file_info"]
+        fn fileInfo(self: &QDirIterator) -> QFileInfo;
+
+        /// Returns the file name for the current directory entry, without the path prepended.
+        ///
+        /// This function is convenient when iterating a single directory. When using the [`QDirIteratorIteratorFlag::Subdirectories`] flag, you can use [`file_path`](QDirIterator::file_path) to get the full path.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QDirIterator) -> QString;
+
+        /// Returns the full file path for the current directory entry.
+        #[rust_name = "file_path"]
+        fn filePath(self: &QDirIterator) -> QString;
+
+        /// Returns `true` if there is at least one more entry in the directory; otherwise, `false` is returned.
+        #[rust_name = "has_next"]
+        fn hasNext(self: &QDirIterator) -> bool;
+
+        /// Advances the iterator to the next entry, and returns the file path of this new entry. If [`has_next`](QDirIterator::has_next) returns `false`, this function does nothing, and returns an empty string.
+        #[rust_name = "next_file_path"]
+        fn next(self: Pin<&mut QDirIterator>) -> QString;
+
+        /// Returns the base directory of the iterator.
+        fn path(self: &QDirIterator) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qdiriterator_new_dir"]
+        fn make_unique(dir: &QDir, flags: QDirIteratorIteratorFlags) -> UniquePtr<QDirIterator>;
+        #[rust_name = "qdiriterator_new_path"]
+        fn make_unique(path: &QString, flags: QDirIteratorIteratorFlags)
+        -> UniquePtr<QDirIterator>;
+        #[rust_name = "qdiriterator_new_filters"]
+        fn make_unique(
+            path: &QString,
+            filters: QDirFilters,
+            flags: QDirIteratorIteratorFlags,
+        ) -> UniquePtr<QDirIterator>;
+        #[rust_name = "qdiriterator_new_name_filters"]
+        fn make_unique(
+            path: &QString,
+            name_filters: &QStringList,
+            filters: QDirFilters,
+            flags: QDirIteratorIteratorFlags,
+        ) -> UniquePtr<QDirIterator>;
+    }
+}
+
+pub use ffi::{QDirIterator, QDirIteratorIteratorFlag};
+
+/// [`QFlags`] of [`QDirIteratorIteratorFlag`].
+pub type QDirIteratorIteratorFlags = QFlags<QDirIteratorIteratorFlag>;
+
+unsafe_impl_qflag!(
+    QDirIteratorIteratorFlag,
+    "rust::cxxqtio1::QDirIteratorIteratorFlags"
+);
+
+impl fmt::Debug for QDirIterator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QDirIterator")
+            .field("path", &self.path())
+            .field("file_path", &self.file_path())
+            .finish()
+    }
+}
+
+impl QDirIterator {
+    /// Constructs a `QDirIterator` that can iterate over `path`. You can pass options via `flags` to decide how the directory should be iterated.
+    ///
+    /// By default, `flags` is [`QDirIteratorIteratorFlag::NoIteratorFlags`], which provides the same behavior as in [`QDir`]'s entry lists.
+    pub fn new(path: &QString, flags: QDirIteratorIteratorFlags) -> UniquePtr<Self> {
+        ffi::qdiriterator_new_path(path, flags)
+    }
+
+    /// Constructs a `QDirIterator` that can iterate over `dir`'s entries, using `dir`'s name filters and regular filters. You can pass options via `flags` to decide how the directory should be iterated.
+    ///
+    /// The sorting in `dir` is ignored.
+    pub fn for_dir(dir: &QDir, flags: QDirIteratorIteratorFlags) -> UniquePtr<Self> {
+        ffi::qdiriterator_new_dir(dir, flags)
+    }
+
+    /// Constructs a `QDirIterator` that can iterate over `path`, with no name filtering and `filters` for entry filtering. You can pass options via `flags` to decide how the directory should be iterated.
+    ///
+    /// **Note:** To list symlinks that point to non existing files, [`QDirFilter::System`](crate::QDirFilter::System) must be passed to `filters`.
+    pub fn with_filters(
+        path: &QString,
+        filters: QDirFilters,
+        flags: QDirIteratorIteratorFlags,
+    ) -> UniquePtr<Self> {
+        ffi::qdiriterator_new_filters(path, filters, flags)
+    }
+
+    /// Constructs a `QDirIterator` that can iterate over `path`, using `name_filters` and `filters`. You can pass options via `flags` to decide how the directory should be iterated.
+    ///
+    /// Each name filter is a wildcard (globbing) filter that understands `*` and `?` wildcards. For example, `"*.cpp"` matches files ending in `.cpp`. Pass [`QDirFilter::NoFilter`](crate::QDirFilter::NoFilter) as `filters` to apply only the name filters.
+    pub fn with_name_filters(
+        path: &QString,
+        name_filters: &QStringList,
+        filters: QDirFilters,
+        flags: QDirIteratorIteratorFlags,
+    ) -> UniquePtr<Self> {
+        ffi::qdiriterator_new_name_filters(path, name_filters, filters, flags)
+    }
+}
+
+impl Iterator for Pin<&mut QDirIterator> {
+    type Item = QFileInfo;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.has_next() {
+            return None;
+        }
+        self.as_mut().next_file_path();
+        Some(self.file_info())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+    use std::fs;
+
+    use super::*;
+    use crate::QDirFilter;
+
+    #[test]
+    fn iterate() {
+        let root = std::env::temp_dir().join("cxx-qt-io-qdiriterator");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("nested")).unwrap();
+        fs::write(root.join("a.txt"), b"a").unwrap();
+        fs::write(root.join("nested").join("b.txt"), b"b").unwrap();
+        fs::write(root.join("nested").join("c.log"), b"c").unwrap();
+
+        let mut name_filters = cxx_qt_lib::QList::default();
+        name_filters.append(QString::from("*.txt"));
+        let mut iter = QDirIterator::with_name_filters(
+            &QString::from(root.to_str().unwrap()),
+            &QStringList::from(&name_filters),
+            QDirFilter::Files.into(),
+            QDirIteratorIteratorFlag::Subdirectories.into(),
+        );
+
+        let names: BTreeSet<String> = iter
+            .pin_mut()
+            .map(|info| info.file_name().to_string())
+            .collect();
+
+        fs::remove_dir_all(&root).unwrap();
+
+        let expected: BTreeSet<String> = ["a.txt", "b.txt"].map(String::from).into();
+        assert_eq!(names, expected);
+    }
+}
@@ -0,0 +1,5 @@
+#include "cxx-qt-io/qfileinfo.h"
+
+#include <cxx-qt-io/assertion_utils.h>
+
+assert_shared_pointer_type(QFileInfo);
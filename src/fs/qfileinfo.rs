@@ -0,0 +1,537 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use cxx::{ExternType, type_id};
+use cxx_qt_lib::{QDateTime, QString};
+
+use crate::util::IsNonNull;
+use crate::{QDir, QFileDevice, QFileDeviceFileTime};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qdatetime.h");
+        type QDateTime = cxx_qt_lib::QDateTime;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = cxx_qt_lib::qint64;
+
+        include!("cxx-qt-io/qdir.h");
+        type QDir = crate::QDir;
+        include!("cxx-qt-io/qfiledevice.h");
+        type QFileDevice = crate::QFileDevice;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        type QFileDeviceFileTime = crate::QFileDeviceFileTime;
+        type QFileDevicePermissions = crate::QFileDevicePermissions;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qfileinfo.h");
+        type QFileInfo = super::QFileInfo;
+
+        /// Returns the file system entry's absolute path as a [`QDir`] object.
+        #[rust_name = "absolute_dir"]
+        fn absoluteDir(&self) -> QDir;
+
+        /// Returns the absolute full path to the file system entry.
+        ///
+        /// An absolute path name consists of the full path and the file name. On Unix this will always begin with the root, `"/"`, directory. On Windows this will always begin `"D:/"` where `D` is a drive letter, except for network shares that are not mapped to a drive letter, in which case the path will begin `"//sharename/"`.
+        ///
+        /// This function returns the same as [`file_path`](QFileInfo::file_path), unless [`is_relative`](QFileInfo::is_relative) is `true`. In contrast to [`canonical_file_path`](QFileInfo::canonical_file_path), symbolic links or redundant `"."` or `".."` elements are not necessarily removed.
+        #[rust_name = "absolute_file_path"]
+        fn absoluteFilePath(&self) -> QString;
+
+        /// Returns the absolute path of the file system entry, excluding the file name. On Unix the absolute path will always begin with the root, `"/"`, directory.
+        #[rust_name = "absolute_path"]
+        fn absolutePath(&self) -> QString;
+
+        /// Returns the base name of the file without the path.
+        ///
+        /// The base name consists of all characters in the file up to (but not including) the first `'.'` character.
+        #[rust_name = "base_name"]
+        fn baseName(&self) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "birth_time_or_invalid"]
+        fn birthTime(&self) -> QDateTime;
+
+        /// Returns the name of the bundle. On macOS and iOS this returns the proper localized name for a bundle if the path [`is_bundle`](QFileInfo::is_bundle). On all other platforms an empty string is returned.
+        #[rust_name = "bundle_name"]
+        fn bundleName(&self) -> QString;
+
+        /// Returns `true` if caching is enabled; otherwise returns `false`.
+        fn caching(&self) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "canonical_file_path_or_empty"]
+        fn canonicalFilePath(&self) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "canonical_path_or_empty"]
+        fn canonicalPath(&self) -> QString;
+
+        /// Returns the complete base name of the file without the path.
+        ///
+        /// The complete base name consists of all characters in the file up to (but not including) the last `'.'` character.
+        #[rust_name = "complete_base_name"]
+        fn completeBaseName(&self) -> QString;
+
+        /// Returns the complete suffix (extension) of the file.
+        ///
+        /// The complete suffix consists of all characters in the file after (but not including) the first `'.'`.
+        #[rust_name = "complete_suffix"]
+        fn completeSuffix(&self) -> QString;
+
+        /// Returns a [`QDir`] object representing the path of the parent directory of the file system entry that this `QFileInfo` refers to.
+        ///
+        /// **Note:** The [`QDir`] returned always corresponds to the object's parent directory, even if this `QFileInfo` represents a directory.
+        fn dir(&self) -> QDir;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to exists; otherwise returns `false`.
+        ///
+        /// **Note:** If the file system entry is a symlink that points to a non-existing target, this method returns `false`.
+        fn exists(&self) -> bool;
+
+        /// Returns the name of the file system entry this `QFileInfo` refers to, excluding the path.
+        ///
+        /// **Note:** If this `QFileInfo` is given a path ending in a directory separator `'/'`, the entry's name part is considered empty.
+        #[rust_name = "file_name"]
+        fn fileName(&self) -> QString;
+
+        /// Returns the path of the file system entry this `QFileInfo` refers to; the path may be absolute or relative.
+        #[rust_name = "file_path"]
+        fn filePath(&self) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "file_time_or_invalid"]
+        fn fileTime(&self, time: QFileDeviceFileTime) -> QDateTime;
+
+        /// Returns the group of the file. On Windows, on systems where files do not have groups, or if an error occurs, an empty string is returned.
+        ///
+        /// This function can be time consuming under Unix (in the order of milliseconds).
+        ///
+        /// If the file is a symlink, this function returns the owning group of the target (not the symlink).
+        fn group(&self) -> QString;
+
+        /// Returns the id of the group the file belongs to.
+        ///
+        /// On Windows and on systems where files do not have groups this function always returns `u32::MAX - 1`.
+        ///
+        /// If the file is a symlink, this function returns the id of the group owning the target (not the symlink).
+        #[rust_name = "group_id"]
+        fn groupId(&self) -> u32;
+
+        /// Returns `true` if the file system entry's path is absolute, otherwise returns `false` (that is, the path is relative).
+        ///
+        /// **Note:** Paths starting with a colon (`':'`) are always considered absolute, as they denote a [`QResource`](https://doc.qt.io/qt-6/qresource.html).
+        #[rust_name = "is_absolute"]
+        fn isAbsolute(&self) -> bool;
+
+        /// Returns `true` if this object points to an alias; otherwise returns `false`.
+        ///
+        /// Aliases only exist on macOS. They are treated as regular files, so opening an alias will open the file itself.
+        ///
+        /// Introduced in Qt 6.4.
+        #[cfg(cxxqt_qt_version_at_least_6_4)]
+        #[rust_name = "is_alias"]
+        fn isAlias(&self) -> bool;
+
+        /// Returns `true` if this object points to a bundle or to a symbolic link to a bundle on macOS and iOS; otherwise returns `false`.
+        #[rust_name = "is_bundle"]
+        fn isBundle(&self) -> bool;
+
+        /// Returns `true` if this object points to a directory or to a symbolic link to a directory. If the file system entry pointed to by this object doesn't exist, this method returns `false`.
+        #[rust_name = "is_dir"]
+        fn isDir(&self) -> bool;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to is executable; otherwise returns `false`.
+        ///
+        /// If the file is a symlink, this function returns `true` if the target is executable (not the symlink).
+        #[rust_name = "is_executable"]
+        fn isExecutable(&self) -> bool;
+
+        /// Returns `true` if this object points to a file or to a symbolic link to a file. Returns `false` if the object points to something that is not a file (such as a directory) or that does not exist.
+        #[rust_name = "is_file"]
+        fn isFile(&self) -> bool;
+
+        /// Returns `true` if the file system entry this `QFileInfo` refers to is "hidden"; otherwise returns `false`.
+        ///
+        /// **Note:** This function returns `true` for the special entries `"."` and `".."` on Unix, even though [`QDir`] treats them as shown. And note that, since this function inspects the file name, on Unix it will inspect the name of the symlink, if this file is a symlink, not the target's name.
+        #[rust_name = "is_hidden"]
+        fn isHidden(&self) -> bool;
+
+        /// Returns `true` if the object points to a junction; otherwise returns `false`.
+        ///
+        /// Junctions only exist on Windows' NTFS file system, and are typically created by the `mklink` command. They can be thought of as symlinks for directories, and can only be created for absolute paths on the local volume.
+        #[rust_name = "is_junction"]
+        fn isJunction(&self) -> bool;
+
+        /// Returns `true` if the file path can be used directly with native APIs. Returns `false` if the file is otherwise supported by a virtual file system inside Qt, such as [the Qt Resource System](https://doc.qt.io/qt-6/resources.html).
+        ///
+        /// **Note:** Native paths may still require conversion of path separators and character encoding, depending on platform and input requirements of the native API.
+        #[rust_name = "is_native_path"]
+        fn isNativePath(&self) -> bool;
+
+        /// Returns `true` if the user can read the file system entry this `QFileInfo` refers to; otherwise returns `false`.
+        ///
+        /// If the file is a symlink, this function returns `true` if the target is readable (not the symlink).
+        #[rust_name = "is_readable"]
+        fn isReadable(&self) -> bool;
+
+        /// Returns `true` if the file system entry's path is relative, otherwise returns `false` (that is, the path is absolute).
+        #[rust_name = "is_relative"]
+        fn isRelative(&self) -> bool;
+
+        /// Returns `true` if the object points to a directory or to a symbolic link to a directory, and that directory is the root directory; otherwise returns `false`.
+        #[rust_name = "is_root"]
+        fn isRoot(&self) -> bool;
+
+        /// Returns `true` if this object points to a shortcut; otherwise returns `false`.
+        ///
+        /// Shortcuts only exist on Windows and are typically `.lnk` files.
+        #[rust_name = "is_shortcut"]
+        fn isShortcut(&self) -> bool;
+
+        /// Returns `true` if this object points to a symbolic link, shortcut, or alias; otherwise returns `false`.
+        ///
+        /// Symbolic links exist on Unix (including macOS and iOS) and Windows and are typically created by the `ln -s` or `mklink` commands, respectively. Opening a symbolic link effectively opens the link's target.
+        ///
+        /// In addition, true will be returned for shortcuts (`*.lnk` files) on Windows, and aliases on macOS. This behavior is deprecated and will likely change in a future version of Qt.
+        #[rust_name = "is_sym_link"]
+        fn isSymLink(&self) -> bool;
+
+        /// Returns `true` if this object points to a symbolic link; otherwise returns `false`.
+        ///
+        /// Unlike [`is_sym_link`](QFileInfo::is_sym_link), false will be returned for shortcuts (`*.lnk` files) on Windows and aliases on macOS.
+        #[rust_name = "is_symbolic_link"]
+        fn isSymbolicLink(&self) -> bool;
+
+        /// Returns `true` if the user can write to the file system entry this `QFileInfo` refers to; otherwise returns `false`.
+        ///
+        /// If the file is a symlink, this function returns `true` if the target is writeable (not the symlink).
+        #[rust_name = "is_writable"]
+        fn isWritable(&self) -> bool;
+
+        /// Resolves the junction to the path it references.
+        ///
+        /// Returns the absolute path to the target of a junction if the object points to one; otherwise returns an empty string.
+        ///
+        /// Introduced in Qt 6.2.
+        #[cfg(cxxqt_qt_version_at_least_6_2)]
+        #[rust_name = "junction_target"]
+        fn junctionTarget(&self) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "last_modified_or_invalid"]
+        fn lastModified(&self) -> QDateTime;
+
+        #[doc(hidden)]
+        #[rust_name = "last_read_or_invalid"]
+        fn lastRead(&self) -> QDateTime;
+
+        /// If the file system entry's path is relative, this method converts it to an absolute path and returns `true`; if the path is already absolute, this method returns `false`.
+        #[rust_name = "make_absolute"]
+        fn makeAbsolute(&mut self) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "metadata_change_time_or_invalid"]
+        fn metadataChangeTime(&self) -> QDateTime;
+
+        /// Returns the owner of the file. On systems where files do not have owners, or if an error occurs, an empty string is returned.
+        ///
+        /// This function can be time consuming under Unix (in the order of milliseconds). On Windows, it will return an empty string unless the NTFS permissions check has been enabled.
+        ///
+        /// If the file is a symlink, this function returns the owner of the target (not the symlink).
+        fn owner(&self) -> QString;
+
+        /// Returns the id of the owner of the file.
+        ///
+        /// On Windows and on systems where files do not have owners this function returns `u32::MAX - 1`.
+        ///
+        /// If the file is a symlink, this function returns the id of the owner of the target (not the symlink).
+        #[rust_name = "owner_id"]
+        fn ownerId(&self) -> u32;
+
+        /// Returns the path of the file system entry this `QFileInfo` refers to, excluding the entry's name.
+        ///
+        /// **Note:** If this `QFileInfo` is given a path ending in a directory separator `'/'`, the entry's name part is considered empty.
+        fn path(&self) -> QString;
+
+        /// Tests for file permissions. The `permissions` argument can be several flags of type [`QFileDevicePermission`](crate::QFileDevicePermission) OR-ed together to check for permission combinations.
+        ///
+        /// On systems where files do not have permissions this function always returns `true`.
+        ///
+        /// If the file is a symlink, this function checks the permissions of the target (not the symlink).
+        fn permission(&self, permissions: QFileDevicePermissions) -> bool;
+
+        /// Returns the complete OR-ed together combination of [`QFileDevicePermission`](crate::QFileDevicePermission)s for the file.
+        ///
+        /// If the file is a symlink, this function returns the permissions of the target (not the symlink).
+        fn permissions(&self) -> QFileDevicePermissions;
+
+        /// Refreshes the information about the file system entry this `QFileInfo` refers to, that is, reads in information from the file system the next time a cached property is fetched.
+        fn refresh(&mut self);
+
+        /// If `enable` is `true`, enables caching of file information. If `enable` is `false` caching is disabled.
+        ///
+        /// When caching is enabled, `QFileInfo` reads the file information from the file system the first time it's needed, but generally not later.
+        ///
+        /// Caching is enabled by default.
+        #[rust_name = "set_caching"]
+        fn setCaching(&mut self, enable: bool);
+
+        /// Sets the path of the file system entry that this `QFileInfo` provides information about to `path`, which can be absolute or relative.
+        #[rust_name = "set_file"]
+        fn setFile(&mut self, path: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "size_qint64"]
+        fn size(&self) -> qint64;
+
+        /// Reads all attributes from the file system.
+        ///
+        /// This is useful when information about the file system is collected in a worker thread, and then passed to the UI in the form of caching `QFileInfo` instances.
+        fn stat(&mut self);
+
+        /// Returns the suffix (extension) of the file.
+        ///
+        /// The suffix consists of all characters in the file after (but not including) the last `'.'`.
+        fn suffix(&self) -> QString;
+
+        /// Returns the absolute path to the file or directory a symbolic link points to, or an empty string if the object isn't a symbolic link.
+        ///
+        /// This name may not represent an existing file; it is only a string.
+        #[rust_name = "sym_link_target"]
+        fn symLinkTarget(&self) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qfileinfo_exists"]
+        fn qfileinfoExists(path: &QString) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qfileinfo_drop"]
+        fn drop(info: &mut QFileInfo);
+
+        #[rust_name = "qfileinfo_init_default"]
+        fn construct() -> QFileInfo;
+        #[rust_name = "qfileinfo_init_dir"]
+        fn construct(dir: &QDir, path: &QString) -> QFileInfo;
+        #[rust_name = "qfileinfo_init_file"]
+        fn construct(file: &QFileDevice) -> QFileInfo;
+        #[rust_name = "qfileinfo_init_path"]
+        fn construct(path: &QString) -> QFileInfo;
+        #[rust_name = "qfileinfo_clone"]
+        fn construct(other: &QFileInfo) -> QFileInfo;
+
+        #[rust_name = "qfileinfo_eq"]
+        fn operatorEq(a: &QFileInfo, b: &QFileInfo) -> bool;
+    }
+}
+
+/// The `QFileInfo` class provides an OS-independent API to retrieve information about file system entries.
+///
+/// Qt Documentation: [QFileInfo](https://doc.qt.io/qt-6/qfileinfo.html#details)
+#[repr(C)]
+pub struct QFileInfo {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QFileInfo {
+    fn clone(&self) -> Self {
+        ffi::qfileinfo_clone(self)
+    }
+}
+
+impl Default for QFileInfo {
+    /// Constructs an empty `QFileInfo` object that doesn't refer to any file system entry.
+    fn default() -> Self {
+        ffi::qfileinfo_init_default()
+    }
+}
+
+impl Drop for QFileInfo {
+    fn drop(&mut self) {
+        ffi::qfileinfo_drop(self);
+    }
+}
+
+impl PartialEq for QFileInfo {
+    /// Returns `true` if this `QFileInfo` refers to the same file system entry as `other`; otherwise returns `false`.
+    ///
+    /// If both `QFileInfo` objects refer to a file system entry that does not exist, they are considered different, even if their paths are the same.
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qfileinfo_eq(self, other)
+    }
+}
+
+impl fmt::Debug for QFileInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("QFileInfo").field(&self.file_path()).finish()
+    }
+}
+
+impl QFileInfo {
+    /// Constructs a `QFileInfo` that gives information about the file system entry at `path` that is relative to the directory `dir`.
+    ///
+    /// If `dir` has a relative path, the `QFileInfo` will also have a relative path.
+    ///
+    /// If `path` is absolute, then the directory specified by `dir` will be disregarded.
+    pub fn in_dir(dir: &QDir, path: &QString) -> Self {
+        ffi::qfileinfo_init_dir(dir, path)
+    }
+
+    /// Returns the date and time when the file system entry was created / born, or `None` if the birth time is not available.
+    ///
+    /// If the file system entry is a symlink, the time of the target is returned (not the symlink).
+    pub fn birth_time(&self) -> Option<QDateTime> {
+        self.birth_time_or_invalid().nonnull()
+    }
+
+    /// Returns the canonical path including the file name, i.e. an absolute path without symbolic links or redundant `"."` or `".."` elements.
+    ///
+    /// If the file system entry does not exist, returns `None` (Qt reports this as an empty string).
+    pub fn canonical_file_path(&self) -> Option<QString> {
+        let path = self.canonical_file_path_or_empty();
+        if path.is_empty() { None } else { Some(path) }
+    }
+
+    /// Returns the file system entry's path canonical path (excluding the entry's name), i.e. an absolute path without symbolic links or redundant `"."` or `".."` elements.
+    ///
+    /// If the file system entry does not exist, returns `None` (Qt reports this as an empty string).
+    pub fn canonical_path(&self) -> Option<QString> {
+        let path = self.canonical_path_or_empty();
+        if path.is_empty() { None } else { Some(path) }
+    }
+
+    /// Returns `true` if the file system entry at `path` exists; otherwise returns `false`.
+    ///
+    /// **Note:** If `path` is a symlink that points to a non-existing target, this method returns `false`.
+    ///
+    /// **Note:** Using this function is faster than using `QFileInfo::from(path).exists()` for file system access.
+    pub fn exists_at(path: &QString) -> bool {
+        ffi::qfileinfo_exists(path)
+    }
+
+    /// Returns the file time specified by `time`. If the time cannot be determined, returns `None`.
+    ///
+    /// If the file system entry is a symlink, the time of the target is returned (not the symlink).
+    pub fn file_time(&self, time: QFileDeviceFileTime) -> Option<QDateTime> {
+        self.file_time_or_invalid(time).nonnull()
+    }
+
+    /// Returns the date and time when the file system entry was last modified, or `None` if it cannot be determined.
+    ///
+    /// If the file system entry is a symlink, the time of the target is returned (not the symlink).
+    pub fn last_modified(&self) -> Option<QDateTime> {
+        self.last_modified_or_invalid().nonnull()
+    }
+
+    /// Returns the date and time when the file system entry was last read (accessed), or `None` if it cannot be determined.
+    ///
+    /// On platforms where this information is not available, returns the same value as [`last_modified`](QFileInfo::last_modified).
+    ///
+    /// If the file system entry is a symlink, the time of the target is returned (not the symlink).
+    pub fn last_read(&self) -> Option<QDateTime> {
+        self.last_read_or_invalid().nonnull()
+    }
+
+    /// Returns the date and time when the file system entry's metadata was last changed, or `None` if it cannot be determined. A metadata change occurs when the file is first created, but it also occurs whenever the user writes or sets inode information (for example, changing the file permissions).
+    ///
+    /// If the file system entry is a symlink, the time of the target is returned (not the symlink).
+    pub fn metadata_change_time(&self) -> Option<QDateTime> {
+        self.metadata_change_time_or_invalid().nonnull()
+    }
+
+    /// Returns the file size in bytes. If the file does not exist or cannot be fetched, 0 is returned.
+    ///
+    /// If the file is a symlink, the size of the target file is returned (not the symlink).
+    pub fn size(&self) -> i64 {
+        self.size_qint64().into()
+    }
+}
+
+impl From<&QString> for QFileInfo {
+    /// Constructs a `QFileInfo` that gives information about a file system entry located at `path` that can be absolute or relative.
+    ///
+    /// If `path` is relative, the `QFileInfo` will also have a relative path.
+    fn from(value: &QString) -> Self {
+        ffi::qfileinfo_init_path(value)
+    }
+}
+
+impl From<&QFileDevice> for QFileInfo {
+    /// Constructs a new `QFileInfo` that gives information about file `value`.
+    ///
+    /// If the file has a relative path, the `QFileInfo` will also have a relative path.
+    fn from(value: &QFileDevice) -> Self {
+        ffi::qfileinfo_init_file(value)
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QFileInfo {
+    type Id = type_id!("QFileInfo");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QFileInfoProps {
+            base_name: QString,
+            complete_base_name: QString,
+            complete_suffix: QString,
+            file_name: QString,
+            is_relative: bool,
+            path: QString,
+            suffix: QString,
+        }
+
+        let props = QFileInfoProps {
+            base_name: QString::from("archive"),
+            complete_base_name: QString::from("archive.tar"),
+            complete_suffix: QString::from("tar.gz"),
+            file_name: QString::from("archive.tar.gz"),
+            is_relative: true,
+            path: QString::from("nested/dir"),
+            suffix: QString::from("gz"),
+        };
+
+        let info = QFileInfo::from(&QString::from("nested/dir/archive.tar.gz"));
+
+        let actual_props = QFileInfoProps {
+            base_name: info.base_name(),
+            complete_base_name: info.complete_base_name(),
+            complete_suffix: info.complete_suffix(),
+            file_name: info.file_name(),
+            is_relative: info.is_relative(),
+            path: info.path(),
+            suffix: info.suffix(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+
+    #[test]
+    fn canonical_paths_of_missing_file() {
+        let info = QFileInfo::from(&QString::from("nested/dir/missing.txt"));
+        assert_eq!(
+            (info.canonical_file_path(), info.canonical_path()),
+            (None, None)
+        );
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+use std::ops::Deref;
+
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::QStringList;
+
+use crate::qobject::debug_qobject;
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+    }
+
+    extern "C++" {
+        include!("cxx-qt-io/qfilesystemwatcher.h");
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QFileSystemWatcher` class provides an interface for monitoring files and directories for modifications.
+        ///
+        /// `QFileSystemWatcher` monitors the file system for changes to files and directories by watching a list of specified paths.
+        ///
+        /// The [`file_changed`](QFileSystemWatcher::file_changed) signal is emitted when a file has been modified, renamed or removed from disk. Similarly, the [`directory_changed`](QFileSystemWatcher::directory_changed) signal is emitted when a directory or its contents is modified or removed. Note that `QFileSystemWatcher` stops monitoring files once they have been renamed or removed from disk, and directories once they have been removed from disk.
+        ///
+        /// **Note:** The act of monitoring files and directories for modifications consumes system resources. This implies there is a limit to the number of files and directories your process can monitor simultaneously. On all BSD variants, for example, an open file descriptor is required for each monitored file. Some system limits the number of open file descriptors to 256 by default. This means that [`add_path`](QFileSystemWatcher::add_path) and [`add_paths`](QFileSystemWatcher::add_paths) will fail if your process tries to add more than 256 files or directories to the file system monitor.
+        ///
+        /// Qt Documentation: [QFileSystemWatcher](https://doc.qt.io/qt-6/qfilesystemwatcher.html#details)
+        #[qobject]
+        #[base = QObject]
+        type QFileSystemWatcher;
+
+        /// Adds `path` to the file system watcher if `path` exists. The path is not added if it does not exist, or if it is already being monitored by the file system watcher.
+        ///
+        /// If `path` specifies a directory, the [`directory_changed`](QFileSystemWatcher::directory_changed) signal will be emitted when `path` is modified or removed from disk; otherwise the [`file_changed`](QFileSystemWatcher::file_changed) signal is emitted when `path` is modified, renamed or removed.
+        ///
+        /// If the watch was successful, `true` is returned.
+        ///
+        /// Reasons for a watch failure are generally system-dependent, but may include the resource not existing, access failures, or the total watch count limit, if the platform has one.
+        ///
+        /// **Note:** There may be a system dependent limit to the number of files and directories that can be monitored simultaneously. If this limit is been reached, `path` will not be monitored, and `false` is returned.
+        #[rust_name = "add_path"]
+        fn addPath(self: Pin<&mut QFileSystemWatcher>, path: &QString) -> bool;
+
+        /// Adds each path in `paths` to the file system watcher. Paths are not added if they do not exist, or if they are already being monitored by the file system watcher.
+        ///
+        /// If a path specifies a directory, the [`directory_changed`](QFileSystemWatcher::directory_changed) signal will be emitted when the path is modified or removed from disk; otherwise the [`file_changed`](QFileSystemWatcher::file_changed) signal is emitted when the path is modified, renamed, or removed.
+        ///
+        /// The return value is a list of paths that could not be watched.
+        ///
+        /// Reasons for a watch failure are generally system-dependent, but may include the resource not existing, access failures, or the total watch count limit, if the platform has one.
+        #[rust_name = "add_paths"]
+        fn addPaths(self: Pin<&mut QFileSystemWatcher>, paths: &QStringList) -> QStringList;
+
+        /// Returns a list of paths to directories that are being watched.
+        fn directories(self: &QFileSystemWatcher) -> QStringList;
+
+        /// Returns a list of paths to files that are being watched.
+        fn files(self: &QFileSystemWatcher) -> QStringList;
+
+        /// Removes the specified `path` from the file system watcher.
+        ///
+        /// If the watch is successfully removed, `true` is returned.
+        ///
+        /// Reasons for watch removal failing are generally system-dependent, but may be due to the path having already been deleted, for example.
+        #[rust_name = "remove_path"]
+        fn removePath(self: Pin<&mut QFileSystemWatcher>, path: &QString) -> bool;
+
+        /// Removes the specified `paths` from the file system watcher.
+        ///
+        /// The return value is a list of paths which were not able to be unwatched successfully.
+        ///
+        /// Reasons for watch removal failing are generally system-dependent, but may be due to the path having already been deleted, for example.
+        #[rust_name = "remove_paths"]
+        fn removePaths(self: Pin<&mut QFileSystemWatcher>, paths: &QStringList) -> QStringList;
+
+        /// This signal is emitted when the directory at a specified `path` is modified (e.g., when a file is added or deleted) or removed from disk. Note that if there are several changes during a short period of time, some of the changes might not emit this signal. However, the last change in the sequence of changes will always generate this signal.
+        #[qsignal]
+        #[rust_name = "directory_changed"]
+        fn directoryChanged(self: Pin<&mut QFileSystemWatcher>, path: &QString);
+
+        /// This signal is emitted when the file at the specified `path` is modified, renamed or removed from disk.
+        ///
+        /// **Note:** As a safety measure, many applications save an open file by writing a new file and then deleting the old one. In your slot function, you can check `watcher.files().contains(path)`. If it returns `false`, check whether the file still exists and then call [`add_path`](QFileSystemWatcher::add_path) to continue watching it.
+        #[qsignal]
+        #[rust_name = "file_changed"]
+        fn fileChanged(self: Pin<&mut QFileSystemWatcher>, path: &QString);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qfilesystemwatcher_init_default"]
+        fn make_unique() -> UniquePtr<QFileSystemWatcher>;
+        #[rust_name = "qfilesystemwatcher_init_paths"]
+        fn make_unique(paths: &QStringList) -> UniquePtr<QFileSystemWatcher>;
+    }
+}
+
+pub use ffi::QFileSystemWatcher;
+
+impl fmt::Debug for QFileSystemWatcher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QFileSystemWatcher {
+    /// Constructs a new file system watcher object.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qfilesystemwatcher_init_default()
+    }
+
+    /// Constructs a new file system watcher object which monitors the given `paths` list.
+    pub fn with_paths(paths: &QStringList) -> UniquePtr<Self> {
+        ffi::qfilesystemwatcher_init_paths(paths)
+    }
+}
+
+impl Deref for QFileSystemWatcher {
+    type Target = QObject;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cxx_qt_lib::{QList, QString};
+
+    use super::*;
+
+    #[test]
+    fn add_remove_paths() {
+        let dir = QString::from(std::env::temp_dir().to_str().unwrap());
+        let missing = QString::from("cxx-qt-io/nonexistent/path");
+
+        let mut watcher = QFileSystemWatcher::new();
+        let mut watcher = watcher.pin_mut();
+
+        let mut paths = QList::default();
+        paths.append_clone(&dir);
+        paths.append_clone(&missing);
+        let failed = QList::from(&watcher.as_mut().add_paths(&QStringList::from(&paths)));
+
+        let watched = QList::from(&watcher.directories());
+        let removed = watcher.as_mut().remove_path(&dir);
+        let remaining = QList::from(&watcher.directories());
+
+        assert_eq!(
+            (failed.contains(&missing), watched.contains(&dir), removed),
+            (true, true, true)
+        );
+        assert!(remaining.is_empty());
+    }
+}
@@ -0,0 +1,313 @@
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::time::Duration;
+
+use cxx::UniquePtr;
+use cxx_qt_lib::{QString, qint64};
+
+use crate::util::MSecs;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the result of the last call to [`QLockFile::lock`] or [`QLockFile::try_lock`].
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QLockFileLockError {
+        /// The lock was acquired successfully.
+        NoError = 0,
+        /// The lock could not be acquired because another process holds it.
+        LockFailedError = 1,
+        /// The lock file could not be created, for lack of permissions in the parent directory.
+        PermissionError = 2,
+        /// Another error happened, for instance a full partition prevented writing out the lock file.
+        UnknownError = 3,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = cxx_qt_lib::qint64;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qlockfile.h");
+        type QLockFileLockError;
+    }
+
+    unsafe extern "C++" {
+        /// The `QLockFile` class provides locking between processes using a file.
+        ///
+        /// A lock file can be used to prevent multiple processes from accessing concurrently the same resource. For instance, a configuration file on disk, or a socket, a port, a region of shared memory...
+        ///
+        /// Serialization is only guaranteed if all processes that access the shared resource use `QLockFile`, with the same file path.
+        ///
+        /// `QLockFile` supports two use cases: to protect a resource for a short-term operation (e.g. verifying if a configuration file has changed before saving new settings), and for long-lived protection of a resource (e.g. a document opened by a user in an editor) for an indefinite amount of time.
+        ///
+        /// When protecting for a short-term operation, it is acceptable to call [`lock`](QLockFile::lock) and wait until any running operation finishes. When protecting a resource over a long time, however, the application should always call [`set_stale_lock_time(Duration::ZERO)`](QLockFile::set_stale_lock_time) and then [`try_lock`](QLockFile::try_lock) with a short timeout, in order to warn the user that the resource is locked.
+        ///
+        /// If the process holding the lock crashes, the lock file stays on disk and can prevent any other process from accessing the shared resource, ever. For this reason, `QLockFile` tries to detect such a "stale" lock file, based on the process ID written into the file. To cover the situation that the process ID got reused meanwhile, the current process name is compared to the name of the process that corresponds to the process ID from the lock file. If the process names differ, the lock file is considered stale. Additionally, the last modification time of the lock file (30s by default, for the use case of a short-lived operation) is taken into account. If the lock file is found to be stale, it will be deleted.
+        ///
+        /// For the use case of protecting a resource over a long time, you should therefore call [`set_stale_lock_time(Duration::ZERO)`](QLockFile::set_stale_lock_time), and when [`try_lock`](QLockFile::try_lock) returns [`QLockFileLockError::LockFailedError`], inform the user that the document is locked, possibly using [`get_lock_info`](QLockFile::get_lock_info) for more details.
+        ///
+        /// **Note:** On Windows, `QLockFile` cannot detect whether the lock was created by another process on the same host. Therefore, it is recommended that lock files are not stored on network paths shared between Windows and other hosts.
+        ///
+        /// Qt Documentation: [QLockFile](https://doc.qt.io/qt-6/qlockfile.html#details)
+        type QLockFile;
+
+        /// Returns the lock file error status.
+        ///
+        /// If [`try_lock`](QLockFile::try_lock) returns `false`, this function can be called to find out the reason why the locking failed.
+        fn error(self: &QLockFile) -> QLockFileLockError;
+
+        /// Returns the file name of the lock file.
+        #[rust_name = "file_name"]
+        fn fileName(self: &QLockFile) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "get_lock_info_raw"]
+        unsafe fn getLockInfo(
+            self: &QLockFile,
+            pid: *mut qint64,
+            hostname: *mut QString,
+            appname: *mut QString,
+        ) -> bool;
+
+        /// Returns `true` if the lock was acquired by this `QLockFile` instance, otherwise returns `false`.
+        #[rust_name = "is_locked"]
+        fn isLocked(self: &QLockFile) -> bool;
+
+        /// Creates the lock file.
+        ///
+        /// If another process (or another thread) has created the lock file already, this function will block until that process (or thread) releases it.
+        ///
+        /// Calling this function multiple times on the same lock from the same thread without unlocking first is not allowed. This function will *dead-lock* when the file is locked recursively.
+        ///
+        /// Returns `true` if the lock was acquired, `false` if it could not be acquired due to an unrecoverable error, such as no permissions in the parent directory.
+        fn lock(self: Pin<&mut QLockFile>) -> bool;
+
+        /// Attempts to forcefully remove an existing lock file.
+        ///
+        /// Calling this is not recommended when protecting a short-lived operation: `QLockFile` already takes care of removing lock files after they are older than [`stale_lock_time`](QLockFile::stale_lock_time).
+        ///
+        /// This method should only be called when protecting a resource for a long time, i.e. with [`stale_lock_time`](QLockFile::stale_lock_time) set to zero, and after [`try_lock`](QLockFile::try_lock) returned [`QLockFileLockError::LockFailedError`], and the user agreed on removing the lock file.
+        ///
+        /// Returns `true` on success, `false` if the lock file couldn't be removed. This happens on Windows, when the application owning the lock is still running.
+        #[rust_name = "remove_stale_lock_file"]
+        fn removeStaleLockFile(self: Pin<&mut QLockFile>) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "set_stale_lock_time_msecs"]
+        fn setStaleLockTime(self: Pin<&mut QLockFile>, stale_lock_time: i32);
+
+        #[doc(hidden)]
+        #[rust_name = "stale_lock_time_msecs"]
+        fn staleLockTime(self: &QLockFile) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "try_lock_msecs"]
+        fn tryLock(self: Pin<&mut QLockFile>, timeout: i32) -> bool;
+
+        /// Releases the lock, by deleting the lock file.
+        ///
+        /// Calling `unlock()` without locking the file first, does nothing.
+        fn unlock(self: Pin<&mut QLockFile>);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlockfile_new"]
+        fn make_unique(file_name: &QString) -> UniquePtr<QLockFile>;
+    }
+}
+
+pub use ffi::{QLockFile, QLockFileLockError};
+
+impl fmt::Debug for QLockFile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QLockFile")
+            .field("file_name", &self.file_name())
+            .field("is_locked", &self.is_locked())
+            .finish()
+    }
+}
+
+impl QLockFile {
+    /// Constructs a new lock file object. The object is created in an unlocked state. When calling [`lock`](QLockFile::lock) or [`try_lock`](QLockFile::try_lock), a lock file named `file_name` will be created, if it doesn't already exist.
+    pub fn new(file_name: &QString) -> UniquePtr<Self> {
+        ffi::qlockfile_new(file_name)
+    }
+
+    /// Retrieves information about the current owner of the lock file.
+    ///
+    /// If [`try_lock`](QLockFile::try_lock) returns `false`, and [`error`](QLockFile::error) returns [`QLockFileLockError::LockFailedError`], this function can be called to find out more information about the existing lock file:
+    ///
+    /// * the PID of the application (returned in [`LockInfo::pid`]),
+    /// * the hostname it's running on (returned in [`LockInfo::hostname`], useful in case of networked filesystems),
+    /// * the name of the application which created it (returned in [`LockInfo::appname`]).
+    ///
+    /// Note that [`try_lock`](QLockFile::try_lock) automatically deleted the file if there is no running application with this PID, so [`QLockFileLockError::LockFailedError`] can only happen if there is an application with this PID (it could be unrelated though).
+    ///
+    /// This can be used to inform users about the existing lock file and give them the choice to delete it. After removing the file using [`remove_stale_lock_file`](QLockFile::remove_stale_lock_file), the application can call [`try_lock`](QLockFile::try_lock) again.
+    ///
+    /// This function returns `None` if the file couldn't be read, for instance because it doesn't exist.
+    pub fn get_lock_info(&self) -> Option<LockInfo> {
+        let mut pid = qint64::from(0);
+        let mut hostname = QString::default();
+        let mut appname = QString::default();
+        // SAFETY: The pointers are valid for the duration of the call.
+        let found = unsafe { self.get_lock_info_raw(&mut pid, &mut hostname, &mut appname) };
+        if !found {
+            return None;
+        }
+        Some(LockInfo {
+            pid: pid.into(),
+            hostname,
+            appname,
+        })
+    }
+
+    /// Acquires the lock, as with [`lock`](QLockFile::lock), and returns a guard that releases it when dropped.
+    ///
+    /// Returns `None` if the lock could not be acquired. In that case, [`error`](QLockFile::error) describes the reason.
+    pub fn lock_guard(mut self: Pin<&mut Self>) -> Option<QLockFileGuard<'_>> {
+        if self.as_mut().lock() {
+            Some(QLockFileGuard { lock_file: self })
+        } else {
+            None
+        }
+    }
+
+    /// Sets `stale_lock_time` to be the time after which a lock file is considered stale. The default value is 30 seconds.
+    ///
+    /// If your application typically keeps the file locked for more than 30 seconds (for instance while saving megabytes of data for 2 minutes), you should set a bigger value using `set_stale_lock_time`.
+    ///
+    /// The value of `stale_lock_time` is used by [`lock`](QLockFile::lock) and [`try_lock`](QLockFile::try_lock) in order to determine when an existing lock file is considered stale, i.e. left over by a crashed process. This is useful for the case where the PID got reused meanwhile, so one way to detect a stale lock file is by the fact that it has been around for a long time.
+    ///
+    /// Use [`Duration::ZERO`] to disable stale lock detection.
+    pub fn set_stale_lock_time(self: Pin<&mut Self>, stale_lock_time: Duration) {
+        self.set_stale_lock_time_msecs(stale_lock_time.msecs());
+    }
+
+    /// Returns the time after which a lock file is considered stale.
+    pub fn stale_lock_time(&self) -> Duration {
+        let msecs = self.stale_lock_time_msecs();
+        Duration::from_millis(u64::try_from(msecs).unwrap_or_default())
+    }
+
+    /// Attempts to create the lock file. This function returns `true` if the lock was obtained; otherwise it returns `false`. If another process (or another thread) has created the lock file already, this function will wait for at most `timeout` for the lock file to become available.
+    ///
+    /// If `timeout` is [`Duration::ZERO`], this function returns immediately if the lock file is held by another process.
+    ///
+    /// If the lock was obtained, it must be released with [`unlock`](QLockFile::unlock) before another `QLockFile` instance can successfully lock the same file name.
+    pub fn try_lock(self: Pin<&mut Self>, timeout: Duration) -> bool {
+        self.try_lock_msecs(timeout.msecs())
+    }
+
+    /// Attempts to acquire the lock, as with [`try_lock`](QLockFile::try_lock), and returns a guard that releases it when dropped.
+    ///
+    /// Returns `None` if the lock could not be acquired within `timeout`. In that case, [`error`](QLockFile::error) describes the reason.
+    pub fn try_lock_guard(
+        mut self: Pin<&mut Self>,
+        timeout: Duration,
+    ) -> Option<QLockFileGuard<'_>> {
+        if self.as_mut().try_lock(timeout) {
+            Some(QLockFileGuard { lock_file: self })
+        } else {
+            None
+        }
+    }
+}
+
+/// Information about the current owner of a lock file, as returned by [`QLockFile::get_lock_info`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockInfo {
+    /// The PID of the application that holds the lock.
+    pub pid: i64,
+    /// The hostname of the machine the application is running on.
+    pub hostname: QString,
+    /// The name of the application that created the lock file.
+    pub appname: QString,
+}
+
+/// An RAII guard for a locked [`QLockFile`]. The lock is released when the guard is dropped.
+///
+/// Created by [`QLockFile::lock_guard`] and [`QLockFile::try_lock_guard`].
+#[must_use = "if unused the QLockFile will immediately unlock"]
+pub struct QLockFileGuard<'a> {
+    lock_file: Pin<&'a mut QLockFile>,
+}
+
+impl Deref for QLockFileGuard<'_> {
+    type Target = QLockFile;
+
+    fn deref(&self) -> &Self::Target {
+        &self.lock_file
+    }
+}
+
+impl Drop for QLockFileGuard<'_> {
+    fn drop(&mut self) {
+        self.lock_file.as_mut().unlock();
+    }
+}
+
+impl fmt::Debug for QLockFileGuard<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("QLockFileGuard")
+            .field(&*self.lock_file)
+            .finish()
+    }
+}
+
+impl fmt::Display for QLockFileLockError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            Self::NoError => "no error",
+            Self::LockFailedError => "lock held by another process",
+            Self::PermissionError => "permission denied",
+            Self::UnknownError => "unknown error",
+            _ => "unknown",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lock_guard() {
+        let path = std::env::temp_dir().join("cxx-qt-io-qlockfile.lock");
+        let path = QString::from(path.to_str().unwrap());
+
+        let mut lock_file = QLockFile::new(&path);
+        let mut other = QLockFile::new(&path);
+
+        let guard = lock_file.pin_mut().lock_guard().unwrap();
+        let lock_info = guard.get_lock_info().map(|info| info.pid);
+        let locked_elsewhere = other.pin_mut().try_lock(Duration::ZERO);
+        let other_error = other.error();
+        drop(guard);
+
+        assert_eq!(
+            (
+                lock_info,
+                locked_elsewhere,
+                other_error,
+                lock_file.is_locked()
+            ),
+            (
+                Some(i64::from(std::process::id())),
+                false,
+                QLockFileLockError::LockFailedError,
+                false
+            )
+        );
+    }
+}
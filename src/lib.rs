@@ -17,13 +17,19 @@
 //! Beware though that this will pull in many extra dependencies that you may not
 //! need.
 //!
-//! - `full`: Enables all features listed below except `link_qt_object_files`.
+//! - `full`: Enables all features listed below except `futures_io`, `json`, `link_qt_object_files`, and `websockets`.
 //! - `qt_core`: Enables all features for the `QtCore` module (i.e. `fs`).
 //! - `qt_network`: Enables all features for the `QtNetwork` module (i.e. `net`, `request`, and `ssl`).
-//! - `fs`: Bindings for [`QDir`], [`QFile`], [`QSaveFile`], and [`QTemporaryFile`].
-//! - `net`: Bindings for [`QLocalSocket`], [`QTcpServer`], [`QTcpSocket`], and [`QUdpSocket`].
+//! - `fs`: Bindings for [`QDir`], [`QDirIterator`], [`QFile`], [`QFileInfo`], [`QFileSystemWatcher`], [`QLockFile`], [`QSaveFile`], and [`QTemporaryFile`].
+//! - `net`: Bindings for [`QDnsLookup`], [`QHostInfo`], [`QLocalServer`], [`QLocalSocket`], [`QTcpServer`], [`QTcpSocket`], and [`QUdpSocket`].
 //! - `request`: Bindings for [`QNetworkAccessManager`], [`QNetworkRequest`], and [`QNetworkReply`].
 //! - `ssl`: Bindings for [`QSslServer`] and [`QSslSocket`].
+//! - `websockets`: Enables `request` and adds bindings for [`QWebSocket`] and [`QWebSocketServer`]
+//!   from the `QtWebSockets` module. Requires Qt 6.2 or later.
+//! - `futures_io`: Implements [`futures_io::AsyncRead`] and [`futures_io::AsyncWrite`] for
+//!   [`QIODevice`] through the [`AsyncIODevice`] adapter.
+//! - `json`: Enables `request` and implements JSON request bodies for [`RequestBuilder`] via
+//!   [`serde`] and [`serde_json`].
 //! - `link_qt_object_files`: Sets the `link_qt_object_files` feature flag for `cxx-qt-build`.
 //!   This is required for static linking.
 
@@ -59,4 +65,9 @@ mod ssl;
 #[cfg(feature = "ssl")]
 pub use ssl::*;
 
+#[cfg(feature = "websockets")]
+mod websockets;
+#[cfg(feature = "websockets")]
+pub use websockets::*;
+
 mod util;
@@ -9,17 +9,41 @@ pub use qabstractsocket::{
 mod qauthenticator;
 pub use qauthenticator::QAuthenticator;
 
+mod qdnsdomainnamerecord;
+pub use qdnsdomainnamerecord::QDnsDomainNameRecord;
+
+mod qdnshostaddressrecord;
+pub use qdnshostaddressrecord::QDnsHostAddressRecord;
+
+mod qdnslookup;
+pub use qdnslookup::{QDnsLookup, QDnsLookupError, QDnsLookupType};
+
+mod qdnsmailexchangerecord;
+pub use qdnsmailexchangerecord::QDnsMailExchangeRecord;
+
+mod qdnsservicerecord;
+pub use qdnsservicerecord::QDnsServiceRecord;
+
+mod qdnstextrecord;
+pub use qdnstextrecord::QDnsTextRecord;
+
 mod qhostaddress;
 pub use qhostaddress::{
     QHostAddress, QHostAddressConversionMode, QHostAddressConversionModeFlag,
     QHostAddressSpecialAddress, QHostAddressTryFromError,
 };
 
+mod qhostinfo;
+pub use qhostinfo::{QHostInfo, QHostInfoHostInfoError};
+
 #[cfg(cxxqt_qt_version_at_least_6_7)]
 mod qhttpheaders;
 #[cfg(cxxqt_qt_version_at_least_6_7)]
 pub use qhttpheaders::{HttpHeader, QHttpHeaders, QHttpHeadersWellKnownHeader};
 
+mod qlocalserver;
+pub use qlocalserver::{QLocalServer, QLocalServerSocketOption, QLocalServerSocketOptions};
+
 mod qlocalsocket;
 pub use qlocalsocket::{QLocalSocket, QLocalSocketLocalSocketError, QLocalSocketLocalSocketState};
 #[cfg(cxxqt_qt_version_at_least_6_2)]
@@ -31,6 +55,14 @@ pub use qnetworkaddressentry::{QNetworkAddressEntry, QNetworkAddressEntryDnsElig
 mod qnetworkdatagram;
 pub use qnetworkdatagram::QNetworkDatagram;
 
+mod qnetworkinformation;
+#[cfg(cxxqt_qt_version_at_least_6_3)]
+pub use qnetworkinformation::QNetworkInformationTransportMedium;
+pub use qnetworkinformation::{
+    QNetworkInformation, QNetworkInformationFeature, QNetworkInformationFeatures,
+    QNetworkInformationReachability,
+};
+
 mod qnetworkinterface;
 pub use qnetworkinterface::{
     QNetworkInterface, QNetworkInterfaceInterfaceFlag, QNetworkInterfaceInterfaceFlags,
@@ -42,6 +74,12 @@ pub use qnetworkproxy::{
     QNetworkProxy, QNetworkProxyCapabilities, QNetworkProxyCapability, QNetworkProxyProxyType,
 };
 
+pub(crate) mod qnetworkproxyfactory;
+pub use qnetworkproxyfactory::{NetworkProxyFactory, QNetworkProxyFactory};
+
+mod qnetworkproxyquery;
+pub use qnetworkproxyquery::{QNetworkProxyQuery, QNetworkProxyQueryQueryType};
+
 mod qnetworkrequestknownheaders;
 pub use qnetworkrequestknownheaders::QNetworkRequestKnownHeaders;
 
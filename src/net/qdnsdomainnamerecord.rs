@@ -0,0 +1,83 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use cxx::{ExternType, type_id};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qdnsdomainnamerecord.h");
+        type QDnsDomainNameRecord = super::QDnsDomainNameRecord;
+
+        /// Returns the name for this record.
+        fn name(&self) -> QString;
+
+        /// Returns the duration in seconds for which this record is valid.
+        #[rust_name = "time_to_live"]
+        fn timeToLive(&self) -> u32;
+
+        /// Returns the value for this domain name record.
+        fn value(&self) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qdnsdomainnamerecord_drop"]
+        fn drop(record: &mut QDnsDomainNameRecord);
+
+        #[rust_name = "qdnsdomainnamerecord_init_default"]
+        fn construct() -> QDnsDomainNameRecord;
+        #[rust_name = "qdnsdomainnamerecord_clone"]
+        fn construct(other: &QDnsDomainNameRecord) -> QDnsDomainNameRecord;
+    }
+}
+
+/// The `QDnsDomainNameRecord` class stores information about a domain name record.
+///
+/// Qt Documentation: [QDnsDomainNameRecord](https://doc.qt.io/qt-6/qdnsdomainnamerecord.html#details)
+#[repr(C)]
+pub struct QDnsDomainNameRecord {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QDnsDomainNameRecord {
+    fn clone(&self) -> Self {
+        ffi::qdnsdomainnamerecord_clone(self)
+    }
+}
+
+impl Default for QDnsDomainNameRecord {
+    /// Constructs an empty domain name record object.
+    fn default() -> Self {
+        ffi::qdnsdomainnamerecord_init_default()
+    }
+}
+
+impl Drop for QDnsDomainNameRecord {
+    fn drop(&mut self) {
+        ffi::qdnsdomainnamerecord_drop(self);
+    }
+}
+
+impl fmt::Debug for QDnsDomainNameRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QDnsDomainNameRecord")
+            .field("name", &self.name())
+            .field("time_to_live", &self.time_to_live())
+            .field("value", &self.value())
+            .finish()
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDnsDomainNameRecord {
+    type Id = type_id!("QDnsDomainNameRecord");
+    type Kind = cxx::kind::Trivial;
+}
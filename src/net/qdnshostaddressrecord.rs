@@ -0,0 +1,85 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use cxx::{ExternType, type_id};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-io/qhostaddress.h");
+        type QHostAddress = crate::QHostAddress;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qdnshostaddressrecord.h");
+        type QDnsHostAddressRecord = super::QDnsHostAddressRecord;
+
+        /// Returns the name for this record.
+        fn name(&self) -> QString;
+
+        /// Returns the duration in seconds for which this record is valid.
+        #[rust_name = "time_to_live"]
+        fn timeToLive(&self) -> u32;
+
+        /// Returns the value for this host address record.
+        fn value(&self) -> QHostAddress;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qdnshostaddressrecord_drop"]
+        fn drop(record: &mut QDnsHostAddressRecord);
+
+        #[rust_name = "qdnshostaddressrecord_init_default"]
+        fn construct() -> QDnsHostAddressRecord;
+        #[rust_name = "qdnshostaddressrecord_clone"]
+        fn construct(other: &QDnsHostAddressRecord) -> QDnsHostAddressRecord;
+    }
+}
+
+/// The `QDnsHostAddressRecord` class stores information about a host address record.
+///
+/// Qt Documentation: [QDnsHostAddressRecord](https://doc.qt.io/qt-6/qdnshostaddressrecord.html#details)
+#[repr(C)]
+pub struct QDnsHostAddressRecord {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QDnsHostAddressRecord {
+    fn clone(&self) -> Self {
+        ffi::qdnshostaddressrecord_clone(self)
+    }
+}
+
+impl Default for QDnsHostAddressRecord {
+    /// Constructs an empty host address record object.
+    fn default() -> Self {
+        ffi::qdnshostaddressrecord_init_default()
+    }
+}
+
+impl Drop for QDnsHostAddressRecord {
+    fn drop(&mut self) {
+        ffi::qdnshostaddressrecord_drop(self);
+    }
+}
+
+impl fmt::Debug for QDnsHostAddressRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QDnsHostAddressRecord")
+            .field("name", &self.name())
+            .field("time_to_live", &self.time_to_live())
+            .field("value", &self.value())
+            .finish()
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDnsHostAddressRecord {
+    type Id = type_id!("QDnsHostAddressRecord");
+    type Kind = cxx::kind::Trivial;
+}
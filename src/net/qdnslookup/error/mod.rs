@@ -0,0 +1,9 @@
+#[cfg(cxxqt_qt_version_at_least_6_6)]
+mod v6_6;
+#[cfg(cxxqt_qt_version_at_least_6_6)]
+pub use v6_6::QDnsLookupError;
+
+#[cfg(not(cxxqt_qt_version_at_least_6_6))]
+mod v6_1;
+#[cfg(not(cxxqt_qt_version_at_least_6_6))]
+pub use v6_1::QDnsLookupError;
@@ -0,0 +1,33 @@
+#[cxx::bridge]
+mod ffi {
+    /// Indicates all possible error conditions found during the processing of the DNS lookup.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QDnsLookupError {
+        /// No error condition.
+        NoError,
+        /// There was an error initializing the system's DNS resolver.
+        ResolverError,
+        /// The lookup was aborted using the [`QDnsLookup::abort`](crate::QDnsLookup::abort) method.
+        OperationCancelledError,
+        /// The requested DNS lookup was invalid.
+        InvalidRequestError,
+        /// The reply returned by the server was invalid.
+        InvalidReplyError,
+        /// The server encountered an internal failure while processing the request (SERVFAIL).
+        ServerFailureError,
+        /// The server refused to process the request for security or policy reasons (REFUSED).
+        ServerRefusedError,
+        /// The requested domain name does not exist (NXDOMAIN).
+        NotFoundError,
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qdnslookup.h");
+        type QDnsLookupError;
+    }
+}
+
+pub use ffi::QDnsLookupError;
@@ -0,0 +1,340 @@
+use std::fmt;
+use std::ops::Deref;
+
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::QString;
+
+use crate::QHostAddress;
+use crate::qobject::debug_qobject;
+use crate::util::IsNonNull;
+
+mod error;
+pub use error::QDnsLookupError;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Indicates the type of DNS lookup that was performed.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QDnsLookupType {
+        /// IPv4 address records.
+        A = 1,
+        /// IPv6 address records.
+        AAAA = 28,
+        /// Any records.
+        ANY = 255,
+        /// Canonical name records.
+        CNAME = 5,
+        /// Mail exchange records.
+        MX = 15,
+        /// Name server records.
+        NS = 2,
+        /// Pointer records.
+        PTR = 12,
+        /// Service records.
+        SRV = 33,
+        /// Text records.
+        TXT = 16,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-io/qhostaddress.h");
+        type QHostAddress = crate::QHostAddress;
+
+        include!("cxx-qt-io/qlist.h");
+        type QList_QDnsDomainNameRecord = cxx_qt_lib::QList<crate::QDnsDomainNameRecord>;
+        type QList_QDnsHostAddressRecord = cxx_qt_lib::QList<crate::QDnsHostAddressRecord>;
+        type QList_QDnsMailExchangeRecord = cxx_qt_lib::QList<crate::QDnsMailExchangeRecord>;
+        type QList_QDnsServiceRecord = cxx_qt_lib::QList<crate::QDnsServiceRecord>;
+        type QList_QDnsTextRecord = cxx_qt_lib::QList<crate::QDnsTextRecord>;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qdnslookup.h");
+        type QDnsLookupError = super::QDnsLookupError;
+        type QDnsLookupType;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QDnsLookup` class represents a DNS lookup.
+        ///
+        /// `QDnsLookup` uses the mechanisms provided by the operating system to perform DNS lookups. To perform a lookup you need to specify a [`name`](QDnsLookup::name) and [`type`](QDnsLookup::lookup_type) then invoke the [`lookup`](QDnsLookup::lookup) slot. The [`finished`](QDnsLookup::finished) signal will be emitted upon completion.
+        ///
+        /// The records can then be retrieved with the accessor for the record type, such as [`service_records`](QDnsLookup::service_records).
+        ///
+        /// Qt Documentation: [QDnsLookup](https://doc.qt.io/qt-6/qdnslookup.html#details)
+        #[qobject]
+        #[base = QObject]
+        type QDnsLookup;
+
+        /// Aborts the DNS lookup operation.
+        ///
+        /// If the lookup is already finished, does nothing.
+        fn abort(self: Pin<&mut QDnsLookup>);
+
+        /// Returns the list of canonical name records associated with this lookup.
+        #[rust_name = "canonical_name_records"]
+        fn canonicalNameRecords(self: &QDnsLookup) -> QList_QDnsDomainNameRecord;
+
+        /// Returns the type of error that occurred if the DNS lookup failed, or [`QDnsLookupError::NoError`].
+        fn error(self: &QDnsLookup) -> QDnsLookupError;
+
+        /// Returns a human-readable description of the error if the DNS lookup failed.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QDnsLookup) -> QString;
+
+        /// Returns the list of host address records associated with this lookup.
+        #[rust_name = "host_address_records"]
+        fn hostAddressRecords(self: &QDnsLookup) -> QList_QDnsHostAddressRecord;
+
+        /// Returns whether the reply has finished or was aborted.
+        #[rust_name = "is_finished"]
+        fn isFinished(self: &QDnsLookup) -> bool;
+
+        /// Performs the DNS lookup. The [`finished`](QDnsLookup::finished) signal is emitted upon completion.
+        fn lookup(self: Pin<&mut QDnsLookup>);
+
+        /// Returns the type of DNS records to lookup.
+        #[cxx_name = "type"]
+        fn lookup_type(self: &QDnsLookup) -> QDnsLookupType;
+
+        /// Returns the list of mail exchange records associated with this lookup.
+        ///
+        /// The records are sorted according to [RFC 5321](https://datatracker.ietf.org/doc/html/rfc5321), so if you use them to connect to servers, you should try them in the order they are listed.
+        #[rust_name = "mail_exchange_records"]
+        fn mailExchangeRecords(self: &QDnsLookup) -> QList_QDnsMailExchangeRecord;
+
+        /// Returns the name to lookup.
+        ///
+        /// If the name to look up is empty, `QDnsLookup` will attempt to resolve the root domain of DNS. That query is usually performed with [`lookup_type`](QDnsLookup::lookup_type) set to [`QDnsLookupType::NS`].
+        fn name(self: &QDnsLookup) -> QString;
+
+        /// Returns the list of name server records associated with this lookup.
+        #[rust_name = "name_server_records"]
+        fn nameServerRecords(self: &QDnsLookup) -> QList_QDnsDomainNameRecord;
+
+        #[doc(hidden)]
+        #[rust_name = "nameserver_or_null"]
+        fn nameserver(self: &QDnsLookup) -> QHostAddress;
+
+        /// Returns the port number of nameserver to use for DNS lookups. If the value is 0, the default port for the protocol will be used.
+        ///
+        /// Introduced in Qt 6.6.
+        #[cfg(cxxqt_qt_version_at_least_6_6)]
+        #[rust_name = "nameserver_port"]
+        fn nameserverPort(self: &QDnsLookup) -> u16;
+
+        /// Returns the list of pointer records associated with this lookup.
+        #[rust_name = "pointer_records"]
+        fn pointerRecords(self: &QDnsLookup) -> QList_QDnsDomainNameRecord;
+
+        /// Returns the list of service records associated with this lookup.
+        ///
+        /// The records are sorted according to [RFC 2782](https://datatracker.ietf.org/doc/html/rfc2782), so if you use them to connect to servers, you should try them in the order they are listed.
+        #[rust_name = "service_records"]
+        fn serviceRecords(self: &QDnsLookup) -> QList_QDnsServiceRecord;
+
+        /// Sets the type of DNS records to lookup.
+        #[rust_name = "set_lookup_type"]
+        fn setType(self: Pin<&mut QDnsLookup>, lookup_type: QDnsLookupType);
+
+        /// Sets the name to lookup.
+        #[rust_name = "set_name"]
+        fn setName(self: Pin<&mut QDnsLookup>, name: &QString);
+
+        /// Sets the nameserver to use for DNS lookups. If `nameserver` is null, the system's default nameservers are used.
+        #[rust_name = "set_nameserver"]
+        fn setNameserver(self: Pin<&mut QDnsLookup>, nameserver: &QHostAddress);
+
+        /// Sets the nameserver to use for DNS lookups to `nameserver`, and the port to `port`. If `port` is 0, the default port for the protocol will be used.
+        ///
+        /// Introduced in Qt 6.6.
+        #[cfg(cxxqt_qt_version_at_least_6_6)]
+        #[rust_name = "set_nameserver_with_port"]
+        fn setNameserver(self: Pin<&mut QDnsLookup>, nameserver: &QHostAddress, port: u16);
+
+        /// Sets the port number of nameserver to use for DNS lookups to `port`. If `port` is 0, the default port for the protocol will be used.
+        ///
+        /// Introduced in Qt 6.6.
+        #[cfg(cxxqt_qt_version_at_least_6_6)]
+        #[rust_name = "set_nameserver_port"]
+        fn setNameserverPort(self: Pin<&mut QDnsLookup>, port: u16);
+
+        /// Returns the list of text records associated with this lookup.
+        #[rust_name = "text_records"]
+        fn textRecords(self: &QDnsLookup) -> QList_QDnsTextRecord;
+
+        /// This signal is emitted when the reply has finished processing.
+        #[qsignal]
+        fn finished(self: Pin<&mut QDnsLookup>);
+
+        /// This signal is emitted when the lookup name changes. `name` is the new lookup name.
+        #[qsignal]
+        #[rust_name = "name_changed"]
+        fn nameChanged(self: Pin<&mut QDnsLookup>, name: &QString);
+
+        /// This signal is emitted when the lookup nameserver changes. `nameserver` is the new nameserver.
+        #[qsignal]
+        #[rust_name = "nameserver_changed"]
+        fn nameserverChanged(self: Pin<&mut QDnsLookup>, nameserver: &QHostAddress);
+
+        /// This signal is emitted when the lookup nameserver port changes. `port` is the new nameserver port.
+        ///
+        /// Introduced in Qt 6.6.
+        #[cfg(cxxqt_qt_version_at_least_6_6)]
+        #[qsignal]
+        #[rust_name = "nameserver_port_changed"]
+        fn nameserverPortChanged(self: Pin<&mut QDnsLookup>, port: u16);
+
+        /// This signal is emitted when the lookup type changes. `lookup_type` is the new lookup type.
+        #[qsignal]
+        #[rust_name = "lookup_type_changed"]
+        fn typeChanged(self: Pin<&mut QDnsLookup>, lookup_type: QDnsLookupType);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qdnslookup_new"]
+        fn make_unique(lookup_type: QDnsLookupType, name: &QString) -> UniquePtr<QDnsLookup>;
+
+        #[rust_name = "qdnslookup_new_with_nameserver"]
+        fn make_unique(
+            lookup_type: QDnsLookupType,
+            name: &QString,
+            nameserver: &QHostAddress,
+        ) -> UniquePtr<QDnsLookup>;
+
+        #[cfg(cxxqt_qt_version_at_least_6_6)]
+        #[rust_name = "qdnslookup_new_with_nameserver_port"]
+        fn make_unique(
+            lookup_type: QDnsLookupType,
+            name: &QString,
+            nameserver: &QHostAddress,
+            port: u16,
+        ) -> UniquePtr<QDnsLookup>;
+    }
+}
+
+pub use ffi::{QDnsLookup, QDnsLookupType};
+
+impl fmt::Debug for QDnsLookup {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QDnsLookup {
+    /// Constructs a `QDnsLookup` object for the given `lookup_type` and `name`, using the system's default nameservers.
+    pub fn new(lookup_type: QDnsLookupType, name: &QString) -> UniquePtr<Self> {
+        ffi::qdnslookup_new(lookup_type, name)
+    }
+
+    /// Constructs a `QDnsLookup` object for the given `lookup_type` and `name`, that will send queries to `nameserver` instead of the system's default nameservers.
+    pub fn with_nameserver(
+        lookup_type: QDnsLookupType,
+        name: &QString,
+        nameserver: &QHostAddress,
+    ) -> UniquePtr<Self> {
+        ffi::qdnslookup_new_with_nameserver(lookup_type, name, nameserver)
+    }
+
+    /// Constructs a `QDnsLookup` object for the given `lookup_type` and `name`, that will send queries to `nameserver` on `port` instead of the system's default nameservers.
+    ///
+    /// Introduced in Qt 6.6.
+    #[cfg(cxxqt_qt_version_at_least_6_6)]
+    pub fn with_nameserver_port(
+        lookup_type: QDnsLookupType,
+        name: &QString,
+        nameserver: &QHostAddress,
+        port: u16,
+    ) -> UniquePtr<Self> {
+        ffi::qdnslookup_new_with_nameserver_port(lookup_type, name, nameserver, port)
+    }
+
+    /// Returns the nameserver to use for DNS lookups, or `None` if the system's default nameservers are used.
+    pub fn nameserver(&self) -> Option<QHostAddress> {
+        self.nameserver_or_null().nonnull()
+    }
+}
+
+impl Deref for QDnsLookup {
+    type Target = QObject;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+impl fmt::Display for QDnsLookupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            Self::NoError => "no error",
+            Self::ResolverError => "resolver error",
+            Self::OperationCancelledError => "operation cancelled",
+            Self::InvalidRequestError => "invalid request",
+            Self::InvalidReplyError => "invalid reply",
+            Self::ServerFailureError => "server failure",
+            Self::ServerRefusedError => "server refused",
+            Self::NotFoundError => "not found",
+            #[cfg(cxxqt_qt_version_at_least_6_6)]
+            Self::TimeoutError => "timeout",
+            _ => "unknown error",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QDnsLookupProps {
+            lookup_type: QDnsLookupType,
+            name: QString,
+            nameserver: Option<QHostAddress>,
+            #[cfg(cxxqt_qt_version_at_least_6_6)]
+            nameserver_port: u16,
+        }
+
+        let props = QDnsLookupProps {
+            lookup_type: QDnsLookupType::SRV,
+            name: QString::from("_xmpp-client._tcp.example.com"),
+            nameserver: Some(QHostAddress::from(Ipv4Addr::LOCALHOST)),
+            #[cfg(cxxqt_qt_version_at_least_6_6)]
+            nameserver_port: 5353,
+        };
+
+        let mut lookup = QDnsLookup::new(QDnsLookupType::A, &QString::default());
+
+        lookup.pin_mut().set_lookup_type(props.lookup_type);
+        lookup.pin_mut().set_name(&props.name);
+        if let Some(nameserver) = &props.nameserver {
+            lookup.pin_mut().set_nameserver(nameserver);
+        }
+        #[cfg(cxxqt_qt_version_at_least_6_6)]
+        lookup.pin_mut().set_nameserver_port(props.nameserver_port);
+
+        let actual_props = QDnsLookupProps {
+            lookup_type: lookup.lookup_type(),
+            name: lookup.name(),
+            nameserver: lookup.nameserver(),
+            #[cfg(cxxqt_qt_version_at_least_6_6)]
+            nameserver_port: lookup.nameserver_port(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+}
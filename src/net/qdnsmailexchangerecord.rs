@@ -0,0 +1,89 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use cxx::{ExternType, type_id};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qdnsmailexchangerecord.h");
+        type QDnsMailExchangeRecord = super::QDnsMailExchangeRecord;
+
+        /// Returns the domain name of the mail exchange for this record.
+        fn exchange(&self) -> QString;
+
+        /// Returns the name for this record.
+        fn name(&self) -> QString;
+
+        /// Returns the preference for this record.
+        fn preference(&self) -> u16;
+
+        /// Returns the duration in seconds for which this record is valid.
+        #[rust_name = "time_to_live"]
+        fn timeToLive(&self) -> u32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qdnsmailexchangerecord_drop"]
+        fn drop(record: &mut QDnsMailExchangeRecord);
+
+        #[rust_name = "qdnsmailexchangerecord_init_default"]
+        fn construct() -> QDnsMailExchangeRecord;
+        #[rust_name = "qdnsmailexchangerecord_clone"]
+        fn construct(other: &QDnsMailExchangeRecord) -> QDnsMailExchangeRecord;
+    }
+}
+
+/// The `QDnsMailExchangeRecord` class stores information about a DNS MX record.
+///
+/// When performing a lookup on a service, multiple records might be returned. To select which one to use, the records are sorted by [`preference`](QDnsMailExchangeRecord::preference), lowest first.
+///
+/// Qt Documentation: [QDnsMailExchangeRecord](https://doc.qt.io/qt-6/qdnsmailexchangerecord.html#details)
+#[repr(C)]
+pub struct QDnsMailExchangeRecord {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QDnsMailExchangeRecord {
+    fn clone(&self) -> Self {
+        ffi::qdnsmailexchangerecord_clone(self)
+    }
+}
+
+impl Default for QDnsMailExchangeRecord {
+    /// Constructs an empty mail exchange record object.
+    fn default() -> Self {
+        ffi::qdnsmailexchangerecord_init_default()
+    }
+}
+
+impl Drop for QDnsMailExchangeRecord {
+    fn drop(&mut self) {
+        ffi::qdnsmailexchangerecord_drop(self);
+    }
+}
+
+impl fmt::Debug for QDnsMailExchangeRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QDnsMailExchangeRecord")
+            .field("name", &self.name())
+            .field("exchange", &self.exchange())
+            .field("preference", &self.preference())
+            .field("time_to_live", &self.time_to_live())
+            .finish()
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDnsMailExchangeRecord {
+    type Id = type_id!("QDnsMailExchangeRecord");
+    type Kind = cxx::kind::Trivial;
+}
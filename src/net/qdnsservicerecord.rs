@@ -0,0 +1,101 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use cxx::{ExternType, type_id};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qdnsservicerecord.h");
+        type QDnsServiceRecord = super::QDnsServiceRecord;
+
+        /// Returns the name for this record.
+        fn name(&self) -> QString;
+
+        /// Returns the port on the target host for this service record.
+        fn port(&self) -> u16;
+
+        /// Returns the priority for this service record.
+        ///
+        /// A client must attempt to contact the target host with the lowest-numbered priority.
+        fn priority(&self) -> u16;
+
+        /// Returns the domain name of the target host for this service record.
+        fn target(&self) -> QString;
+
+        /// Returns the duration in seconds for which this record is valid.
+        #[rust_name = "time_to_live"]
+        fn timeToLive(&self) -> u32;
+
+        /// Returns the weight for this service record.
+        ///
+        /// The weight field specifies a relative weight for entries with the same priority. Entries with higher weights should be selected with a higher probability.
+        fn weight(&self) -> u16;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qdnsservicerecord_drop"]
+        fn drop(record: &mut QDnsServiceRecord);
+
+        #[rust_name = "qdnsservicerecord_init_default"]
+        fn construct() -> QDnsServiceRecord;
+        #[rust_name = "qdnsservicerecord_clone"]
+        fn construct(other: &QDnsServiceRecord) -> QDnsServiceRecord;
+    }
+}
+
+/// The `QDnsServiceRecord` class stores information about a DNS SRV record.
+///
+/// When performing a lookup on a service, multiple records might be returned. To select which one to use, the records are sorted by ascending [`priority`](QDnsServiceRecord::priority), and then by descending [`weight`](QDnsServiceRecord::weight).
+///
+/// Qt Documentation: [QDnsServiceRecord](https://doc.qt.io/qt-6/qdnsservicerecord.html#details)
+#[repr(C)]
+pub struct QDnsServiceRecord {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QDnsServiceRecord {
+    fn clone(&self) -> Self {
+        ffi::qdnsservicerecord_clone(self)
+    }
+}
+
+impl Default for QDnsServiceRecord {
+    /// Constructs an empty service record object.
+    fn default() -> Self {
+        ffi::qdnsservicerecord_init_default()
+    }
+}
+
+impl Drop for QDnsServiceRecord {
+    fn drop(&mut self) {
+        ffi::qdnsservicerecord_drop(self);
+    }
+}
+
+impl fmt::Debug for QDnsServiceRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QDnsServiceRecord")
+            .field("name", &self.name())
+            .field("target", &self.target())
+            .field("port", &self.port())
+            .field("priority", &self.priority())
+            .field("weight", &self.weight())
+            .field("time_to_live", &self.time_to_live())
+            .finish()
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDnsServiceRecord {
+    type Id = type_id!("QDnsServiceRecord");
+    type Kind = cxx::kind::Trivial;
+}
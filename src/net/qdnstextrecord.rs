@@ -0,0 +1,87 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use cxx::{ExternType, type_id};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qlist.h");
+        type QList_QByteArray = cxx_qt_lib::QList<QByteArray>;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qdnstextrecord.h");
+        type QDnsTextRecord = super::QDnsTextRecord;
+
+        /// Returns the name for this text record.
+        fn name(&self) -> QString;
+
+        /// Returns the duration in seconds for which this record is valid.
+        #[rust_name = "time_to_live"]
+        fn timeToLive(&self) -> u32;
+
+        /// Returns the values for this text record.
+        fn values(&self) -> QList_QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qdnstextrecord_drop"]
+        fn drop(record: &mut QDnsTextRecord);
+
+        #[rust_name = "qdnstextrecord_init_default"]
+        fn construct() -> QDnsTextRecord;
+        #[rust_name = "qdnstextrecord_clone"]
+        fn construct(other: &QDnsTextRecord) -> QDnsTextRecord;
+    }
+}
+
+/// The `QDnsTextRecord` class stores information about a DNS TXT record.
+///
+/// Qt Documentation: [QDnsTextRecord](https://doc.qt.io/qt-6/qdnstextrecord.html#details)
+#[repr(C)]
+pub struct QDnsTextRecord {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QDnsTextRecord {
+    fn clone(&self) -> Self {
+        ffi::qdnstextrecord_clone(self)
+    }
+}
+
+impl Default for QDnsTextRecord {
+    /// Constructs an empty text record object.
+    fn default() -> Self {
+        ffi::qdnstextrecord_init_default()
+    }
+}
+
+impl Drop for QDnsTextRecord {
+    fn drop(&mut self) {
+        ffi::qdnstextrecord_drop(self);
+    }
+}
+
+impl fmt::Debug for QDnsTextRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QDnsTextRecord")
+            .field("name", &self.name())
+            .field("values", &self.values())
+            .field("time_to_live", &self.time_to_live())
+            .finish()
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QDnsTextRecord {
+    type Id = type_id!("QDnsTextRecord");
+    type Kind = cxx::kind::Trivial;
+}
@@ -0,0 +1,284 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::net::IpAddr;
+
+use cxx::{ExternType, type_id};
+use cxx_qt_lib::{QList, QString};
+
+use crate::QHostAddress;
+
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the various errors that can occur while trying to resolve a host name.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QHostInfoHostInfoError {
+        /// The lookup was successful.
+        NoError,
+        /// No IP addresses were found for the host.
+        HostNotFound,
+        /// An unknown error occurred.
+        UnknownError,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-io/qlist.h");
+        type QList_QHostAddress = cxx_qt_lib::QList<crate::QHostAddress>;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qhostinfo.h");
+        type QHostInfoHostInfoError;
+    }
+
+    unsafe extern "C++" {
+        type QHostInfo = super::QHostInfo;
+
+        /// Aborts the host lookup with the ID `lookup_id`, as returned by [`QHostInfo::lookup_host`].
+        #[Self = "QHostInfo"]
+        #[rust_name = "abort_host_lookup"]
+        fn abortHostLookup(lookup_id: i32);
+
+        /// Returns the list of IP addresses associated with [`host_name`](QHostInfo::host_name). This list may be empty.
+        fn addresses(&self) -> QList_QHostAddress;
+
+        /// Returns the type of error that occurred if the host name lookup failed; otherwise returns [`QHostInfoHostInfoError::NoError`].
+        fn error(&self) -> QHostInfoHostInfoError;
+
+        /// If the lookup failed, this function returns a human readable description of the error; otherwise "Unknown error" is returned.
+        #[rust_name = "error_string"]
+        fn errorString(&self) -> QString;
+
+        /// Looks up the IP address(es) for the given host `name`. The function blocks during the lookup which means that execution of the program is suspended until the results of the lookup are ready. Returns the result of the lookup in a `QHostInfo` object.
+        ///
+        /// If you pass a literal IP address to `name` instead of a host name, `QHostInfo` will search for the domain name for the IP (i.e., `QHostInfo` will perform a reverse lookup). On success, the returned `QHostInfo` will contain both the resolved domain name and IP addresses for the host name.
+        ///
+        /// **Note:** If you want to use this function to look up many IP addresses, consider using [`QHostInfo::lookup_host`] instead, which does not block.
+        #[Self = "QHostInfo"]
+        #[rust_name = "from_name"]
+        fn fromName(name: &QString) -> QHostInfo;
+
+        /// Returns the name of the host whose IP addresses were looked up.
+        #[rust_name = "host_name"]
+        fn hostName(&self) -> QString;
+
+        /// Returns the DNS domain of this machine.
+        ///
+        /// **Note:** DNS domains are not related to domain names found in Windows networks.
+        #[Self = "QHostInfo"]
+        #[rust_name = "local_domain_name"]
+        fn localDomainName() -> QString;
+
+        /// Returns this machine's host name, if one is configured. Note that hostnames are not guaranteed to be globally unique, especially if they were configured automatically.
+        ///
+        /// This function does not guarantee the returned host name is a Fully Qualified Domain Name (FQDN). For that, use [`QHostInfo::from_name`] to resolve the returned name to an FQDN.
+        #[Self = "QHostInfo"]
+        #[rust_name = "local_host_name"]
+        fn localHostName() -> QString;
+
+        /// Returns the ID of this lookup.
+        #[rust_name = "lookup_id"]
+        fn lookupId(&self) -> i32;
+
+        /// Sets the list of addresses in this `QHostInfo` to `addresses`.
+        #[rust_name = "set_addresses"]
+        fn setAddresses(&mut self, addresses: &QList_QHostAddress);
+
+        /// Sets the error type of this `QHostInfo` to `error`.
+        #[rust_name = "set_error"]
+        fn setError(&mut self, error: QHostInfoHostInfoError);
+
+        /// Sets the human readable description of the error that occurred to `error_string` if the lookup failed.
+        #[rust_name = "set_error_string"]
+        fn setErrorString(&mut self, error_string: &QString);
+
+        /// Sets the host name of this `QHostInfo` to `host_name`.
+        #[rust_name = "set_host_name"]
+        fn setHostName(&mut self, host_name: &QString);
+
+        /// Sets the ID of this lookup to `id`.
+        #[rust_name = "set_lookup_id"]
+        fn setLookupId(&mut self, id: i32);
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "Rust" {
+        type QHostInfoLookupCallback;
+
+        #[cxx_name = "qhostinfoLookupCallbackInvoke"]
+        fn qhostinfo_lookup_callback_invoke(
+            callback: &mut QHostInfoLookupCallback,
+            info: &QHostInfo,
+        );
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qhostinfo_lookup_host"]
+        fn qhostinfoLookupHost(name: &QString, callback: Box<QHostInfoLookupCallback>) -> i32;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qhostinfo_drop"]
+        fn drop(info: &mut QHostInfo);
+
+        #[rust_name = "qhostinfo_init_default"]
+        fn construct() -> QHostInfo;
+        #[rust_name = "qhostinfo_clone"]
+        fn construct(other: &QHostInfo) -> QHostInfo;
+    }
+}
+
+pub use ffi::QHostInfoHostInfoError;
+
+/// The `QHostInfo` class provides static functions for host name lookups.
+///
+/// Qt Documentation: [QHostInfo](https://doc.qt.io/qt-6/qhostinfo.html#details)
+#[repr(C)]
+pub struct QHostInfo {
+    _space: MaybeUninit<usize>,
+}
+
+struct QHostInfoLookupCallback(Option<Box<dyn FnOnce(&QHostInfo)>>);
+
+fn qhostinfo_lookup_callback_invoke(callback: &mut QHostInfoLookupCallback, info: &QHostInfo) {
+    if let Some(callback) = callback.0.take() {
+        callback(info);
+    }
+}
+
+impl Clone for QHostInfo {
+    fn clone(&self) -> Self {
+        ffi::qhostinfo_clone(self)
+    }
+}
+
+impl Default for QHostInfo {
+    /// Constructs an empty host info object.
+    fn default() -> Self {
+        ffi::qhostinfo_init_default()
+    }
+}
+
+impl Drop for QHostInfo {
+    fn drop(&mut self) {
+        ffi::qhostinfo_drop(self);
+    }
+}
+
+impl fmt::Debug for QHostInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QHostInfo")
+            .field("host_name", &self.host_name())
+            .field("addresses", &self.addresses())
+            .field("error", &self.error())
+            .finish()
+    }
+}
+
+impl QHostInfo {
+    /// Returns the list of IP addresses associated with [`host_name`](QHostInfo::host_name), converted to Rust addresses. This list may be empty.
+    pub fn ip_addresses(&self) -> Vec<IpAddr> {
+        self.addresses().iter().map(IpAddr::from).collect()
+    }
+
+    /// Looks up the IP address(es) associated with host name `name`, and returns an ID for the lookup. When the result of the lookup is ready, `callback` is called with a `QHostInfo` argument. The `QHostInfo` object can then be inspected to get the results of the lookup.
+    ///
+    /// The lookup is performed asynchronously. `callback` is run in the thread that calls this function, which must have a running Qt event loop.
+    ///
+    /// If you pass a literal IP address to `name` instead of a host name, `QHostInfo` will search for the domain name for the IP (i.e., `QHostInfo` will perform a reverse lookup). On success, the resulting `QHostInfo` will contain both the resolved domain name and IP addresses for the host name.
+    ///
+    /// **Note:** There is no guarantee on the order the signals will be emitted if you start multiple requests with `lookup_host`.
+    pub fn lookup_host<F>(name: &QString, callback: F) -> i32
+    where
+        F: FnOnce(&QHostInfo) + 'static,
+    {
+        let callback = QHostInfoLookupCallback(Some(Box::new(callback)));
+        ffi::qhostinfo_lookup_host(name, Box::new(callback))
+    }
+
+    /// Sets the list of addresses in this `QHostInfo` to `addresses`, converted from Rust addresses.
+    pub fn set_ip_addresses<I>(&mut self, addresses: I)
+    where
+        I: IntoIterator<Item = IpAddr>,
+    {
+        let mut list = QList::default();
+        for address in addresses {
+            list.append(QHostAddress::from(address));
+        }
+        self.set_addresses(&list);
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QHostInfo {
+    type Id = type_id!("QHostInfo");
+    type Kind = cxx::kind::Trivial;
+}
+
+impl fmt::Display for QHostInfoHostInfoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.pad(match *self {
+            Self::NoError => "no error",
+            Self::HostNotFound => "host not found",
+            Self::UnknownError => "unknown error",
+            _ => "unknown",
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use super::*;
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QHostInfoProps {
+            addresses: Vec<IpAddr>,
+            error: QHostInfoHostInfoError,
+            error_string: QString,
+            host_name: QString,
+            lookup_id: i32,
+        }
+
+        let props = QHostInfoProps {
+            addresses: vec![
+                IpAddr::V4(Ipv4Addr::new(192, 168, 0, 16)),
+                IpAddr::V6(Ipv6Addr::LOCALHOST),
+            ],
+            error: QHostInfoHostInfoError::HostNotFound,
+            error_string: QString::from("error"),
+            host_name: QString::from("host"),
+            lookup_id: 10,
+        };
+
+        let mut info = QHostInfo::default();
+
+        info.set_ip_addresses(props.addresses.iter().copied());
+        info.set_error(props.error);
+        info.set_error_string(&props.error_string);
+        info.set_host_name(&props.host_name);
+        info.set_lookup_id(props.lookup_id);
+
+        let actual_props = QHostInfoProps {
+            addresses: info.ip_addresses(),
+            error: info.error(),
+            error_string: info.error_string(),
+            host_name: info.host_name(),
+            lookup_id: info.lookup_id(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+}
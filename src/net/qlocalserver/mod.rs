@@ -0,0 +1,303 @@
+use std::ops::Deref;
+use std::pin::Pin;
+use std::time::Duration;
+use std::{fmt, ptr};
+
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QFlags, QString};
+
+use crate::qobject::debug_qobject;
+use crate::util::{IsNonNull, MSecs};
+use crate::{QLocalSocket, SocketDescriptor};
+
+mod socket_option;
+pub use socket_option::QLocalServerSocketOption;
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qintptr = cxx_qt_lib::qintptr;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qabstractsocket.h");
+        type QAbstractSocketSocketError = crate::QAbstractSocketSocketError;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qlocalserver.h");
+        type QLocalServerSocketOptions = super::QLocalServerSocketOptions;
+    }
+
+    extern "C++" {
+        type QLocalSocket = crate::QLocalSocket;
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QLocalServer` class provides a local socket based server.
+        ///
+        /// Qt Documentation: [QLocalServer](https://doc.qt.io/qt-6/qlocalserver.html#details)
+        #[qobject]
+        #[base = QObject]
+        type QLocalServer;
+
+        /// Stop listening for incoming connections. Existing connections are not affected, but any new connections will be refused.
+        fn close(self: Pin<&mut QLocalServer>);
+
+        /// Returns the human-readable message appropriate to the current error reported by [`server_error`](QLocalServer::server_error). If no suitable string is available, an empty string is returned.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QLocalServer) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "full_server_name_or_empty"]
+        fn fullServerName(self: &QLocalServer) -> QString;
+
+        /// Returns `true` if the server has a pending connection; otherwise returns `false`.
+        #[rust_name = "has_pending_connections"]
+        fn hasPendingConnections(self: &QLocalServer) -> bool;
+
+        /// Returns `true` if the server is listening for incoming connections otherwise `false`.
+        #[rust_name = "is_listening"]
+        fn isListening(self: &QLocalServer) -> bool;
+
+        /// Tells the server to listen for incoming connections on `name`. If the server is currently listening then it will return `false`. Return `true` on success otherwise `false`.
+        ///
+        /// `name` can be a single name and `QLocalServer` will determine the correct platform specific path. [`full_server_name`](QLocalServer::full_server_name) will return the name that is passed into the underlying system.
+        ///
+        /// Usually you would just pass in a name like "foo", but on Unix this could also be a path such as "/tmp/foo" and on Windows this could be a pipe path such as "\\\\.\\pipe\\foo".
+        ///
+        /// **Note:** On Unix if the server crashes without closing listen will fail with [`QAbstractSocketSocketError::AddressInUseError`](crate::QAbstractSocketSocketError::AddressInUseError). To create a new server the file should be removed. On Windows two local servers can listen to the same pipe at the same time, but any connections will go to one of the server.
+        fn listen(self: Pin<&mut QLocalServer>, name: &QString) -> bool;
+
+        /// Returns the backlog queue size of to be accepted connections.
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[rust_name = "listen_backlog_size"]
+        fn listenBacklogSize(self: &QLocalServer) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "listen_qintptr"]
+        fn listen(self: Pin<&mut QLocalServer>, socket_descriptor: qintptr) -> bool;
+
+        /// Returns the maximum number of pending accepted connections. The default is 30.
+        #[rust_name = "max_pending_connections"]
+        fn maxPendingConnections(self: &QLocalServer) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "next_pending_connection_raw"]
+        fn nextPendingConnection(self: Pin<&mut QLocalServer>) -> *mut QLocalSocket;
+
+        /// Returns an error code for the last error that occurred.
+        #[rust_name = "server_error"]
+        fn serverError(self: &QLocalServer) -> QAbstractSocketSocketError;
+
+        #[doc(hidden)]
+        #[rust_name = "server_name_or_empty"]
+        fn serverName(self: &QLocalServer) -> QString;
+
+        /// Sets the backlog queue size of to be accepted connections to `size`. The operating system might reduce or ignore this value. By default, the queue size is 50.
+        ///
+        /// **Note:** This property must be set prior to calling [`listen`](QLocalServer::listen).
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[rust_name = "set_listen_backlog_size"]
+        fn setListenBacklogSize(self: Pin<&mut QLocalServer>, size: i32);
+
+        /// Sets the maximum number of pending accepted connections to `num_connections`. `QLocalServer` will accept no more than `num_connections` incoming connections before [`next_pending_connection`](QLocalServer::next_pending_connection) is called.
+        ///
+        /// Note: Even though `QLocalServer` will stop accepting new connections after it has reached its maximum number of pending connections, the operating system may still keep them in queue which will result in clients signaling that it is connected.
+        #[rust_name = "set_max_pending_connections"]
+        fn setMaxPendingConnections(self: Pin<&mut QLocalServer>, num_connections: i32);
+
+        /// Sets the socket options to `options`. Options must be set before [`listen`](QLocalServer::listen) is called.
+        ///
+        /// In some cases, such as with Unix domain sockets on Linux, access to the socket will be determined by file system permissions, and are created based on the umask. Setting the access flags will override this and will restrict or permit access as specified.
+        ///
+        /// Other Unix-based operating systems, such as macOS, do not honor file permissions for Unix domain sockets and by default have [`QLocalServerSocketOption::WorldAccessOption`] and none of the access restrictions apply.
+        #[rust_name = "set_socket_options"]
+        fn setSocketOptions(self: Pin<&mut QLocalServer>, options: QLocalServerSocketOptions);
+
+        #[doc(hidden)]
+        #[rust_name = "socket_descriptor_or_negative"]
+        fn socketDescriptor(self: &QLocalServer) -> qintptr;
+
+        /// Returns the socket options set on the socket.
+        #[rust_name = "socket_options"]
+        fn socketOptions(self: &QLocalServer) -> QLocalServerSocketOptions;
+
+        /// # Safety
+        ///
+        /// `timed_out` must be valid or null.
+        #[doc(hidden)]
+        #[rust_name = "wait_for_new_connection_msec"]
+        unsafe fn waitForNewConnection(
+            self: Pin<&mut QLocalServer>,
+            msec: i32,
+            timed_out: *mut bool,
+        ) -> bool;
+
+        /// This signal is emitted every time a new connection is available.
+        #[qsignal]
+        #[rust_name = "new_connection"]
+        fn newConnection(self: Pin<&mut QLocalServer>);
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qlocalserver_remove_server"]
+        fn qlocalserverRemoveServer(name: &QString) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qlocalserver_init_default"]
+        fn make_unique() -> UniquePtr<QLocalServer>;
+    }
+}
+
+pub use ffi::QLocalServer;
+
+/// [`QFlags`] of [`QLocalServerSocketOption`].
+pub type QLocalServerSocketOptions = QFlags<QLocalServerSocketOption>;
+
+unsafe_impl_qflag!(
+    QLocalServerSocketOption,
+    "rust::cxxqtio1::QLocalServerSocketOptions"
+);
+
+impl fmt::Debug for QLocalServer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QLocalServer {
+    /// Create a new local socket server.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qlocalserver_init_default()
+    }
+
+    /// Returns the full path that the server is listening on, or `None` if the server is not listening.
+    ///
+    /// **Note:** This is platform specific.
+    pub fn full_server_name(&self) -> Option<QString> {
+        self.full_server_name_or_empty().nonnull()
+    }
+
+    /// Instructs the server to listen for incoming connections on `socket_descriptor`. The function returns `false` if the server is already listening, or if the file descriptor `socket_descriptor` is not a Unix domain socket, or if [`listen`](QLocalServer::listen) fails. On success, returns `true`.
+    ///
+    /// The socket must be ready to accept new connections with no extra platform-specific functions called (for example, the socket is already in listening state).
+    ///
+    /// **Note:** This function is only supported on Unix platforms.
+    pub fn listen_socket_descriptor(
+        self: Pin<&mut Self>,
+        socket_descriptor: SocketDescriptor,
+    ) -> bool {
+        self.listen_qintptr(socket_descriptor.into())
+    }
+
+    /// Returns the next pending connection as a connected [`QLocalSocket`] object.
+    ///
+    /// A null pointer is returned if this function is called when there are no pending connections.
+    pub fn next_pending_connection(self: Pin<&mut Self>) -> UniquePtr<QLocalSocket> {
+        let conn = self.next_pending_connection_raw();
+        // SAFETY: `conn` is valid and Qt expects us to delete it when done.
+        unsafe { UniquePtr::from_raw(conn) }
+    }
+
+    /// Removes any server instance that might cause a call to [`listen`](QLocalServer::listen) to fail and returns `true` if successful; otherwise returns `false`. This function is meant to recover from a crash, when the previous server instance has not been cleaned up.
+    ///
+    /// On Windows, this function does nothing; on Unix, it removes the socket file given by `name`.
+    ///
+    /// **Warning:** Be careful to avoid removing sockets of running instances.
+    pub fn remove_server(name: &QString) -> bool {
+        ffi::qlocalserver_remove_server(name)
+    }
+
+    /// Returns the server name if the server is listening for connections; otherwise returns `None`.
+    pub fn server_name(&self) -> Option<QString> {
+        self.server_name_or_empty().nonnull()
+    }
+
+    /// Returns the native socket descriptor the server uses to listen for incoming instructions, or `None` if the server is not listening.
+    ///
+    /// The type of the descriptor depends on the platform: on Windows, the returned value is a Winsock 2 Socket Handle; on Unix, the returned value is a file descriptor.
+    pub fn socket_descriptor(&self) -> Option<SocketDescriptor> {
+        SocketDescriptor::from(self.socket_descriptor_or_negative()).nonnull()
+    }
+
+    /// Waits for at most `duration` or until an incoming connection is available. Returns `true` if a connection is available; otherwise returns `false`.
+    ///
+    /// This is a blocking function call. Its use is disadvised in a single-threaded GUI application, since the whole application will stop responding until the function returns. This function is mostly useful when there is no event loop available.
+    ///
+    /// The non-blocking alternative is to connect to the [`new_connection`](QLocalServer::new_connection) signal.
+    ///
+    /// If `duration` is `None`, this function will not time out.
+    pub fn wait_for_new_connection(self: Pin<&mut Self>, duration: Option<Duration>) -> bool {
+        // SAFETY: Qt ignores the null pointer.
+        unsafe { self.wait_for_new_connection_msec(duration.msecs(), ptr::null_mut()) }
+    }
+}
+
+impl Deref for QLocalServer {
+    type Target = QObject;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QLocalServerProps {
+            #[cfg(cxxqt_qt_version_at_least_6_3)]
+            listen_backlog_size: i32,
+            max_pending_connections: i32,
+            socket_options: QLocalServerSocketOptions,
+        }
+
+        let props = QLocalServerProps {
+            #[cfg(cxxqt_qt_version_at_least_6_3)]
+            listen_backlog_size: 10,
+            max_pending_connections: 15,
+            socket_options: QLocalServerSocketOption::UserAccessOption
+                | QLocalServerSocketOption::GroupAccessOption,
+        };
+
+        let mut server = QLocalServer::new();
+
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        server
+            .pin_mut()
+            .set_listen_backlog_size(props.listen_backlog_size);
+        server
+            .pin_mut()
+            .set_max_pending_connections(props.max_pending_connections);
+        server.pin_mut().set_socket_options(props.socket_options);
+
+        let actual_props = QLocalServerProps {
+            #[cfg(cxxqt_qt_version_at_least_6_3)]
+            listen_backlog_size: server.listen_backlog_size(),
+            max_pending_connections: server.max_pending_connections(),
+            socket_options: server.socket_options(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+}
@@ -0,0 +1,27 @@
+#[cxx::bridge]
+mod ffi {
+    /// This enum describes the possible options that can be used to create the socket. This changes the access permissions on platforms (Linux, Windows) that support access permissions on the socket. Both GroupAccess and OtherAccess may vary slightly in meanings depending on the platform.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QLocalServerSocketOption {
+        /// No access restrictions have been set.
+        NoOptions = 0x0,
+        /// Access is restricted to the same user as the process that created the socket.
+        UserAccessOption = 0x01,
+        /// Access is restricted to the same group but not the user that created the socket on Linux. Access is restricted to the primary group of the process on Windows.
+        GroupAccessOption = 0x2,
+        /// Access is available to everyone but the user and group that created the socket on Linux. Access is available to everyone on Windows.
+        OtherAccessOption = 0x4,
+        /// No access restrictions.
+        WorldAccessOption = 0x7,
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qlocalserver.h");
+        type QLocalServerSocketOption;
+    }
+}
+
+pub use ffi::QLocalServerSocketOption;
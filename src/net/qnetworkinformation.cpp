@@ -0,0 +1,26 @@
+#include "cxx-qt-io/qnetworkinformation.h"
+
+namespace rust {
+namespace cxxqtio1 {
+bool
+qnetworkinformationLoadBackendByFeatures(QNetworkInformationFeatures features)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 4, 0))
+  return QNetworkInformation::loadBackendByFeatures(features);
+#else
+  return QNetworkInformation::load(features);
+#endif
+}
+
+bool
+qnetworkinformationLoadBackendByName(const QString& backend)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 4, 0))
+  return QNetworkInformation::loadBackendByName(backend);
+#else
+  return QNetworkInformation::load(backend);
+#endif
+}
+
+}
+}
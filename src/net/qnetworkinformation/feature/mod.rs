@@ -0,0 +1,14 @@
+#[cfg(cxxqt_qt_version_at_least_6_3)]
+mod v6_3;
+#[cfg(cxxqt_qt_version_at_least_6_3)]
+pub use v6_3::QNetworkInformationFeature;
+
+#[cfg(all(cxxqt_qt_version_at_least_6_2, not(cxxqt_qt_version_at_least_6_3)))]
+mod v6_2;
+#[cfg(all(cxxqt_qt_version_at_least_6_2, not(cxxqt_qt_version_at_least_6_3)))]
+pub use v6_2::QNetworkInformationFeature;
+
+#[cfg(not(cxxqt_qt_version_at_least_6_2))]
+mod v6_1;
+#[cfg(not(cxxqt_qt_version_at_least_6_2))]
+pub use v6_1::QNetworkInformationFeature;
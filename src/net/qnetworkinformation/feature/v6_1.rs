@@ -0,0 +1,19 @@
+#[cxx::bridge]
+mod ffi {
+    /// Lists all of the features that a plugin may currently support. This can be used in [`QNetworkInformation::load_backend_by_features`](crate::QNetworkInformation::load_backend_by_features).
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QNetworkInformationFeature {
+        /// If the plugin supports this feature then the [`reachability`](crate::QNetworkInformation::reachability) property will provide useful results. Otherwise it will always return [`QNetworkInformationReachability::Unknown`](crate::QNetworkInformationReachability::Unknown).
+        Reachability = 0x1,
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkinformation.h");
+        type QNetworkInformationFeature;
+    }
+}
+
+pub use ffi::QNetworkInformationFeature;
@@ -0,0 +1,23 @@
+#[cxx::bridge]
+mod ffi {
+    /// Lists all of the features that a plugin may currently support. This can be used in [`QNetworkInformation::load_backend_by_features`](crate::QNetworkInformation::load_backend_by_features).
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QNetworkInformationFeature {
+        /// If the plugin supports this feature then the [`reachability`](crate::QNetworkInformation::reachability) property will provide useful results. Otherwise it will always return [`QNetworkInformationReachability::Unknown`](crate::QNetworkInformationReachability::Unknown).
+        Reachability = 0x1,
+        /// If the plugin supports this feature then the [`is_behind_captive_portal`](crate::QNetworkInformation::is_behind_captive_portal) property will provide useful results. Otherwise it will always return `false`.
+        ///
+        /// Introduced in Qt 6.2.
+        CaptivePortal = 0x2,
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkinformation.h");
+        type QNetworkInformationFeature;
+    }
+}
+
+pub use ffi::QNetworkInformationFeature;
@@ -0,0 +1,31 @@
+#[cxx::bridge]
+mod ffi {
+    /// Lists all of the features that a plugin may currently support. This can be used in [`QNetworkInformation::load_backend_by_features`](crate::QNetworkInformation::load_backend_by_features).
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QNetworkInformationFeature {
+        /// If the plugin supports this feature then the [`reachability`](crate::QNetworkInformation::reachability) property will provide useful results. Otherwise it will always return [`QNetworkInformationReachability::Unknown`](crate::QNetworkInformationReachability::Unknown).
+        Reachability = 0x1,
+        /// If the plugin supports this feature then the [`is_behind_captive_portal`](crate::QNetworkInformation::is_behind_captive_portal) property will provide useful results. Otherwise it will always return `false`.
+        ///
+        /// Introduced in Qt 6.2.
+        CaptivePortal = 0x2,
+        /// If the plugin supports this feature then the [`transport_medium`](crate::QNetworkInformation::transport_medium) property will provide useful results. Otherwise it will always return [`QNetworkInformationTransportMedium::Unknown`](crate::QNetworkInformationTransportMedium::Unknown).
+        ///
+        /// Introduced in Qt 6.3.
+        TransportMedium = 0x4,
+        /// If the plugin supports this feature then the [`is_metered`](crate::QNetworkInformation::is_metered) property will provide useful results. Otherwise it will always return `false`.
+        ///
+        /// Introduced in Qt 6.3.
+        Metered = 0x8,
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkinformation.h");
+        type QNetworkInformationFeature;
+    }
+}
+
+pub use ffi::QNetworkInformationFeature;
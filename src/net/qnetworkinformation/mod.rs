@@ -0,0 +1,258 @@
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QFlags, QString, QStringList};
+
+use crate::qobject::debug_qobject;
+
+mod feature;
+pub use feature::QNetworkInformationFeature;
+
+#[cfg(cxxqt_qt_version_at_least_6_3)]
+mod transport_medium;
+#[cfg(cxxqt_qt_version_at_least_6_3)]
+pub use transport_medium::QNetworkInformationTransportMedium;
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Describes the level of reachability as reported by the operating system.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QNetworkInformationReachability {
+        /// The reachability status is not known.
+        Unknown,
+        /// The system does not have any network connectivity.
+        Disconnected,
+        /// The system is connected to a local network, but not necessarily to the internet.
+        Local,
+        /// The system is connected to a site-local network. This may include the internet.
+        Site,
+        /// The system is connected to the internet.
+        Online,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkinformation.h");
+        type QNetworkInformationFeatures = super::QNetworkInformationFeatures;
+        type QNetworkInformationReachability;
+    }
+
+    #[cfg(cxxqt_qt_version_at_least_6_3)]
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        type QNetworkInformationTransportMedium = crate::QNetworkInformationTransportMedium;
+    }
+
+    unsafe extern "C++Qt" {
+        /// `QNetworkInformation` exposes various network information through native backends.
+        ///
+        /// `QNetworkInformation` provides a cross-platform interface to network-related information through plugins.
+        ///
+        /// Various plugins can have various functionality supported, and so you can load plugins based on which features are needed.
+        ///
+        /// In most cases, the recommended approach is to load the platform-specific backend by calling [`QNetworkInformation::load_default_backend`]. This will automatically select the backend that is best suited for the current platform.
+        ///
+        /// `QNetworkInformation` is a singleton and stays alive from the first successful load until destruction of the `QCoreApplication` object. If you destroy and re-create the `QCoreApplication` object you must call [`QNetworkInformation::load_backend_by_features`] or [`QNetworkInformation::load_backend_by_name`] again to reload the plugin.
+        ///
+        /// Qt Documentation: [QNetworkInformation](https://doc.qt.io/qt-6/qnetworkinformation.html#details)
+        #[qobject]
+        #[base = QObject]
+        type QNetworkInformation;
+
+        /// Returns the name of the currently loaded backend.
+        #[rust_name = "backend_name"]
+        fn backendName(self: &QNetworkInformation) -> QString;
+
+        /// Returns `true` if the user's device is currently known to be behind a captive portal, otherwise returns `false`.
+        ///
+        /// Introduced in Qt 6.2.
+        #[cfg(cxxqt_qt_version_at_least_6_2)]
+        #[rust_name = "is_behind_captive_portal"]
+        fn isBehindCaptivePortal(self: &QNetworkInformation) -> bool;
+
+        /// Returns whether the current connection is (known to be) metered or not.
+        ///
+        /// This is intended as a hint for applications to avoid doing expensive or unnecessary network operations, such as large downloads, when the connection is metered. If the backend does not support [`QNetworkInformationFeature::Metered`], this always returns `false`.
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[rust_name = "is_metered"]
+        fn isMetered(self: &QNetworkInformation) -> bool;
+
+        /// Returns the current reachability status of the system.
+        ///
+        /// If the backend does not support [`QNetworkInformationFeature::Reachability`], this always returns [`QNetworkInformationReachability::Unknown`].
+        fn reachability(self: &QNetworkInformation) -> QNetworkInformationReachability;
+
+        /// Returns all the features supported by the current backend.
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[rust_name = "supported_features"]
+        fn supportedFeatures(self: &QNetworkInformation) -> QNetworkInformationFeatures;
+
+        /// Returns `true` if the currently loaded backend supports `features`.
+        fn supports(self: &QNetworkInformation, features: QNetworkInformationFeatures) -> bool;
+
+        /// Returns the currently active transport medium for the application. On iOS and macOS, this returns the transport medium used by the primary network interface.
+        ///
+        /// If the backend does not support [`QNetworkInformationFeature::TransportMedium`], this always returns [`QNetworkInformationTransportMedium::Unknown`].
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[rust_name = "transport_medium"]
+        fn transportMedium(self: &QNetworkInformation) -> QNetworkInformationTransportMedium;
+
+        /// This signal is emitted when the system's captive portal status changes. `state` is `true` if the user's device is behind a captive portal.
+        ///
+        /// Introduced in Qt 6.2.
+        #[cfg(cxxqt_qt_version_at_least_6_2)]
+        #[qsignal]
+        #[rust_name = "is_behind_captive_portal_changed"]
+        fn isBehindCaptivePortalChanged(self: Pin<&mut QNetworkInformation>, state: bool);
+
+        /// This signal is emitted when the metered status of the current connection changes. `is_metered` is the new metered status.
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[qsignal]
+        #[rust_name = "is_metered_changed"]
+        fn isMeteredChanged(self: Pin<&mut QNetworkInformation>, is_metered: bool);
+
+        /// This signal is emitted when the system's reachability changes. `new_reachability` is the new reachability status.
+        #[qsignal]
+        #[rust_name = "reachability_changed"]
+        fn reachabilityChanged(
+            self: Pin<&mut QNetworkInformation>,
+            new_reachability: QNetworkInformationReachability,
+        );
+
+        /// This signal is emitted when the transport medium of the current connection changes. `current` is the new transport medium.
+        ///
+        /// Introduced in Qt 6.3.
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[qsignal]
+        #[rust_name = "transport_medium_changed"]
+        fn transportMediumChanged(
+            self: Pin<&mut QNetworkInformation>,
+            current: QNetworkInformationTransportMedium,
+        );
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qnetworkinformation_available_backends"]
+        fn qnetworkinformationAvailableBackends() -> QStringList;
+
+        #[rust_name = "qnetworkinformation_instance"]
+        fn qnetworkinformationInstance() -> *mut QNetworkInformation;
+
+        #[rust_name = "qnetworkinformation_load_backend_by_features"]
+        fn qnetworkinformationLoadBackendByFeatures(features: QNetworkInformationFeatures) -> bool;
+
+        #[rust_name = "qnetworkinformation_load_backend_by_name"]
+        fn qnetworkinformationLoadBackendByName(backend: &QString) -> bool;
+
+        #[cfg(cxxqt_qt_version_at_least_6_3)]
+        #[rust_name = "qnetworkinformation_load_default_backend"]
+        fn qnetworkinformationLoadDefaultBackend() -> bool;
+    }
+}
+
+pub use ffi::{QNetworkInformation, QNetworkInformationReachability};
+
+/// [`QFlags`] of [`QNetworkInformationFeature`].
+pub type QNetworkInformationFeatures = QFlags<QNetworkInformationFeature>;
+
+unsafe_impl_qflag!(
+    QNetworkInformationFeature,
+    "rust::cxxqtio1::QNetworkInformationFeatures"
+);
+
+impl fmt::Debug for QNetworkInformation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QNetworkInformation {
+    /// Returns a list of the names of all currently available backends.
+    pub fn available_backends() -> QStringList {
+        ffi::qnetworkinformation_available_backends()
+    }
+
+    /// Returns the `QNetworkInformation` instance, or `None` if no backend has been loaded.
+    ///
+    /// The instance is owned by Qt and stays alive until the `QCoreApplication` object is destroyed. The returned reference must not be used after that point.
+    ///
+    /// To connect to the instance's signals, use [`QNetworkInformation::with_instance_mut`].
+    pub fn instance() -> Option<&'static Self> {
+        // SAFETY: The instance is either null or valid until the application is destroyed.
+        unsafe { ffi::qnetworkinformation_instance().as_ref() }
+    }
+
+    /// Calls `f` with a pinned mutable reference to the `QNetworkInformation` instance and returns its result, or returns `None` without calling `f` if no backend has been loaded.
+    ///
+    /// The mutable reference cannot escape `f`, so it is never held alongside another mutable reference to the instance. This is mainly useful for connecting to the instance's signals.
+    pub fn with_instance_mut<F, R>(f: F) -> Option<R>
+    where
+        F: FnOnce(Pin<&mut Self>) -> R,
+    {
+        let instance = ffi::qnetworkinformation_instance();
+        // SAFETY: The instance is either null or valid until the application is destroyed, and the reference is only lent to `f` for the duration of the call.
+        let instance = unsafe { instance.as_mut() }?;
+        // SAFETY: The instance is owned by Qt and is never moved.
+        Some(f(unsafe { Pin::new_unchecked(instance) }))
+    }
+
+    /// Attempts to load a backend which supports `features`. Returns `true` if it managed to load the requested backend or if it was already loaded. Returns `false` otherwise.
+    pub fn load_backend_by_features(features: QNetworkInformationFeatures) -> bool {
+        ffi::qnetworkinformation_load_backend_by_features(features)
+    }
+
+    /// Attempts to load the backend whose name matches `backend` (case insensitively). Returns `true` if it managed to load the requested backend or if it was already loaded. Returns `false` otherwise.
+    pub fn load_backend_by_name(backend: &QString) -> bool {
+        ffi::qnetworkinformation_load_backend_by_name(backend)
+    }
+
+    /// Attempts to load the platform-default backend.
+    ///
+    /// This platform-to-plugin mapping is as follows:
+    ///
+    /// | Platform | Plugin's Name |
+    /// | -------- | ------------- |
+    /// | Windows  | networklistmanager |
+    /// | Apple (macOS/iOS) | applenetworkinformation |
+    /// | Android  | android |
+    /// | Linux    | networkmanager |
+    ///
+    /// This function is provided for convenience where the default for a given platform is good enough. If you are not using the default plugins you must use one of the other load functions to load the plugin you need.
+    ///
+    /// Returns `true` if it managed to load the backend or if it was already loaded. Returns `false` otherwise.
+    ///
+    /// Introduced in Qt 6.3.
+    #[cfg(cxxqt_qt_version_at_least_6_3)]
+    pub fn load_default_backend() -> bool {
+        ffi::qnetworkinformation_load_default_backend()
+    }
+}
+
+impl Deref for QNetworkInformation {
+    type Target = QObject;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
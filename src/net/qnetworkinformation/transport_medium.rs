@@ -0,0 +1,29 @@
+#[cxx::bridge]
+mod ffi {
+    /// Lists the currently recognized media with which one can connect to the internet.
+    ///
+    /// Introduced in Qt 6.3.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QNetworkInformationTransportMedium {
+        /// Returned if either the OS reports no active medium, the active medium is not recognized by Qt, or the [`QNetworkInformationFeature::TransportMedium`](crate::QNetworkInformationFeature::TransportMedium) feature is not supported.
+        Unknown,
+        /// Indicates that the currently active connection is using ethernet. Note: This value may also be returned when Windows is connected to a Bluetooth personal area network.
+        Ethernet,
+        /// Indicates that the currently active connection is using a cellular network.
+        Cellular,
+        /// Indicates that the currently active connection is using Wi-Fi.
+        WiFi,
+        /// Indicates that the currently active connection is connected using Bluetooth.
+        Bluetooth,
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkinformation.h");
+        type QNetworkInformationTransportMedium;
+    }
+}
+
+pub use ffi::QNetworkInformationTransportMedium;
@@ -0,0 +1,12 @@
+#include "cxx-qt-io/qnetworkproxyfactory.h"
+
+namespace rust {
+namespace cxxqtio1 {
+void
+qnetworkproxyfactoryClearApplicationProxyFactory()
+{
+  QNetworkProxyFactory::setApplicationProxyFactory(nullptr);
+}
+
+}
+}
@@ -0,0 +1,174 @@
+#[cfg(feature = "request")]
+use std::pin::Pin;
+
+use cxx_qt_lib::QList;
+
+#[cfg(feature = "request")]
+use crate::QNetworkAccessManager;
+use crate::{QNetworkProxy, QNetworkProxyQuery};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkproxyquery.h");
+        type QNetworkProxyQuery = crate::QNetworkProxyQuery;
+
+        include!("cxx-qt-io/qlist.h");
+        type QList_QNetworkProxy = cxx_qt_lib::QList<crate::QNetworkProxy>;
+    }
+
+    #[cfg(feature = "request")]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkaccessmanager.h");
+        type QNetworkAccessManager = crate::QNetworkAccessManager;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "Rust" {
+        type NetworkProxyFactoryBox;
+
+        #[cxx_name = "networkProxyFactoryQueryProxy"]
+        fn network_proxy_factory_query_proxy(
+            factory: &NetworkProxyFactoryBox,
+            query: &QNetworkProxyQuery,
+        ) -> QList_QNetworkProxy;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qnetworkproxyfactory.h");
+
+        #[rust_name = "qnetworkproxyfactory_clear_application_proxy_factory"]
+        fn qnetworkproxyfactoryClearApplicationProxyFactory();
+
+        #[rust_name = "qnetworkproxyfactory_proxy_for_query"]
+        fn qnetworkproxyfactoryProxyForQuery(query: &QNetworkProxyQuery) -> QList_QNetworkProxy;
+
+        #[rust_name = "qnetworkproxyfactory_set_application_proxy_factory"]
+        fn qnetworkproxyfactorySetApplicationProxyFactory(factory: Box<NetworkProxyFactoryBox>);
+
+        #[rust_name = "qnetworkproxyfactory_set_use_system_configuration"]
+        fn qnetworkproxyfactorySetUseSystemConfiguration(enable: bool);
+
+        #[rust_name = "qnetworkproxyfactory_system_proxy_for_query"]
+        fn qnetworkproxyfactorySystemProxyForQuery(
+            query: &QNetworkProxyQuery,
+        ) -> QList_QNetworkProxy;
+
+        #[cfg(cxxqt_qt_version_at_least_6_8)]
+        #[rust_name = "qnetworkproxyfactory_uses_system_configuration"]
+        fn qnetworkproxyfactoryUsesSystemConfiguration() -> bool;
+    }
+
+    #[cfg(feature = "request")]
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qnetworkaccessmanager_set_proxy_factory"]
+        fn qnetworkaccessmanagerSetProxyFactory(
+            manager: Pin<&mut QNetworkAccessManager>,
+            factory: Box<NetworkProxyFactoryBox>,
+        );
+    }
+}
+
+/// A proxy selection policy that can be installed with [`QNetworkProxyFactory::set_application_proxy_factory`] or [`QNetworkAccessManager::set_proxy_factory`](crate::QNetworkAccessManager::set_proxy_factory).
+///
+/// Qt may query the factory from multiple threads simultaneously, so implementations must be thread-safe.
+pub trait NetworkProxyFactory: Send + Sync + 'static {
+    /// Returns a list of proxies to be used for the context represented by `query`. The list is in order of preference: the first proxy is tried first, and so on.
+    ///
+    /// The list must contain at least one entry. Use a [`QNetworkProxy`] of type [`QNetworkProxyProxyType::NoProxy`](crate::QNetworkProxyProxyType::NoProxy) to connect directly, or the result of [`QNetworkProxyFactory::system_proxy_for_query`] to fall back to the system configuration.
+    fn query_proxy(&self, query: &QNetworkProxyQuery) -> QList<QNetworkProxy>;
+}
+
+impl<F> NetworkProxyFactory for F
+where
+    F: Fn(&QNetworkProxyQuery) -> QList<QNetworkProxy> + Send + Sync + 'static,
+{
+    fn query_proxy(&self, query: &QNetworkProxyQuery) -> QList<QNetworkProxy> {
+        self(query)
+    }
+}
+
+struct NetworkProxyFactoryBox(Box<dyn NetworkProxyFactory>);
+
+impl NetworkProxyFactoryBox {
+    fn new<F: NetworkProxyFactory>(factory: F) -> Box<Self> {
+        Box::new(Self(Box::new(factory)))
+    }
+}
+
+fn network_proxy_factory_query_proxy(
+    factory: &NetworkProxyFactoryBox,
+    query: &QNetworkProxyQuery,
+) -> QList<QNetworkProxy> {
+    factory.0.query_proxy(query)
+}
+
+/// The `QNetworkProxyFactory` class provides fine-grained proxy selection.
+///
+/// Proxy selection policies are implemented in Rust with the [`NetworkProxyFactory`] trait. This type provides the static functions for installing and querying them.
+///
+/// Qt Documentation: [QNetworkProxyFactory](https://doc.qt.io/qt-6/qnetworkproxyfactory.html#details)
+pub enum QNetworkProxyFactory {}
+
+impl QNetworkProxyFactory {
+    /// Removes the application-wide proxy factory, if one was set with [`QNetworkProxyFactory::set_application_proxy_factory`].
+    pub fn clear_application_proxy_factory() {
+        ffi::qnetworkproxyfactory_clear_application_proxy_factory();
+    }
+
+    /// This function takes the query request, `query`, examines the details of the type of socket or request and returns a list of [`QNetworkProxy`] objects that indicate the proxy servers to be used, in order of preference.
+    ///
+    /// If no application-wide proxy factory is installed, this returns the application proxy set with [`QNetworkProxy::set_application_proxy`](crate::QNetworkProxy::set_application_proxy), or the system proxies if the system configuration is in use.
+    pub fn proxy_for_query(query: &QNetworkProxyQuery) -> QList<QNetworkProxy> {
+        ffi::qnetworkproxyfactory_proxy_for_query(query)
+    }
+
+    /// Sets the application-wide proxy factory to be `factory`. This function will take ownership of that object and will delete it when necessary.
+    ///
+    /// The application-wide proxy is used as a last-resort when all other proxy selection requests returned [`QNetworkProxyProxyType::DefaultProxy`](crate::QNetworkProxyProxyType::DefaultProxy). For example, [`QTcpSocket`](crate::QTcpSocket) objects can have a proxy set with [`QAbstractSocket::set_proxy`](crate::QAbstractSocket::set_proxy), but if none is set, the proxy factory class set with this function will be queried.
+    ///
+    /// If you set a proxy factory with this function, any application level proxies set with [`QNetworkProxy::set_application_proxy`](crate::QNetworkProxy::set_application_proxy) will be overridden, and [`QNetworkProxyFactory::set_use_system_configuration`] will be disabled.
+    pub fn set_application_proxy_factory<F: NetworkProxyFactory>(factory: F) {
+        ffi::qnetworkproxyfactory_set_application_proxy_factory(NetworkProxyFactoryBox::new(
+            factory,
+        ));
+    }
+
+    /// Enables the use of the platform-specific proxy settings, and only those. See [`QNetworkProxyFactory::system_proxy_for_query`] for more information.
+    ///
+    /// Calling this function with `true` will replace any application-wide proxy factory. Calling it with `false` will remove it.
+    pub fn set_use_system_configuration(enable: bool) {
+        ffi::qnetworkproxyfactory_set_use_system_configuration(enable);
+    }
+
+    /// This function takes the query request, `query`, examines the details of the type of socket or request and returns a list of [`QNetworkProxy`] objects that indicate the proxy servers to be used, in order of preference.
+    ///
+    /// This function can be used to determine the platform-specific proxy settings. This function will use the libraries provided by the operating system to determine the proxy for a given connection, if such libraries exist. If they don't, this function will just return a `QNetworkProxy` of type [`QNetworkProxyProxyType::NoProxy`](crate::QNetworkProxyProxyType::NoProxy).
+    ///
+    /// On Windows, this function will use the WinHTTP DLL functions. Despite its name, Microsoft suggests using it for all applications that require network connections, not just HTTP. This will respect the proxy settings set on the registry with the proxycfg.exe tool. If those settings are not found, this function will attempt to obtain Internet Explorer's settings and use them.
+    ///
+    /// On macOS, this function will obtain the proxy settings using the CFNetwork framework from Apple. It will apply the FTP, HTTP and HTTPS proxy configurations for queries that contain the protocol tag "ftp", "http" and "https", respectively. If the SOCKS proxy is enabled in that configuration, this function will use the SOCKS server for all queries. If SOCKS isn't enabled, it will use the HTTPS proxy for all [`QNetworkProxyQueryQueryType::TcpSocket`](crate::QNetworkProxyQueryQueryType::TcpSocket) and [`QNetworkProxyQueryQueryType::UrlRequest`](crate::QNetworkProxyQueryQueryType::UrlRequest) queries.
+    ///
+    /// On systems configured with libproxy support, this function will rely on libproxy to obtain the proxy settings. On other systems, the `http_proxy` and `no_proxy` environment variables are used.
+    pub fn system_proxy_for_query(query: &QNetworkProxyQuery) -> QList<QNetworkProxy> {
+        ffi::qnetworkproxyfactory_system_proxy_for_query(query)
+    }
+
+    /// Returns whether the use of platform-specific proxy settings are enabled.
+    ///
+    /// Introduced in Qt 6.8.
+    #[cfg(cxxqt_qt_version_at_least_6_8)]
+    pub fn uses_system_configuration() -> bool {
+        ffi::qnetworkproxyfactory_uses_system_configuration()
+    }
+}
+
+#[cfg(feature = "request")]
+pub(crate) fn set_manager_proxy_factory<F: NetworkProxyFactory>(
+    manager: Pin<&mut QNetworkAccessManager>,
+    factory: F,
+) {
+    ffi::qnetworkaccessmanager_set_proxy_factory(manager, NetworkProxyFactoryBox::new(factory));
+}
@@ -0,0 +1,289 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use cxx::{ExternType, type_id};
+use cxx_qt_lib::{QString, QUrl};
+
+#[cxx::bridge]
+mod ffi {
+    /// Describes the type of one `QNetworkProxyQuery` query.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QNetworkProxyQueryQueryType {
+        /// A normal, outgoing TCP socket.
+        TcpSocket,
+        /// A datagram-based UDP socket, which could send to multiple destinations.
+        UdpSocket,
+        /// A message-oriented, outgoing SCTP socket.
+        SctpSocket,
+        /// A TCP server that listens for incoming connections from the network.
+        TcpServer = 100,
+        /// A more complex request which involves loading of a URL.
+        UrlRequest,
+        /// A message-oriented SCTP server that listens for incoming connections from the network.
+        SctpServer,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkproxyquery.h");
+        type QNetworkProxyQueryQueryType;
+    }
+
+    unsafe extern "C++" {
+        type QNetworkProxyQuery = super::QNetworkProxyQuery;
+
+        #[doc(hidden)]
+        #[rust_name = "local_port_or_negative"]
+        fn localPort(&self) -> i32;
+
+        /// Returns the host name or IP address of the outgoing connection being requested, or an empty string if the remote host is not specified.
+        #[rust_name = "peer_host_name"]
+        fn peerHostName(&self) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "peer_port_or_negative"]
+        fn peerPort(&self) -> i32;
+
+        /// Returns the protocol tag for this `QNetworkProxyQuery` object, or an empty string in case the protocol tag is unknown.
+        ///
+        /// In the case of URL requests, this function returns the scheme of the URL.
+        #[rust_name = "protocol_tag"]
+        fn protocolTag(&self) -> QString;
+
+        /// Returns the query type.
+        #[rust_name = "query_type"]
+        fn queryType(&self) -> QNetworkProxyQueryQueryType;
+
+        #[doc(hidden)]
+        #[rust_name = "set_local_port_or_negative"]
+        fn setLocalPort(&mut self, port: i32);
+
+        /// Sets the hostname of the outgoing connection being requested to `hostname`. An empty hostname can be used to indicate that the remote host is unknown.
+        ///
+        /// The peer host name can also be used to indicate the expected source address of an incoming connection in the case of [`QNetworkProxyQueryQueryType::TcpServer`]-type queries.
+        #[rust_name = "set_peer_host_name"]
+        fn setPeerHostName(&mut self, hostname: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "set_peer_port_or_negative"]
+        fn setPeerPort(&mut self, port: i32);
+
+        /// Sets the protocol tag for this `QNetworkProxyQuery` object to be `protocol_tag`.
+        ///
+        /// The protocol tag is an arbitrary string that indicates which protocol is being talked over the socket, such as "http", "xmpp", "telnet", etc. The protocol tag is used by the backend to return a request that is more specific to the protocol in question: for example, a HTTP connection could be use a caching HTTP proxy server, while all other connections use a more powerful SOCKSv5 proxy server.
+        #[rust_name = "set_protocol_tag"]
+        fn setProtocolTag(&mut self, protocol_tag: &QString);
+
+        /// Modifies this `QNetworkProxyQuery` object to be of type `query_type`.
+        #[rust_name = "set_query_type"]
+        fn setQueryType(&mut self, query_type: QNetworkProxyQueryQueryType);
+
+        /// Sets the URL component of this `QNetworkProxyQuery` object to be `url`. Setting the URL will also set the protocol tag, the remote host name and port number. This is done so as to facilitate the implementation of the code that determines the proxy server to be used.
+        #[rust_name = "set_url"]
+        fn setUrl(&mut self, url: &QUrl);
+
+        /// Returns the URL component of this `QNetworkProxyQuery` object in case of a query of type [`QNetworkProxyQueryQueryType::UrlRequest`].
+        fn url(&self) -> QUrl;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qnetworkproxyquery_drop"]
+        fn drop(query: &mut QNetworkProxyQuery);
+
+        #[rust_name = "qnetworkproxyquery_init_default"]
+        fn construct() -> QNetworkProxyQuery;
+        #[rust_name = "qnetworkproxyquery_init_url"]
+        fn construct(
+            request_url: &QUrl,
+            query_type: QNetworkProxyQueryQueryType,
+        ) -> QNetworkProxyQuery;
+        #[rust_name = "qnetworkproxyquery_init_peer"]
+        fn construct(
+            hostname: &QString,
+            port: i32,
+            protocol_tag: &QString,
+            query_type: QNetworkProxyQueryQueryType,
+        ) -> QNetworkProxyQuery;
+        #[rust_name = "qnetworkproxyquery_init_bind_port"]
+        fn construct(
+            bind_port: u16,
+            protocol_tag: &QString,
+            query_type: QNetworkProxyQueryQueryType,
+        ) -> QNetworkProxyQuery;
+        #[rust_name = "qnetworkproxyquery_clone"]
+        fn construct(other: &QNetworkProxyQuery) -> QNetworkProxyQuery;
+
+        #[rust_name = "qnetworkproxyquery_eq"]
+        fn operatorEq(a: &QNetworkProxyQuery, b: &QNetworkProxyQuery) -> bool;
+
+        #[rust_name = "qnetworkproxyquery_to_debug_qstring"]
+        fn toDebugQString(value: &QNetworkProxyQuery) -> QString;
+    }
+}
+
+pub use ffi::QNetworkProxyQueryQueryType;
+
+/// The `QNetworkProxyQuery` class is used to query the proxy settings for a socket.
+///
+/// `QNetworkProxyQuery` holds the details of a socket being created or request being made. It is used by [`QNetworkProxy`](crate::QNetworkProxy) and [`QNetworkProxyFactory`](crate::QNetworkProxyFactory) to allow applications to have a more fine-grained control over which proxy servers are used, depending on the details of the query. This allows an application to apply different settings, according to the protocol or destination hostname, for instance.
+///
+/// Qt Documentation: [QNetworkProxyQuery](https://doc.qt.io/qt-6/qnetworkproxyquery.html#details)
+#[repr(C)]
+pub struct QNetworkProxyQuery {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QNetworkProxyQuery {
+    fn clone(&self) -> Self {
+        ffi::qnetworkproxyquery_clone(self)
+    }
+}
+
+impl Default for QNetworkProxyQuery {
+    /// Constructs a default `QNetworkProxyQuery` object. By default, the query type will be [`QNetworkProxyQueryQueryType::TcpSocket`].
+    fn default() -> Self {
+        ffi::qnetworkproxyquery_init_default()
+    }
+}
+
+impl Drop for QNetworkProxyQuery {
+    fn drop(&mut self) {
+        ffi::qnetworkproxyquery_drop(self);
+    }
+}
+
+impl PartialEq for QNetworkProxyQuery {
+    fn eq(&self, other: &Self) -> bool {
+        ffi::qnetworkproxyquery_eq(self, other)
+    }
+}
+
+impl Eq for QNetworkProxyQuery {}
+
+impl fmt::Debug for QNetworkProxyQuery {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        ffi::qnetworkproxyquery_to_debug_qstring(self).fmt(f)
+    }
+}
+
+impl QNetworkProxyQuery {
+    /// Constructs a `QNetworkProxyQuery` with the URL `request_url` and sets the query type to `query_type`.
+    pub fn with_url(request_url: &QUrl, query_type: QNetworkProxyQueryQueryType) -> Self {
+        ffi::qnetworkproxyquery_init_url(request_url, query_type)
+    }
+
+    /// Constructs a `QNetworkProxyQuery` of type `query_type` and sets the protocol tag to be `protocol_tag`. This constructor is suitable for [`QNetworkProxyQueryQueryType::TcpSocket`] and [`QNetworkProxyQueryQueryType::UdpSocket`] queries.
+    pub fn with_peer(
+        hostname: &QString,
+        port: u16,
+        protocol_tag: &QString,
+        query_type: QNetworkProxyQueryQueryType,
+    ) -> Self {
+        ffi::qnetworkproxyquery_init_peer(hostname, port.into(), protocol_tag, query_type)
+    }
+
+    /// Constructs a `QNetworkProxyQuery` of type `query_type` and sets the protocol tag to be `protocol_tag`. This constructor is suitable for [`QNetworkProxyQueryQueryType::TcpServer`] queries.
+    ///
+    /// This function sets the local port number to `bind_port`.
+    pub fn with_bind_port(
+        bind_port: u16,
+        protocol_tag: &QString,
+        query_type: QNetworkProxyQueryQueryType,
+    ) -> Self {
+        ffi::qnetworkproxyquery_init_bind_port(bind_port, protocol_tag, query_type)
+    }
+
+    /// Returns the port number of the socket that will accept incoming packets from remote servers, or `None` if the port is not known.
+    pub fn local_port(&self) -> Option<u16> {
+        u16::try_from(self.local_port_or_negative()).ok()
+    }
+
+    /// Returns the port number for the outgoing request or `None` if the port number is not known.
+    ///
+    /// If the query type is [`QNetworkProxyQueryQueryType::UrlRequest`], this function returns the port number of the URL being requested. In general, frameworks will fill in the port number from their default values.
+    pub fn peer_port(&self) -> Option<u16> {
+        u16::try_from(self.peer_port_or_negative()).ok()
+    }
+
+    /// Sets the port number that the socket wishes to use locally to accept incoming packets from remote servers to `port`. `None` indicates the port number should be auto-detected by the operating system.
+    ///
+    /// This is used for [`QNetworkProxyQueryQueryType::TcpServer`] queries, which determine where the server will be listening.
+    pub fn set_local_port(&mut self, port: Option<u16>) {
+        self.set_local_port_or_negative(port.map_or(-1, i32::from));
+    }
+
+    /// Sets the requested port number for the outgoing connection to be `port`. `None` indicates the port number is not known.
+    ///
+    /// The peer port number can also be used to indicate the expected port number of an incoming connection in the case of [`QNetworkProxyQueryQueryType::TcpServer`]-type queries.
+    pub fn set_peer_port(&mut self, port: Option<u16>) {
+        self.set_peer_port_or_negative(port.map_or(-1, i32::from));
+    }
+}
+
+impl From<&QUrl> for QNetworkProxyQuery {
+    /// Constructs a [`QNetworkProxyQueryQueryType::UrlRequest`] query for `value`.
+    fn from(value: &QUrl) -> Self {
+        Self::with_url(value, QNetworkProxyQueryQueryType::UrlRequest)
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QNetworkProxyQuery {
+    type Id = type_id!("QNetworkProxyQuery");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QNetworkProxyQueryProps {
+            local_port: Option<u16>,
+            peer_host_name: QString,
+            peer_port: Option<u16>,
+            protocol_tag: QString,
+            query_type: QNetworkProxyQueryQueryType,
+        }
+
+        let props = QNetworkProxyQueryProps {
+            local_port: Some(8080),
+            peer_host_name: QString::from("example.com"),
+            peer_port: Some(443),
+            protocol_tag: QString::from("https"),
+            query_type: QNetworkProxyQueryQueryType::TcpServer,
+        };
+
+        let mut query = QNetworkProxyQuery::default();
+
+        query.set_local_port(props.local_port);
+        query.set_peer_host_name(&props.peer_host_name);
+        query.set_peer_port(props.peer_port);
+        query.set_protocol_tag(&props.protocol_tag);
+        query.set_query_type(props.query_type);
+
+        let actual_props = QNetworkProxyQueryProps {
+            local_port: query.local_port(),
+            peer_host_name: query.peer_host_name(),
+            peer_port: query.peer_port(),
+            protocol_tag: query.protocol_tag(),
+            query_type: query.query_type(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+}
@@ -0,0 +1,429 @@
+use std::borrow::Cow;
+use std::error::Error;
+use std::fmt;
+use std::pin::Pin;
+
+use cxx_qt_lib::{QByteArray, QUrl};
+
+#[cfg(cxxqt_qt_version_at_least_6_7)]
+use crate::QHttpHeaders;
+use crate::{QNetworkReply, QNetworkReplyNetworkError, QNetworkRequestAttribute, RawHeaderList};
+
+/// A snapshot of a finished [`QNetworkReply`], holding its status, headers, and body.
+///
+/// Unlike `QNetworkReply`, `HttpResponse` is a plain Rust value that does not reference the reply object, so it remains valid after the reply has been deleted by its [`QNetworkAccessManager`](crate::QNetworkAccessManager).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HttpResponse {
+    error: QNetworkReplyNetworkError,
+    status: Option<i32>,
+    reason: Option<String>,
+    url: QUrl,
+    headers: Vec<(Vec<u8>, Vec<u8>)>,
+    body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// Reads the status, headers, and all remaining body data from `reply`.
+    ///
+    /// This consumes any data that has not yet been read from the reply. It is intended to be called once the reply has emitted [`QNetworkReply::finished`].
+    pub fn from_reply(mut reply: Pin<&mut QNetworkReply>) -> Self {
+        let status = reply
+            .attribute(QNetworkRequestAttribute::HttpStatusCodeAttribute)
+            .and_then(|status| status.value::<i32>());
+        let reason = reply
+            .attribute(QNetworkRequestAttribute::HttpReasonPhraseAttribute)
+            .and_then(|reason| reason.value::<QByteArray>())
+            .map(|reason| String::from_utf8_lossy(reason.as_slice()).into_owned());
+        let headers = reply
+            .raw_header_pairs()
+            .iter()
+            .map(|(name, value)| (name.as_slice().to_vec(), value.as_slice().to_vec()))
+            .collect();
+        let body = reply.as_io_device_mut().read_all().as_slice().to_vec();
+        Self {
+            error: reply.error(),
+            status,
+            reason,
+            url: reply.url(),
+            headers,
+            body,
+        }
+    }
+
+    /// Creates a response for a reply that was destroyed before it finished.
+    pub(crate) fn destroyed(url: QUrl) -> Self {
+        Self {
+            error: QNetworkReplyNetworkError::OperationCanceledError,
+            status: None,
+            reason: None,
+            url,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Returns the body of the response.
+    pub fn body(&self) -> &[u8] {
+        &self.body
+    }
+
+    /// Returns the error that was found during the processing of the request. If no errors were found, returns [`QNetworkReplyNetworkError::NoError`].
+    pub fn error(&self) -> QNetworkReplyNetworkError {
+        self.error
+    }
+
+    /// Turns the response into an error if the request failed or the server responded with a client error (4xx) or server error (5xx) status.
+    pub fn error_for_status(self) -> Result<Self, HttpResponseError> {
+        self.error_for_status_ref()?;
+        Ok(self)
+    }
+
+    /// Returns an error if the request failed or the server responded with a client error (4xx) or server error (5xx) status.
+    pub fn error_for_status_ref(&self) -> Result<&Self, HttpResponseError> {
+        if let Some(status @ 400..=599) = self.status {
+            return Err(HttpResponseError::Status {
+                status,
+                reason: self.reason.clone().unwrap_or_default(),
+                error: self.error,
+            });
+        }
+        type E = QNetworkReplyNetworkError;
+        let error = self.error;
+        match error {
+            E::NoError => Ok(self),
+            E::ConnectionRefusedError
+            | E::RemoteHostClosedError
+            | E::HostNotFoundError
+            | E::TimeoutError
+            | E::OperationCanceledError
+            | E::SslHandshakeFailedError
+            | E::TemporaryNetworkFailureError
+            | E::NetworkSessionFailedError
+            | E::BackgroundRequestNotAllowedError
+            | E::TooManyRedirectsError
+            | E::InsecureRedirectError
+            | E::UnknownNetworkError => Err(HttpResponseError::Connection(error)),
+            E::ProxyConnectionRefusedError
+            | E::ProxyConnectionClosedError
+            | E::ProxyNotFoundError
+            | E::ProxyTimeoutError
+            | E::ProxyAuthenticationRequiredError
+            | E::UnknownProxyError => Err(HttpResponseError::Proxy(error)),
+            E::ContentAccessDenied
+            | E::ContentOperationNotPermittedError
+            | E::ContentNotFoundError
+            | E::AuthenticationRequiredError
+            | E::ContentReSendError
+            | E::ContentConflictError
+            | E::ContentGoneError
+            | E::UnknownContentError => Err(HttpResponseError::Content(error)),
+            E::ProtocolUnknownError | E::ProtocolInvalidOperationError | E::ProtocolFailure => {
+                Err(HttpResponseError::Protocol(error))
+            }
+            E::InternalServerError
+            | E::OperationNotImplementedError
+            | E::ServiceUnavailableError
+            | E::UnknownServerError => Err(HttpResponseError::Server(error)),
+            _ => Err(HttpResponseError::Other(error)),
+        }
+    }
+
+    /// Returns the value of the first header named `name`, compared case-insensitively, if the response contains one.
+    pub fn header<T: AsRef<[u8]>>(&self, name: T) -> Option<&[u8]> {
+        let name = name.as_ref();
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_slice())
+    }
+
+    /// Returns the raw header name-value pairs sent by the remote server, in the order they were received.
+    pub fn headers(&self) -> &[(Vec<u8>, Vec<u8>)] {
+        &self.headers
+    }
+
+    /// Returns the headers sent by the remote server as a `QHttpHeaders`.
+    ///
+    /// Introduced in Qt 6.7.
+    #[cfg(cxxqt_qt_version_at_least_6_7)]
+    pub fn http_headers(&self) -> QHttpHeaders {
+        QHttpHeaders::from(&self.raw_header_list())
+    }
+
+    /// Consumes the response, returning its body.
+    pub fn into_body(self) -> Vec<u8> {
+        self.body
+    }
+
+    /// Returns the headers sent by the remote server as a list of raw header pairs.
+    pub fn raw_header_list(&self) -> RawHeaderList {
+        self.headers
+            .iter()
+            .map(|(name, value)| {
+                (
+                    QByteArray::from(name.as_slice()),
+                    QByteArray::from(value.as_slice()),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns the HTTP reason phrase of the response (e.g. `"Not Found"`), or `None` if the request did not use HTTP or no response was received.
+    pub fn reason(&self) -> Option<&str> {
+        self.reason.as_deref()
+    }
+
+    /// Returns the HTTP status code of the response, or `None` if the request did not use HTTP or no response was received.
+    pub fn status(&self) -> Option<i32> {
+        self.status
+    }
+
+    /// Returns the body of the response as a string, replacing invalid UTF-8 sequences with [`U+FFFD REPLACEMENT CHARACTER`](std::char::REPLACEMENT_CHARACTER).
+    pub fn text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.body)
+    }
+
+    /// Returns the URL of the response. If redirections were followed, this is the URL of the resource the request was redirected to.
+    pub fn url(&self) -> &QUrl {
+        &self.url
+    }
+}
+
+/// An error returned by [`HttpResponse::error_for_status`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum HttpResponseError {
+    /// The connection to the remote server failed, e.g. because it was refused, timed out, or could not be resolved.
+    Connection(QNetworkReplyNetworkError),
+    /// The connection to the proxy server failed, or the proxy rejected the request.
+    Proxy(QNetworkReplyNetworkError),
+    /// The remote content could not be accessed, e.g. because it was not found or access was denied, and no HTTP status was received.
+    Content(QNetworkReplyNetworkError),
+    /// The request could not be completed due to a protocol error, such as an unknown scheme or a malformed response.
+    Protocol(QNetworkReplyNetworkError),
+    /// The remote server failed to fulfill the request, and no HTTP status was received.
+    Server(QNetworkReplyNetworkError),
+    /// The request failed with an error that Qt does not assign to any of the above categories.
+    Other(QNetworkReplyNetworkError),
+    /// The server responded with a client error (4xx) or server error (5xx) status.
+    Status {
+        /// HTTP status code.
+        status: i32,
+        /// HTTP reason phrase.
+        reason: String,
+        /// The error that Qt associated with the status code.
+        error: QNetworkReplyNetworkError,
+    },
+}
+
+impl HttpResponseError {
+    /// Returns the error that Qt reported for the reply.
+    pub fn network_error(&self) -> QNetworkReplyNetworkError {
+        match self {
+            Self::Connection(error)
+            | Self::Proxy(error)
+            | Self::Content(error)
+            | Self::Protocol(error)
+            | Self::Server(error)
+            | Self::Other(error)
+            | Self::Status { error, .. } => *error,
+        }
+    }
+
+    /// Returns the HTTP status code if the server responded with an error status.
+    pub fn status(&self) -> Option<i32> {
+        match self {
+            Self::Status { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the server responded with a client error (4xx) status.
+    pub fn is_client_error(&self) -> bool {
+        matches!(self.status(), Some(400..=499))
+    }
+
+    /// Returns `true` if the server responded with a server error (5xx) status.
+    pub fn is_server_error(&self) -> bool {
+        matches!(self.status(), Some(500..=599))
+    }
+}
+
+impl fmt::Display for HttpResponseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Connection(error) => write!(f, "connection error: {error}"),
+            Self::Proxy(error) => write!(f, "proxy error: {error}"),
+            Self::Content(error) => write!(f, "content error: {error}"),
+            Self::Protocol(error) => write!(f, "protocol error: {error}"),
+            Self::Server(error) => write!(f, "server error: {error}"),
+            Self::Other(error) => write!(f, "network error: {error}"),
+            Self::Status { status, reason, .. } if reason.is_empty() => {
+                write!(f, "HTTP status {status}")
+            }
+            Self::Status { status, reason, .. } => write!(f, "HTTP status {status} {reason}"),
+        }
+    }
+}
+
+impl Error for HttpResponseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    type E = QNetworkReplyNetworkError;
+
+    fn response(error: E, status: Option<i32>, reason: Option<&str>) -> HttpResponse {
+        HttpResponse {
+            error,
+            status,
+            reason: reason.map(str::to_owned),
+            url: QUrl::default(),
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    fn error_for(error: E) -> Option<HttpResponseError> {
+        response(error, None, None).error_for_status().err()
+    }
+
+    #[test]
+    fn error_for_status_categories() {
+        let connection = [
+            E::ConnectionRefusedError,
+            E::RemoteHostClosedError,
+            E::HostNotFoundError,
+            E::TimeoutError,
+            E::OperationCanceledError,
+            E::SslHandshakeFailedError,
+            E::TemporaryNetworkFailureError,
+            E::NetworkSessionFailedError,
+            E::BackgroundRequestNotAllowedError,
+            E::TooManyRedirectsError,
+            E::InsecureRedirectError,
+            E::UnknownNetworkError,
+        ];
+        let proxy = [
+            E::ProxyConnectionRefusedError,
+            E::ProxyConnectionClosedError,
+            E::ProxyNotFoundError,
+            E::ProxyTimeoutError,
+            E::ProxyAuthenticationRequiredError,
+            E::UnknownProxyError,
+        ];
+        let content = [
+            E::ContentAccessDenied,
+            E::ContentOperationNotPermittedError,
+            E::ContentNotFoundError,
+            E::AuthenticationRequiredError,
+            E::ContentReSendError,
+            E::ContentConflictError,
+            E::ContentGoneError,
+            E::UnknownContentError,
+        ];
+        let protocol = [
+            E::ProtocolUnknownError,
+            E::ProtocolInvalidOperationError,
+            E::ProtocolFailure,
+        ];
+        let server = [
+            E::InternalServerError,
+            E::OperationNotImplementedError,
+            E::ServiceUnavailableError,
+            E::UnknownServerError,
+        ];
+
+        assert_eq!(error_for(E::NoError), None);
+        for error in connection {
+            assert_eq!(error_for(error), Some(HttpResponseError::Connection(error)));
+        }
+        for error in proxy {
+            assert_eq!(error_for(error), Some(HttpResponseError::Proxy(error)));
+        }
+        for error in content {
+            assert_eq!(error_for(error), Some(HttpResponseError::Content(error)));
+        }
+        for error in protocol {
+            assert_eq!(error_for(error), Some(HttpResponseError::Protocol(error)));
+        }
+        for error in server {
+            assert_eq!(error_for(error), Some(HttpResponseError::Server(error)));
+        }
+        let unknown = E { repr: 1000 };
+        assert_eq!(error_for(unknown), Some(HttpResponseError::Other(unknown)));
+    }
+
+    #[test]
+    fn error_for_status_codes() {
+        let ok = response(E::NoError, Some(204), None);
+        let not_found = response(E::ContentNotFoundError, Some(404), Some("Not Found"));
+        let unavailable = response(E::ServiceUnavailableError, Some(503), None);
+
+        let not_found_error = not_found.error_for_status_ref().unwrap_err();
+        let unavailable_error = unavailable.error_for_status_ref().unwrap_err();
+
+        assert_eq!(ok.error_for_status_ref(), Ok(&ok));
+        assert_eq!(
+            not_found_error,
+            HttpResponseError::Status {
+                status: 404,
+                reason: "Not Found".to_owned(),
+                error: E::ContentNotFoundError,
+            }
+        );
+        assert_eq!(
+            (
+                not_found_error.status(),
+                not_found_error.network_error(),
+                not_found_error.is_client_error(),
+                not_found_error.is_server_error(),
+            ),
+            (Some(404), E::ContentNotFoundError, true, false)
+        );
+        assert_eq!(
+            (
+                unavailable_error.status(),
+                unavailable_error.is_client_error(),
+                unavailable_error.is_server_error(),
+            ),
+            (Some(503), false, true)
+        );
+    }
+
+    #[test]
+    fn display_error() {
+        let errors = [
+            HttpResponseError::Connection(E::HostNotFoundError),
+            HttpResponseError::Proxy(E::ProxyTimeoutError),
+            HttpResponseError::Content(E::ContentGoneError),
+            HttpResponseError::Protocol(E::ProtocolFailure),
+            HttpResponseError::Server(E::InternalServerError),
+            HttpResponseError::Other(E::NoError),
+            HttpResponseError::Status {
+                status: 404,
+                reason: "Not Found".to_owned(),
+                error: E::ContentNotFoundError,
+            },
+            HttpResponseError::Status {
+                status: 500,
+                reason: String::new(),
+                error: E::InternalServerError,
+            },
+        ];
+        let displayed: Vec<String> = errors.iter().map(ToString::to_string).collect();
+        assert_eq!(
+            displayed,
+            [
+                "connection error: HostNotFoundError",
+                "proxy error: ProxyTimeoutError",
+                "content error: ContentGoneError",
+                "protocol error: ProtocolFailure",
+                "server error: InternalServerError",
+                "network error: NoError",
+                "HTTP status 404 Not Found",
+                "HTTP status 500",
+            ]
+        );
+    }
+}
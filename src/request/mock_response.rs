@@ -0,0 +1,671 @@
+use std::ffi::c_char;
+use std::fmt;
+use std::io::{self, Read};
+use std::ops::Deref;
+use std::pin::Pin;
+use std::time::Duration;
+use std::{ptr, str};
+
+use cxx::UniquePtr;
+use cxx_qt::CxxQtType;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QByteArray, QString, QUrl, QVariant, qint64};
+
+use crate::qobject::debug_qobject;
+use crate::util::MSecs;
+use crate::{
+    QIODeviceOpenModeFlag, QNetworkAccessManagerOperation, QNetworkCacheMetaData,
+    QNetworkCacheMetaDataAttributesMap, QNetworkReply, QNetworkReplyNetworkError, QNetworkRequest,
+    QNetworkRequestAttribute, RawHeaderList,
+};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = cxx_qt_lib::qint64;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+        include!("cxx-qt-lib/qvariant.h");
+        type QVariant = cxx_qt_lib::QVariant;
+
+        include!("cxx-qt-io/qnetworkreply.h");
+        type QNetworkReply = crate::QNetworkReply;
+        include!("cxx-qt-io/qnetworkrequest.h");
+        type QNetworkRequest = crate::QNetworkRequest;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkaccessmanager.h");
+        type QNetworkAccessManagerOperation = crate::QNetworkAccessManagerOperation;
+        type QNetworkReplyNetworkError = crate::QNetworkReplyNetworkError;
+        type QNetworkRequestAttribute = crate::QNetworkRequestAttribute;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "RustQt" {
+        /// A [`QNetworkReply`](crate::QNetworkReply) subclass that delivers a [`MockResponse`](crate::MockResponse) instead of performing a network request.
+        ///
+        /// After the response's [delay](crate::MockResponse::delay) has elapsed, the reply emits the same signals as a reply from the network: [`meta_data_changed`](crate::QNetworkReply::meta_data_changed) once the status and headers are set, [`ready_read`](crate::QIODevice::ready_read) and [`download_progress`](crate::QNetworkReply::download_progress) once the body is available, [`error_occurred`](crate::QNetworkReply::error_occurred) if the response has an error, and finally [`finished`](crate::QNetworkReply::finished). Delivery requires a running event loop.
+        ///
+        /// Replies are usually created by a [`RustNetworkAccessManager`](crate::RustNetworkAccessManager).
+        #[qobject]
+        #[base = QNetworkReply]
+        type RustNetworkReply = super::RustNetworkReplyRust;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "abort"]
+        fn backend_abort(self: Pin<&mut RustNetworkReply>);
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "bytesAvailable"]
+        fn backend_bytes_available(self: &RustNetworkReply) -> qint64;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "readData"]
+        unsafe fn backend_read_data(
+            self: Pin<&mut RustNetworkReply>,
+            data: *mut c_char,
+            max_size: qint64,
+        ) -> qint64;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_name = "deliverResponse"]
+        fn deliver_response(self: Pin<&mut RustNetworkReply>);
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "bytesAvailable"]
+        fn base_bytes_available(self: &RustNetworkReply) -> qint64;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setAttribute"]
+        fn base_set_attribute(
+            self: Pin<&mut RustNetworkReply>,
+            code: QNetworkRequestAttribute,
+            value: &QVariant,
+        );
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setError"]
+        fn base_set_error(
+            self: Pin<&mut RustNetworkReply>,
+            error_code: QNetworkReplyNetworkError,
+            error_string: &QString,
+        );
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setFinished"]
+        fn base_set_finished(self: Pin<&mut RustNetworkReply>, finished: bool);
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setOperation"]
+        fn base_set_operation(
+            self: Pin<&mut RustNetworkReply>,
+            operation: QNetworkAccessManagerOperation,
+        );
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setRawHeader"]
+        fn base_set_raw_header(
+            self: Pin<&mut RustNetworkReply>,
+            header_name: &QByteArray,
+            value: &QByteArray,
+        );
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setRequest"]
+        fn base_set_request(self: Pin<&mut RustNetworkReply>, request: &QNetworkRequest);
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setUrl"]
+        fn base_set_url(self: Pin<&mut RustNetworkReply>, url: &QUrl);
+
+        #[doc(hidden)]
+        #[qsignal]
+        #[inherit]
+        #[cxx_name = "downloadProgress"]
+        fn reply_download_progress(
+            self: Pin<&mut RustNetworkReply>,
+            bytes_received: qint64,
+            bytes_total: qint64,
+        );
+
+        #[doc(hidden)]
+        #[qsignal]
+        #[inherit]
+        #[cxx_name = "errorOccurred"]
+        fn reply_error_occurred(self: Pin<&mut RustNetworkReply>, code: QNetworkReplyNetworkError);
+
+        #[doc(hidden)]
+        #[qsignal]
+        #[inherit]
+        #[cxx_name = "finished"]
+        fn reply_finished(self: Pin<&mut RustNetworkReply>);
+
+        #[doc(hidden)]
+        #[qsignal]
+        #[inherit]
+        #[cxx_name = "metaDataChanged"]
+        fn reply_meta_data_changed(self: Pin<&mut RustNetworkReply>);
+
+        #[doc(hidden)]
+        #[qsignal]
+        #[inherit]
+        #[cxx_name = "readyRead"]
+        fn reply_ready_read(self: Pin<&mut RustNetworkReply>);
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qnetworkreply_schedule_delivery"]
+        fn qnetworkreplyScheduleDelivery(reply: Pin<&mut RustNetworkReply>, msecs: i32);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "rustnetworkreply_init_default"]
+        fn make_unique() -> UniquePtr<RustNetworkReply>;
+    }
+}
+
+pub use ffi::RustNetworkReply;
+
+/// The maximum number of bytes read from a streaming body at a time.
+const CHUNK_SIZE: usize = 16 * 1024;
+
+/// How long to wait before reading from a streaming body again when no data is available.
+const POLL_INTERVAL: Duration = Duration::from_millis(10);
+
+enum MockBody {
+    Bytes(Vec<u8>),
+    Reader(Box<dyn Read>),
+}
+
+impl Default for MockBody {
+    fn default() -> Self {
+        Self::Bytes(Vec::new())
+    }
+}
+
+impl fmt::Debug for MockBody {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Bytes(bytes) => f.debug_tuple("Bytes").field(&bytes.len()).finish(),
+            Self::Reader(_) => f.debug_tuple("Reader").finish_non_exhaustive(),
+        }
+    }
+}
+
+/// A response delivered by a [`RustNetworkReply`], either canned for tests or produced by a handler for a custom URL scheme.
+///
+/// By default, a response has no status code, headers, or body, and is delivered as soon as control returns to the event loop.
+///
+/// # Examples
+///
+/// ```ignore
+/// let response = MockResponse::new()
+///     .status(200)
+///     .header("Content-Type", "application/json")
+///     .body(r#"{"id":1}"#)
+///     .delay(Duration::from_millis(50));
+/// ```
+#[must_use = "a MockResponse does nothing until it is delivered by a RustNetworkReply"]
+#[derive(Debug, Default)]
+pub struct MockResponse {
+    status: Option<i32>,
+    reason: Option<String>,
+    headers: Vec<(Vec<u8>, Vec<u8>)>,
+    body: MockBody,
+    delay: Duration,
+    error: Option<(QNetworkReplyNetworkError, String)>,
+    from_cache: bool,
+}
+
+impl MockResponse {
+    /// Constructs an empty response.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the response body to `body`.
+    pub fn body<T: Into<Vec<u8>>>(mut self, body: T) -> Self {
+        self.body = MockBody::Bytes(body.into());
+        self
+    }
+
+    /// Sets the response body to the contents of `reader`, which are read in chunks once the response has been delivered.
+    ///
+    /// The reply emits [`ready_read`](crate::QIODevice::ready_read) and [`download_progress`](crate::QNetworkReply::download_progress) for each chunk and finishes when `reader` reaches the end of its data. Reads that fail with [`io::ErrorKind::WouldBlock`] are retried every 10 milliseconds, since a [`Read`] implementation has no way to signal that more data is available. Each retry wakes the event loop, and data that arrives in between is delivered up to 10 milliseconds late. Other errors make the reply fail with [`QNetworkReplyNetworkError::UnknownContentError`]. If a "Content-Length" header is set, it is reported as the total size of the download.
+    pub fn body_reader<R: Read + 'static>(mut self, reader: R) -> Self {
+        self.body = MockBody::Reader(Box::new(reader));
+        self
+    }
+
+    /// Delays delivery of the response by `delay`.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Makes the reply fail with `code` and the human-readable description `message`. The status, headers, and body are still delivered before the error, as they are for HTTP error responses.
+    pub fn error<T: Into<String>>(mut self, code: QNetworkReplyNetworkError, message: T) -> Self {
+        self.error = Some((code, message.into()));
+        self
+    }
+
+    /// Adds a raw header with `name` and `value`. If a header with the same name is added more than once, the last value is used.
+    pub fn header<K, V>(mut self, name: K, value: V) -> Self
+    where
+        K: Into<Vec<u8>>,
+        V: Into<Vec<u8>>,
+    {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the HTTP reason phrase, such as "Not Found".
+    pub fn reason<T: Into<String>>(mut self, reason: T) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    /// Sets the HTTP status code, such as 200 or 404.
+    pub fn status(mut self, status: i32) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    fn content_length(&self) -> Option<i64> {
+        let (_, value) = self
+            .headers
+            .iter()
+            .rev()
+            .find(|(name, _)| name.eq_ignore_ascii_case(b"content-length"))?;
+        str::from_utf8(value).ok()?.trim().parse().ok()
+    }
+
+    /// Returns the values of the "Set-Cookie" headers of the response.
+    pub(crate) fn set_cookie_headers(&self) -> impl Iterator<Item = &[u8]> {
+        self.headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case(b"set-cookie"))
+            .map(|(_, value)| value.as_slice())
+    }
+
+    /// Reconstructs a response from a cache entry created with [`to_cache`](Self::to_cache). The reply that delivers it sets [`SourceIsFromCacheAttribute`](QNetworkRequestAttribute::SourceIsFromCacheAttribute).
+    pub(crate) fn from_cache(meta_data: &QNetworkCacheMetaData, body: Vec<u8>) -> Self {
+        let attributes = meta_data.attributes();
+        let status = attributes
+            .get(&QNetworkRequestAttribute::HttpStatusCodeAttribute)
+            .and_then(|status| status.value::<i32>());
+        let reason = attributes
+            .get(&QNetworkRequestAttribute::HttpReasonPhraseAttribute)
+            .and_then(|reason| reason.value::<QByteArray>())
+            .map(|reason| String::from_utf8_lossy(reason.as_slice()).into_owned());
+        let headers = meta_data
+            .raw_headers()
+            .iter()
+            .map(|(name, value)| (name.as_slice().to_vec(), value.as_slice().to_vec()))
+            .collect();
+        Self {
+            status,
+            reason,
+            headers,
+            body: MockBody::Bytes(body),
+            from_cache: true,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the metadata and body with which this response to a request for `url` is cached, or `None` if the response has an error or a streaming body.
+    pub(crate) fn to_cache(&self, url: &QUrl) -> Option<(QNetworkCacheMetaData, &[u8])> {
+        let MockBody::Bytes(body) = &self.body else {
+            return None;
+        };
+        if self.error.is_some() {
+            return None;
+        }
+        let mut attributes = QNetworkCacheMetaDataAttributesMap::default();
+        if let Some(status) = self.status {
+            attributes.insert(
+                QNetworkRequestAttribute::HttpStatusCodeAttribute,
+                QVariant::from(&status),
+            );
+        }
+        if let Some(reason) = &self.reason {
+            attributes.insert(
+                QNetworkRequestAttribute::HttpReasonPhraseAttribute,
+                QVariant::from(&QByteArray::from(reason.as_str())),
+            );
+        }
+        let mut headers = RawHeaderList::default();
+        for (name, value) in &self.headers {
+            headers.append(
+                &QByteArray::from(name.as_slice()),
+                &QByteArray::from(value.as_slice()),
+            );
+        }
+        let mut meta_data = QNetworkCacheMetaData::default();
+        meta_data.set_url(url);
+        meta_data.set_attributes(&attributes);
+        meta_data.set_raw_headers(&headers);
+        Some((meta_data, body))
+    }
+}
+
+/// The body of a request whose response cannot be produced until the body has been uploaded.
+struct PendingUpload {
+    request: QNetworkRequest,
+    body: Vec<u8>,
+}
+
+#[doc(hidden)]
+#[derive(Default)]
+pub struct RustNetworkReplyRust {
+    upload: Option<PendingUpload>,
+    pending: Option<MockResponse>,
+    reader: Option<Box<dyn Read>>,
+    error: Option<(QNetworkReplyNetworkError, String)>,
+    buffer: Vec<u8>,
+    position: usize,
+    received: i64,
+    total: i64,
+}
+
+impl fmt::Debug for RustNetworkReply {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self.as_network_reply())
+    }
+}
+
+impl RustNetworkReply {
+    /// Constructs a reply to `request` that delivers `response` once its delay has elapsed.
+    pub fn new(
+        operation: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+        response: MockResponse,
+    ) -> UniquePtr<Self> {
+        let mut reply = Self::init(operation, request);
+        reply.pin_mut().respond(response);
+        reply
+    }
+
+    /// Constructs a reply to `request` whose response is supplied with [`respond`](Self::respond) once the request body has been collected with [`append_upload`](Self::append_upload).
+    pub(crate) fn awaiting_upload(
+        operation: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+    ) -> UniquePtr<Self> {
+        let mut reply = Self::init(operation, request);
+        reply.pin_mut().rust_mut().get_mut().upload = Some(PendingUpload {
+            request: request.clone(),
+            body: Vec::new(),
+        });
+        reply
+    }
+
+    fn init(
+        operation: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+    ) -> UniquePtr<Self> {
+        let mut reply = ffi::rustnetworkreply_init_default();
+        let mut this = reply.pin_mut();
+        this.as_mut().base_set_operation(operation);
+        this.as_mut().base_set_request(request);
+        this.as_mut().base_set_url(&request.url());
+        this.as_network_reply_mut()
+            .as_io_device_mut()
+            .open(QIODeviceOpenModeFlag::ReadOnly.into());
+        reply
+    }
+
+    /// Appends `data` to the body of a reply created with [`awaiting_upload`](Self::awaiting_upload). Does nothing if the upload has already been taken or the reply was aborted.
+    pub(crate) fn append_upload(self: Pin<&mut Self>, data: &[u8]) {
+        if let Some(upload) = &mut self.rust_mut().get_mut().upload {
+            upload.body.extend_from_slice(data);
+        }
+    }
+
+    /// Takes the request and collected body of a reply created with [`awaiting_upload`](Self::awaiting_upload), or returns `None` if they have already been taken or the reply was aborted.
+    pub(crate) fn take_upload(self: Pin<&mut Self>) -> Option<(QNetworkRequest, Vec<u8>)> {
+        let upload = self.rust_mut().get_mut().upload.take()?;
+        Some((upload.request, upload.body))
+    }
+
+    /// Delivers `response` once its delay has elapsed. Does nothing if the reply has already finished.
+    pub(crate) fn respond(mut self: Pin<&mut Self>, response: MockResponse) {
+        if self.is_finished() {
+            return;
+        }
+        let delay = response.delay;
+        self.as_mut().rust_mut().get_mut().pending = Some(response);
+        self.schedule(delay);
+    }
+
+    /// Casts this object to `QNetworkReply`.
+    pub fn as_network_reply(&self) -> &QNetworkReply {
+        self.upcast()
+    }
+
+    /// Mutably casts this object to `QNetworkReply`.
+    pub fn as_network_reply_mut<'a>(self: &'a mut Pin<&mut Self>) -> Pin<&'a mut QNetworkReply> {
+        self.as_mut().upcast_pin()
+    }
+
+    fn backend_abort(mut self: Pin<&mut Self>) {
+        if self.is_finished() {
+            return;
+        }
+        let this = self.as_mut().rust_mut().get_mut();
+        this.upload = None;
+        this.pending = None;
+        this.reader = None;
+        let code = QNetworkReplyNetworkError::OperationCanceledError;
+        self.as_mut()
+            .base_set_error(code, &QString::from("Operation canceled"));
+        self.as_mut().reply_error_occurred(code);
+        self.as_mut().base_set_finished(true);
+        self.reply_finished();
+    }
+
+    fn backend_bytes_available(&self) -> qint64 {
+        let this = self.rust();
+        let remaining = i64::try_from(this.buffer.len() - this.position).unwrap_or(i64::MAX);
+        (remaining + i64::from(self.base_bytes_available())).into()
+    }
+
+    unsafe fn backend_read_data(
+        self: Pin<&mut Self>,
+        data: *mut c_char,
+        max_size: qint64,
+    ) -> qint64 {
+        let Ok(max_size) = usize::try_from(i64::from(max_size)) else {
+            return qint64::from(-1);
+        };
+        let this = self.rust_mut().get_mut();
+        let remaining = &this.buffer[this.position..];
+        let len = remaining.len().min(max_size);
+        // SAFETY: Qt guarantees that `data` is valid for writes of `max_size` bytes.
+        unsafe { ptr::copy_nonoverlapping(remaining.as_ptr(), data.cast(), len) };
+        this.position += len;
+        qint64::from(i64::try_from(len).unwrap_or(i64::MAX))
+    }
+
+    fn deliver_response(mut self: Pin<&mut Self>) {
+        match self.as_mut().rust_mut().get_mut().pending.take() {
+            Some(response) => self.deliver_head(response),
+            None => self.deliver_chunk(),
+        }
+    }
+
+    fn deliver_head(mut self: Pin<&mut Self>, response: MockResponse) {
+        if let Some(status) = response.status {
+            self.as_mut().base_set_attribute(
+                QNetworkRequestAttribute::HttpStatusCodeAttribute,
+                &QVariant::from(&status),
+            );
+        }
+        if let Some(reason) = &response.reason {
+            self.as_mut().base_set_attribute(
+                QNetworkRequestAttribute::HttpReasonPhraseAttribute,
+                &QVariant::from(&QByteArray::from(reason.as_str())),
+            );
+        }
+        if response.from_cache {
+            self.as_mut().base_set_attribute(
+                QNetworkRequestAttribute::SourceIsFromCacheAttribute,
+                &QVariant::from(&true),
+            );
+        }
+        for (name, value) in &response.headers {
+            self.as_mut().base_set_raw_header(
+                &QByteArray::from(name.as_slice()),
+                &QByteArray::from(value.as_slice()),
+            );
+        }
+        let content_length = response.content_length();
+        let this = self.as_mut().rust_mut().get_mut();
+        this.error = response.error;
+        match response.body {
+            MockBody::Bytes(bytes) => {
+                this.total = i64::try_from(bytes.len()).unwrap_or(i64::MAX);
+                self.as_mut().reply_meta_data_changed();
+                self.as_mut().append_data(&bytes);
+                self.finish();
+            }
+            MockBody::Reader(reader) => {
+                this.total = content_length.unwrap_or(-1);
+                this.reader = Some(reader);
+                self.as_mut().reply_meta_data_changed();
+                self.deliver_chunk();
+            }
+        }
+    }
+
+    fn deliver_chunk(mut self: Pin<&mut Self>) {
+        // The reader is discarded if the reply was aborted.
+        let Some(mut reader) = self.as_mut().rust_mut().get_mut().reader.take() else {
+            return;
+        };
+        let mut chunk = vec![0; CHUNK_SIZE];
+        match reader.read(&mut chunk) {
+            Ok(0) => self.finish(),
+            Ok(n) => {
+                self.as_mut().rust_mut().get_mut().reader = Some(reader);
+                self.as_mut().append_data(&chunk[..n]);
+                self.schedule(Duration::ZERO);
+            }
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {
+                self.as_mut().rust_mut().get_mut().reader = Some(reader);
+                self.schedule(Duration::ZERO);
+            }
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => {
+                self.as_mut().rust_mut().get_mut().reader = Some(reader);
+                self.schedule(POLL_INTERVAL);
+            }
+            Err(e) => {
+                self.as_mut().rust_mut().get_mut().error = Some((
+                    QNetworkReplyNetworkError::UnknownContentError,
+                    e.to_string(),
+                ));
+                self.finish();
+            }
+        }
+    }
+
+    fn append_data(mut self: Pin<&mut Self>, data: &[u8]) {
+        if self.is_finished() {
+            return;
+        }
+        let this = self.as_mut().rust_mut().get_mut();
+        this.buffer.drain(..this.position);
+        this.position = 0;
+        this.buffer.extend_from_slice(data);
+        this.received += i64::try_from(data.len()).unwrap_or(i64::MAX);
+        let (received, total) = (this.received, this.total);
+        if !data.is_empty() {
+            self.as_mut().reply_ready_read();
+        }
+        self.reply_download_progress(received.into(), total.into());
+    }
+
+    fn finish(mut self: Pin<&mut Self>) {
+        if self.is_finished() {
+            return;
+        }
+        let this = self.as_mut().rust_mut().get_mut();
+        let received = this.received;
+        let unknown_total = this.total == -1;
+        let error = this.error.take();
+        if unknown_total {
+            self.as_mut().rust_mut().get_mut().total = received;
+            self.as_mut()
+                .reply_download_progress(received.into(), received.into());
+        }
+        if let Some((code, message)) = error {
+            self.as_mut().base_set_error(code, &QString::from(&message));
+            self.as_mut().reply_error_occurred(code);
+        }
+        self.as_mut().base_set_finished(true);
+        self.reply_finished();
+    }
+
+    fn schedule(self: Pin<&mut Self>, delay: Duration) {
+        ffi::qnetworkreply_schedule_delivery(self, delay.msecs());
+    }
+}
+
+impl Deref for RustNetworkReply {
+    type Target = QNetworkReply;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn abort_before_delivery() {
+        let url = QUrl::from("https://example.com/resource");
+        let response = MockResponse::new().status(200).body("body");
+        let mut reply = RustNetworkReply::new(
+            QNetworkAccessManagerOperation::GetOperation,
+            &QNetworkRequest::from(&url),
+            response,
+        );
+        let before = (reply.is_finished(), reply.url());
+        reply.pin_mut().as_network_reply_mut().abort();
+        let after = (reply.is_finished(), reply.error());
+        assert_eq!(
+            (before, after),
+            (
+                (false, url),
+                (true, QNetworkReplyNetworkError::OperationCanceledError)
+            )
+        );
+    }
+}
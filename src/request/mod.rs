@@ -1,3 +1,18 @@
+mod http_response;
+pub use http_response::{HttpResponse, HttpResponseError};
+
+mod mock_response;
+pub use mock_response::{MockResponse, RustNetworkReply};
+
+mod network_access_handler;
+pub use network_access_handler::{NetworkAccessHandler, RustNetworkAccessManager};
+
+mod network_cache_backend;
+pub use network_cache_backend::{NetworkCacheBackend, RustNetworkCache};
+
+mod network_cookie_jar_policy;
+pub use network_cookie_jar_policy::{NetworkCookieJarPolicy, RustNetworkCookieJar};
+
 mod qabstractnetworkcache;
 pub use qabstractnetworkcache::{QAbstractNetworkCache, QAbstractNetworkCacheWriter};
 
@@ -43,5 +58,26 @@ pub use qnetworkrequest::{
     QNetworkRequestLoadControl, QNetworkRequestPriority, QNetworkRequestRedirectPolicy,
 };
 
+#[cfg(cxxqt_qt_version_at_least_6_7)]
+mod qnetworkrequestfactory;
+#[cfg(cxxqt_qt_version_at_least_6_7)]
+pub use qnetworkrequestfactory::QNetworkRequestFactory;
+
 mod qnetworkreply;
 pub use qnetworkreply::{QNetworkReply, QNetworkReplyNetworkError};
+
+mod qnetworkreply_future;
+pub use qnetworkreply_future::QNetworkReplyFuture;
+
+#[cfg(cxxqt_qt_version_at_least_6_7)]
+mod qrestaccessmanager;
+#[cfg(cxxqt_qt_version_at_least_6_7)]
+pub use qrestaccessmanager::QRestAccessManager;
+
+#[cfg(cxxqt_qt_version_at_least_6_7)]
+mod qrestreply;
+#[cfg(cxxqt_qt_version_at_least_6_7)]
+pub use qrestreply::QRestReply;
+
+mod request_builder;
+pub use request_builder::RequestBuilder;
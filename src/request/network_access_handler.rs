@@ -0,0 +1,495 @@
+use std::fmt;
+use std::io::Write;
+use std::ops::Deref;
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+use cxx_qt::CxxQtType;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QByteArray, QList, QString, QStringList};
+
+use crate::qobject::{debug_qobject, set_parent};
+use crate::util::upcast_mut;
+use crate::{
+    MockResponse, QIODevice, QNetworkAccessManager, QNetworkAccessManagerOperation, QNetworkCookie,
+    QNetworkCookieRawForm, QNetworkReply, QNetworkReplyNetworkError, QNetworkRequest,
+    QNetworkRequestAttribute, QNetworkRequestCacheLoadControl, QNetworkRequestLoadControl,
+    RustNetworkReply,
+};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstringlist.h");
+        type QStringList = cxx_qt_lib::QStringList;
+
+        include!("cxx-qt-io/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+        include!("cxx-qt-io/qnetworkaccessmanager.h");
+        type QNetworkAccessManager = crate::QNetworkAccessManager;
+        include!("cxx-qt-io/qnetworkreply.h");
+        type QNetworkReply = crate::QNetworkReply;
+        include!("cxx-qt-io/qnetworkrequest.h");
+        type QNetworkRequest = crate::QNetworkRequest;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        type QNetworkAccessManagerOperation = crate::QNetworkAccessManagerOperation;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "RustQt" {
+        /// A [`QNetworkAccessManager`](crate::QNetworkAccessManager) subclass that lets a [`NetworkAccessHandler`](crate::NetworkAccessHandler) implemented in Rust answer requests.
+        ///
+        /// Every request sent through the manager, including those sent with [`QNetworkAccessManager::get`](crate::QNetworkAccessManager::get), [`QNetworkAccessManager::post`](crate::QNetworkAccessManager::post), and [`QNetworkAccessManager::send_custom_request`](crate::QNetworkAccessManager::send_custom_request), is first offered to the handler. If the handler returns a [`MockResponse`](crate::MockResponse), the request is answered by a [`RustNetworkReply`](crate::RustNetworkReply) without accessing the network. Otherwise, the request is sent as usual.
+        ///
+        /// This makes it possible to test code written against `QNetworkAccessManager` offline, with the same signal flow as real replies.
+        ///
+        /// Handlers can also be registered for custom URL schemes with [`RustNetworkAccessManager::register_scheme`](crate::RustNetworkAccessManager::register_scheme).
+        #[qobject]
+        #[base = QNetworkAccessManager]
+        type RustNetworkAccessManager = super::RustNetworkAccessManagerRust;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "createRequest"]
+        unsafe fn backend_create_request(
+            self: Pin<&mut RustNetworkAccessManager>,
+            op: QNetworkAccessManagerOperation,
+            request: &QNetworkRequest,
+            outgoing_data: *mut QIODevice,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_name = "supportedSchemesImplementation"]
+        fn backend_supported_schemes(self: &RustNetworkAccessManager) -> QStringList;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_name = "receiveUpload"]
+        unsafe fn receive_upload(
+            self: Pin<&mut RustNetworkAccessManager>,
+            reply: *mut QNetworkReply,
+            device: *mut QIODevice,
+            finished: bool,
+        );
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "createRequest"]
+        unsafe fn base_create_request(
+            self: Pin<&mut RustNetworkAccessManager>,
+            op: QNetworkAccessManagerOperation,
+            request: &QNetworkRequest,
+            outgoing_data: *mut QIODevice,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "supportedSchemesImplementation"]
+        fn base_supported_schemes(self: &RustNetworkAccessManager) -> QStringList;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qnetworkaccessmanager_watch_upload"]
+        fn qnetworkaccessmanagerWatchUpload(
+            manager: Pin<&mut RustNetworkAccessManager>,
+            reply: Pin<&mut QNetworkReply>,
+            device: Pin<&mut QIODevice>,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "rustnetworkaccessmanager_init_default"]
+        fn make_unique() -> UniquePtr<RustNetworkAccessManager>;
+    }
+}
+
+pub use ffi::RustNetworkAccessManager;
+
+/// A request handler that can be plugged into a [`QNetworkAccessManager`] through [`RustNetworkAccessManager`].
+pub trait NetworkAccessHandler: 'static {
+    /// Returns the response to `request`, or `None` if the request should be sent over the network.
+    ///
+    /// `operation` is the HTTP operation of the request. For [`QNetworkAccessManagerOperation::CustomOperation`], the verb is stored in the request's [`CustomVerbAttribute`](crate::QNetworkRequestAttribute::CustomVerbAttribute). `body` contains the data uploaded with the request, if any.
+    ///
+    /// If the body is uploaded from a random-access device, such as a [`QBuffer`](crate::QBuffer), the handler is called immediately with the data between the device's current position and its end. If it is uploaded from a sequential device, such as a socket or a [`RustIODevice`](crate::RustIODevice), the handler is not called until the device has emitted [`read_channel_finished`](crate::QIODevice::read_channel_finished), been closed, or been destroyed, since only then is the whole body known. By that point the body has been consumed, so such a request cannot be sent over the network instead: if every handler returns `None`, the reply fails with [`QNetworkReplyNetworkError::ProtocolUnknownError`].
+    fn create_request(
+        &mut self,
+        operation: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+        body: &[u8],
+    ) -> Option<MockResponse>;
+}
+
+impl<F> NetworkAccessHandler for F
+where
+    F: FnMut(QNetworkAccessManagerOperation, &QNetworkRequest, &[u8]) -> Option<MockResponse>
+        + 'static,
+{
+    fn create_request(
+        &mut self,
+        operation: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+        body: &[u8],
+    ) -> Option<MockResponse> {
+        self(operation, request, body)
+    }
+}
+
+#[doc(hidden)]
+#[derive(Default)]
+pub struct RustNetworkAccessManagerRust {
+    handler: Option<Box<dyn NetworkAccessHandler>>,
+    schemes: Vec<(String, Box<dyn NetworkAccessHandler>)>,
+}
+
+impl fmt::Debug for RustNetworkAccessManager {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self.as_network_access_manager())
+    }
+}
+
+impl RustNetworkAccessManager {
+    /// Constructs a network access manager that offers every request to `handler` before sending it.
+    pub fn new<H: NetworkAccessHandler>(handler: H) -> UniquePtr<Self> {
+        let mut manager = ffi::rustnetworkaccessmanager_init_default();
+        manager.pin_mut().rust_mut().get_mut().handler = Some(Box::new(handler));
+        manager
+    }
+
+    /// Casts this object to `QNetworkAccessManager`.
+    pub fn as_network_access_manager(&self) -> &QNetworkAccessManager {
+        self.upcast()
+    }
+
+    /// Mutably casts this object to `QNetworkAccessManager`.
+    pub fn as_network_access_manager_mut<'a>(
+        self: &'a mut Pin<&mut Self>,
+    ) -> Pin<&'a mut QNetworkAccessManager> {
+        self.as_mut().upcast_pin()
+    }
+
+    /// Registers `handler` for requests to URLs with the scheme `scheme`, such as `"app"`, replacing any handler previously registered for that scheme. Schemes are case-insensitive.
+    ///
+    /// Scheme handlers are consulted before the handler the manager was constructed with. If a scheme handler returns `None`, the request is handled as if the scheme were not registered. Registered schemes are included in [`QNetworkAccessManager::supported_schemes`].
+    ///
+    /// Replies to requests with a custom scheme are [`RustNetworkReply`] objects, which emit the same signals as replies from the network. A handler can stream large resources with [`MockResponse::body_reader`].
+    ///
+    /// Requests answered by handlers use the manager's [`cookie_jar`](QNetworkAccessManager::cookie_jar) and [`cache`](QNetworkAccessManager::cache) like requests sent over the network. Unless the request already has one, a "Cookie" header with the jar's cookies for the URL is added to the request passed to `handler`, and "Set-Cookie" headers in the response are stored in the jar. For GET requests, the cache is consulted according to the request's [`CacheLoadControlAttribute`](crate::QNetworkRequestAttribute::CacheLoadControlAttribute) before `handler` is called, and responses with a body set by [`MockResponse::body`] are saved to the cache unless [`CacheSaveControlAttribute`](crate::QNetworkRequestAttribute::CacheSaveControlAttribute) is `false`. Replies read from the cache set [`SourceIsFromCacheAttribute`](crate::QNetworkRequestAttribute::SourceIsFromCacheAttribute).
+    pub fn register_scheme<H: NetworkAccessHandler>(
+        self: Pin<&mut Self>,
+        scheme: &str,
+        handler: H,
+    ) {
+        let scheme = scheme.to_ascii_lowercase();
+        let schemes = &mut self.rust_mut().get_mut().schemes;
+        schemes.retain(|(registered, _)| *registered != scheme);
+        schemes.push((scheme, Box::new(handler)));
+    }
+
+    unsafe fn backend_create_request(
+        mut self: Pin<&mut Self>,
+        op: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+        outgoing_data: *mut QIODevice,
+    ) -> *mut QNetworkReply {
+        // SAFETY: `outgoing_data` is either null or valid, and Qt objects are never moved.
+        let device = unsafe {
+            outgoing_data
+                .as_mut()
+                .map(|device| Pin::new_unchecked(device))
+        };
+        let body = match device {
+            None => QByteArray::default(),
+            Some(device) if device.is_sequential() => {
+                return self.defer_until_uploaded(op, request, device);
+            }
+            Some(mut device) => {
+                // Peeking leaves the data in place in case the request is sent over the network.
+                let remaining = (device.size() - device.pos()).max(0);
+                device.as_mut().peek_to_array(remaining)
+            }
+        };
+        let Some(response) = self.as_mut().respond(op, request, body.as_slice()) else {
+            // SAFETY: The arguments are passed through unchanged.
+            return unsafe { self.base_create_request(op, request, outgoing_data) };
+        };
+        self.adopt_reply(RustNetworkReply::new(op, request, response))
+    }
+
+    fn respond(
+        mut self: Pin<&mut Self>,
+        op: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+        body: &[u8],
+    ) -> Option<MockResponse> {
+        let mut request = request.clone();
+        self.add_cookies(&mut request);
+        // Like Qt's HTTP backend, only GET requests are read from and written to the cache.
+        let cacheable = op == QNetworkAccessManagerOperation::GetOperation;
+        if cacheable && let Some(response) = self.load_from_cache(&request) {
+            return Some(response);
+        }
+        let response = self.as_mut().handle(op, &request, body)?;
+        self.save_cookies(&request, &response);
+        if cacheable {
+            self.save_to_cache(&request, &response);
+        }
+        Some(response)
+    }
+
+    fn handle(
+        self: Pin<&mut Self>,
+        op: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+        body: &[u8],
+    ) -> Option<MockResponse> {
+        let scheme = String::from(&request.url().scheme());
+        let this = self.rust_mut().get_mut();
+        this.schemes
+            .iter_mut()
+            .find(|(registered, _)| registered.eq_ignore_ascii_case(&scheme))
+            .and_then(|(_, handler)| handler.create_request(op, request, body))
+            .or_else(|| {
+                let handler = this.handler.as_mut()?;
+                handler.create_request(op, request, body)
+            })
+    }
+
+    fn add_cookies(&self, request: &mut QNetworkRequest) {
+        let control = int_attribute(
+            request,
+            QNetworkRequestAttribute::CookieLoadControlAttribute,
+        );
+        if control == Some(QNetworkRequestLoadControl::Manual.repr) {
+            return;
+        }
+        // A "Cookie" header set by the caller takes precedence, as it does for HTTP requests.
+        if request
+            .raw_header_list()
+            .iter()
+            .any(|name| name.as_slice().eq_ignore_ascii_case(b"cookie"))
+        {
+            return;
+        }
+        // SAFETY: The cookie jar is either null or owned by this manager.
+        let Some(jar) = (unsafe { self.cookie_jar().as_ref() }) else {
+            return;
+        };
+        let cookies = jar.cookies_for_url(&request.url());
+        if cookies.is_empty() {
+            return;
+        }
+        let header = cookies
+            .iter()
+            .map(|cookie| {
+                cookie
+                    .to_raw_form(QNetworkCookieRawForm::NameAndValueOnly)
+                    .as_slice()
+                    .to_vec()
+            })
+            .collect::<Vec<_>>()
+            .join(b"; ".as_slice());
+        request.set_raw_header(
+            &QByteArray::from("Cookie"),
+            &QByteArray::from(header.as_slice()),
+        );
+    }
+
+    fn save_cookies(&self, request: &QNetworkRequest, response: &MockResponse) {
+        let control = int_attribute(
+            request,
+            QNetworkRequestAttribute::CookieSaveControlAttribute,
+        );
+        if control == Some(QNetworkRequestLoadControl::Manual.repr) {
+            return;
+        }
+        // SAFETY: The cookie jar is either null or owned by this manager, and Qt objects are never
+        // moved.
+        let Some(mut jar) = (unsafe {
+            self.cookie_jar()
+                .as_mut()
+                .map(|jar| Pin::new_unchecked(jar))
+        }) else {
+            return;
+        };
+        let url = request.url();
+        for header in response.set_cookie_headers() {
+            jar.as_mut()
+                .set_cookies_from_url(&QNetworkCookie::parse_cookies(&header), &url);
+        }
+    }
+
+    fn load_from_cache(&self, request: &QNetworkRequest) -> Option<MockResponse> {
+        let control = int_attribute(request, QNetworkRequestAttribute::CacheLoadControlAttribute)
+            .unwrap_or(QNetworkRequestCacheLoadControl::PreferNetwork.repr);
+        let always_cache = control == QNetworkRequestCacheLoadControl::AlwaysCache.repr;
+        if !always_cache && control != QNetworkRequestCacheLoadControl::PreferCache.repr {
+            return None;
+        }
+        let url = request.url();
+        // SAFETY: The cache is either null or owned by this manager, and Qt objects are never
+        // moved.
+        let cache = unsafe { self.cache().as_mut().map(|cache| Pin::new_unchecked(cache)) };
+        let cached = cache.and_then(|mut cache| {
+            let meta_data = cache.as_mut().meta_data(&url)?;
+            let mut data = cache.data(&url);
+            let body = data.as_mut()?.read_all();
+            Some(MockResponse::from_cache(
+                &meta_data,
+                body.as_slice().to_vec(),
+            ))
+        });
+        if cached.is_none() && always_cache {
+            return Some(MockResponse::new().error(
+                QNetworkReplyNetworkError::ContentNotFoundError,
+                "The requested resource is not in the cache",
+            ));
+        }
+        cached
+    }
+
+    fn save_to_cache(&self, request: &QNetworkRequest, response: &MockResponse) {
+        let save = request
+            .attribute(QNetworkRequestAttribute::CacheSaveControlAttribute)
+            .and_then(|save| save.value::<bool>())
+            .unwrap_or(true);
+        if !save {
+            return;
+        }
+        let Some((meta_data, body)) = response.to_cache(&request.url()) else {
+            return;
+        };
+        // SAFETY: The cache is either null or owned by this manager, and Qt objects are never
+        // moved.
+        let Some(cache) = (unsafe { self.cache().as_mut().map(|cache| Pin::new_unchecked(cache)) })
+        else {
+            return;
+        };
+        let Some(mut writer) = cache.prepare(&meta_data) else {
+            return;
+        };
+        // If the write fails, dropping the writer removes the incomplete entry.
+        if writer.write_all(body).is_ok() {
+            writer.insert();
+        }
+    }
+
+    fn adopt_reply(
+        mut self: Pin<&mut Self>,
+        mut reply: UniquePtr<RustNetworkReply>,
+    ) -> *mut QNetworkReply {
+        // Like replies created by Qt, the reply is owned by the manager.
+        set_parent(
+            reply.pin_mut().as_network_reply_mut(),
+            self.as_network_access_manager_mut(),
+        );
+        upcast_mut(reply.into_raw())
+    }
+
+    fn defer_until_uploaded(
+        mut self: Pin<&mut Self>,
+        op: QNetworkAccessManagerOperation,
+        request: &QNetworkRequest,
+        device: Pin<&mut QIODevice>,
+    ) -> *mut QNetworkReply {
+        let reply = self
+            .as_mut()
+            .adopt_reply(RustNetworkReply::awaiting_upload(op, request));
+        // SAFETY: The reply was just created and is owned by this manager.
+        let reply_pin = unsafe { Pin::new_unchecked(&mut *reply) };
+        ffi::qnetworkaccessmanager_watch_upload(self, reply_pin, device);
+        reply
+    }
+
+    unsafe fn receive_upload(
+        self: Pin<&mut Self>,
+        reply: *mut QNetworkReply,
+        device: *mut QIODevice,
+        finished: bool,
+    ) {
+        // SAFETY: `reply` was created by `defer_until_uploaded`, and the connection that calls
+        // this function is removed when the reply is destroyed.
+        let reply = unsafe { <RustNetworkReply as Upcast<QNetworkReply>>::from_base_ptr(reply) };
+        // SAFETY: `reply` is either null or valid, and Qt objects are never moved.
+        let Some(mut reply) = (unsafe {
+            reply
+                .cast_mut()
+                .as_mut()
+                .map(|reply| Pin::new_unchecked(reply))
+        }) else {
+            return;
+        };
+        // SAFETY: `device` is either null or valid, since a destroyed device is passed as null.
+        let finished = match unsafe { device.as_mut().map(|device| Pin::new_unchecked(device)) } {
+            Some(mut device) => {
+                let data = device.as_mut().read_all();
+                reply.as_mut().append_upload(data.as_slice());
+                finished || !device.is_readable()
+            }
+            None => true,
+        };
+        if !finished {
+            return;
+        }
+        let Some((request, body)) = reply.as_mut().take_upload() else {
+            return;
+        };
+        let op = reply.operation();
+        let response = self.respond(op, &request, &body).unwrap_or_else(|| {
+            MockResponse::new().error(
+                QNetworkReplyNetworkError::ProtocolUnknownError,
+                "No handler accepted the request",
+            )
+        });
+        reply.respond(response);
+    }
+
+    fn backend_supported_schemes(&self) -> QStringList {
+        let mut schemes = QList::from(&self.base_supported_schemes());
+        for (scheme, _) in &self.rust().schemes {
+            let scheme = QString::from(scheme);
+            if !schemes.contains(&scheme) {
+                schemes.append(scheme);
+            }
+        }
+        QStringList::from(&schemes)
+    }
+}
+
+fn int_attribute(request: &QNetworkRequest, code: QNetworkRequestAttribute) -> Option<i32> {
+    request.attribute(code)?.value::<i32>()
+}
+
+impl Deref for RustNetworkAccessManager {
+    type Target = QNetworkAccessManager;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_scheme() {
+        let mut manager = RustNetworkAccessManager::new(|_, _: &QNetworkRequest, _: &[u8]| None);
+        manager
+            .pin_mut()
+            .register_scheme("App", |_, _: &QNetworkRequest, _: &[u8]| {
+                Some(MockResponse::new().body("resource"))
+            });
+        let schemes = QList::from(&manager.supported_schemes());
+        assert!(schemes.contains(&QString::from("app")));
+    }
+}
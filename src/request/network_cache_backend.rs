@@ -0,0 +1,356 @@
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::ptr;
+
+use cxx::UniquePtr;
+use cxx_qt::CxxQtType;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QUrl, qint64};
+
+use crate::qobject::debug_qobject;
+use crate::util::{unpin_for_qt, upcast_mut};
+use crate::{
+    QAbstractNetworkCache, QBuffer, QIODevice, QIODeviceOpenModeFlag, QNetworkCacheMetaData,
+};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = cxx_qt_lib::qint64;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-io/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+        include!("cxx-qt-io/qnetworkcachemetadata.h");
+        type QNetworkCacheMetaData = crate::QNetworkCacheMetaData;
+        include!("cxx-qt-io/qabstractnetworkcache.h");
+        type QAbstractNetworkCache = crate::QAbstractNetworkCache;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "RustQt" {
+        /// A [`QAbstractNetworkCache`](crate::QAbstractNetworkCache) subclass that forwards all cache operations to a [`NetworkCacheBackend`](crate::NetworkCacheBackend) implemented in Rust.
+        ///
+        /// Data is exchanged with the backend as byte vectors. Reads are served from a [`QBuffer`](crate::QBuffer) containing the backend's data, and writes are collected into a [`QBuffer`](crate::QBuffer) until the entry is inserted.
+        ///
+        /// A `RustNetworkCache` can be installed with [`QNetworkAccessManager::set_cache`](crate::QNetworkAccessManager::set_cache).
+        #[qobject]
+        #[base = QAbstractNetworkCache]
+        type RustNetworkCache = super::RustNetworkCacheRust;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "cacheSize"]
+        fn backend_cache_size(self: &RustNetworkCache) -> qint64;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "clear"]
+        fn backend_clear(self: Pin<&mut RustNetworkCache>);
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "data"]
+        fn backend_data(self: Pin<&mut RustNetworkCache>, url: &QUrl) -> *mut QIODevice;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "insert"]
+        unsafe fn backend_insert(self: Pin<&mut RustNetworkCache>, device: *mut QIODevice);
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "metaData"]
+        fn backend_meta_data(self: Pin<&mut RustNetworkCache>, url: &QUrl)
+        -> QNetworkCacheMetaData;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "prepare"]
+        fn backend_prepare(
+            self: Pin<&mut RustNetworkCache>,
+            meta_data: &QNetworkCacheMetaData,
+        ) -> *mut QIODevice;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "remove"]
+        fn backend_remove(self: Pin<&mut RustNetworkCache>, url: &QUrl) -> bool;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "updateMetaData"]
+        fn backend_update_meta_data(
+            self: Pin<&mut RustNetworkCache>,
+            meta_data: &QNetworkCacheMetaData,
+        );
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "rustnetworkcache_init_default"]
+        fn make_unique() -> UniquePtr<RustNetworkCache>;
+    }
+}
+
+pub use ffi::RustNetworkCache;
+
+/// A cache storage implementation that can be plugged into a [`QNetworkAccessManager`](crate::QNetworkAccessManager) through [`RustNetworkCache`].
+///
+/// Each method corresponds to a virtual function of [`QAbstractNetworkCache`]. Entries are keyed by URL. The metadata passed to [`insert`](NetworkCacheBackend::insert) should be returned by subsequent calls to [`meta_data`](NetworkCacheBackend::meta_data) for the same URL, and the data should be returned by [`data`](NetworkCacheBackend::data).
+pub trait NetworkCacheBackend: 'static {
+    /// Returns the meta data for the url `url`, or `None` if the cache does not contain an entry for `url`.
+    fn meta_data(&mut self, url: &QUrl) -> Option<QNetworkCacheMetaData>;
+
+    /// Updates the cache meta data for the `meta_data`'s url to `meta_data`.
+    ///
+    /// If the cache does not contain an entry for the url then no action should be taken.
+    fn update_meta_data(&mut self, meta_data: &QNetworkCacheMetaData);
+
+    /// Returns the data associated with `url`, or `None` if the cache does not contain an entry for `url`.
+    fn data(&mut self, url: &QUrl) -> Option<Vec<u8>>;
+
+    /// Returns `true` if an entry for `meta_data` should be stored. If this returns `false`, the response will not be cached.
+    ///
+    /// This is called before the response body is downloaded. `meta_data` is always valid and has a valid url. The default implementation accepts every entry.
+    fn prepare(&mut self, meta_data: &QNetworkCacheMetaData) -> bool {
+        let _ = meta_data;
+        true
+    }
+
+    /// Stores `data` and `meta_data` in the cache, replacing any existing entry for the same url.
+    fn insert(&mut self, meta_data: QNetworkCacheMetaData, data: Vec<u8>);
+
+    /// Removes the cache entry for `url`, returning `true` if successful, otherwise `false`.
+    fn remove(&mut self, url: &QUrl) -> bool;
+
+    /// Returns the current size taken up by the cache. Depending upon the cache implementation this might be disk or memory size.
+    fn cache_size(&self) -> i64;
+
+    /// Removes all items from the cache. Unless there were failures clearing the cache, [`cache_size`](NetworkCacheBackend::cache_size) should return 0 afterward.
+    fn clear(&mut self);
+}
+
+struct PendingInsert {
+    meta_data: QNetworkCacheMetaData,
+    buffer: UniquePtr<QBuffer>,
+    device: *mut QIODevice,
+}
+
+#[doc(hidden)]
+#[derive(Default)]
+pub struct RustNetworkCacheRust {
+    backend: Option<Box<dyn NetworkCacheBackend>>,
+    pending: Vec<PendingInsert>,
+}
+
+impl fmt::Debug for RustNetworkCache {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self.as_network_cache())
+    }
+}
+
+impl RustNetworkCache {
+    /// Constructs a network cache that stores its entries in `backend`.
+    pub fn new<B: NetworkCacheBackend>(backend: B) -> UniquePtr<Self> {
+        let mut cache = ffi::rustnetworkcache_init_default();
+        cache.pin_mut().rust_mut().get_mut().backend = Some(Box::new(backend));
+        cache
+    }
+
+    /// Casts this object to `QAbstractNetworkCache`.
+    pub fn as_network_cache(&self) -> &QAbstractNetworkCache {
+        self.upcast()
+    }
+
+    /// Mutably casts this object to `QAbstractNetworkCache`.
+    pub fn as_network_cache_mut<'a>(
+        self: &'a mut Pin<&mut Self>,
+    ) -> Pin<&'a mut QAbstractNetworkCache> {
+        self.as_mut().upcast_pin()
+    }
+
+    fn backend_cache_size(&self) -> qint64 {
+        let size = match &self.rust().backend {
+            Some(backend) => backend.cache_size(),
+            None => 0,
+        };
+        size.into()
+    }
+
+    fn backend_clear(self: Pin<&mut Self>) {
+        let this = self.rust_mut().get_mut();
+        this.pending.clear();
+        if let Some(backend) = &mut this.backend {
+            backend.clear();
+        }
+    }
+
+    fn backend_data(self: Pin<&mut Self>, url: &QUrl) -> *mut QIODevice {
+        let this = self.rust_mut().get_mut();
+        let Some(data) = this.backend.as_mut().and_then(|backend| backend.data(url)) else {
+            return ptr::null_mut();
+        };
+        let mut buffer = QBuffer::new();
+        buffer.pin_mut().set_data(data);
+        buffer
+            .pin_mut()
+            .as_io_device_mut()
+            .open(QIODeviceOpenModeFlag::ReadOnly.into());
+        // The caller takes ownership of the device.
+        upcast_mut(buffer.into_raw())
+    }
+
+    unsafe fn backend_insert(self: Pin<&mut Self>, device: *mut QIODevice) {
+        let this = self.rust_mut().get_mut();
+        let Some(index) = this.pending.iter().position(|entry| entry.device == device) else {
+            return;
+        };
+        let entry = this.pending.swap_remove(index);
+        if let Some(backend) = &mut this.backend {
+            backend.insert(entry.meta_data, entry.buffer.as_slice().to_vec());
+        }
+    }
+
+    fn backend_meta_data(self: Pin<&mut Self>, url: &QUrl) -> QNetworkCacheMetaData {
+        let this = self.rust_mut().get_mut();
+        this.backend
+            .as_mut()
+            .and_then(|backend| backend.meta_data(url))
+            .unwrap_or_default()
+    }
+
+    fn backend_prepare(self: Pin<&mut Self>, meta_data: &QNetworkCacheMetaData) -> *mut QIODevice {
+        let this = self.rust_mut().get_mut();
+        let Some(backend) = &mut this.backend else {
+            return ptr::null_mut();
+        };
+        if !meta_data.is_valid() || !meta_data.url().is_valid() || !backend.prepare(meta_data) {
+            return ptr::null_mut();
+        }
+        let mut buffer = QBuffer::new();
+        buffer
+            .pin_mut()
+            .as_io_device_mut()
+            .open(QIODeviceOpenModeFlag::WriteOnly.into());
+        // SAFETY: The buffer is kept in `pending` until it is inserted or removed.
+        let device = upcast_mut(unsafe { unpin_for_qt(buffer.pin_mut()) });
+        this.pending.push(PendingInsert {
+            meta_data: meta_data.clone(),
+            buffer,
+            device,
+        });
+        device
+    }
+
+    fn backend_remove(self: Pin<&mut Self>, url: &QUrl) -> bool {
+        let this = self.rust_mut().get_mut();
+        let pending = this.pending.len();
+        this.pending.retain(|entry| entry.meta_data.url() != *url);
+        let removed_pending = this.pending.len() != pending;
+        let removed = match &mut this.backend {
+            Some(backend) => backend.remove(url),
+            None => false,
+        };
+        removed || removed_pending
+    }
+
+    fn backend_update_meta_data(self: Pin<&mut Self>, meta_data: &QNetworkCacheMetaData) {
+        if let Some(backend) = &mut self.rust_mut().get_mut().backend {
+            backend.update_meta_data(meta_data);
+        }
+    }
+}
+
+impl Deref for RustNetworkCache {
+    type Target = QAbstractNetworkCache;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::{Read, Write};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct MemoryCache {
+        entries: HashMap<String, (QNetworkCacheMetaData, Vec<u8>)>,
+    }
+
+    impl NetworkCacheBackend for MemoryCache {
+        fn meta_data(&mut self, url: &QUrl) -> Option<QNetworkCacheMetaData> {
+            let (meta_data, _) = self.entries.get(&url.to_string())?;
+            Some(meta_data.clone())
+        }
+
+        fn update_meta_data(&mut self, meta_data: &QNetworkCacheMetaData) {
+            if let Some(entry) = self.entries.get_mut(&meta_data.url().to_string()) {
+                entry.0 = meta_data.clone();
+            }
+        }
+
+        fn data(&mut self, url: &QUrl) -> Option<Vec<u8>> {
+            let (_, data) = self.entries.get(&url.to_string())?;
+            Some(data.clone())
+        }
+
+        fn insert(&mut self, meta_data: QNetworkCacheMetaData, data: Vec<u8>) {
+            self.entries
+                .insert(meta_data.url().to_string(), (meta_data, data));
+        }
+
+        fn remove(&mut self, url: &QUrl) -> bool {
+            self.entries.remove(&url.to_string()).is_some()
+        }
+
+        fn cache_size(&self) -> i64 {
+            self.entries
+                .values()
+                .map(|(_, data)| i64::try_from(data.len()).unwrap_or(i64::MAX))
+                .sum()
+        }
+
+        fn clear(&mut self) {
+            self.entries.clear();
+        }
+    }
+
+    #[test]
+    fn insert_and_read() {
+        let url = QUrl::from("https://example.com/resource");
+        let mut meta_data = QNetworkCacheMetaData::default();
+        meta_data.set_url(&url);
+
+        let mut cache = RustNetworkCache::new(MemoryCache::default());
+        let mut cache = cache.pin_mut();
+        let mut writer = cache.as_network_cache_mut().prepare(&meta_data).unwrap();
+        writer.write_all(b"cached").unwrap();
+        writer.insert();
+
+        let mut data = Vec::new();
+        let mut device = cache.as_network_cache_mut().data(&url);
+        device.pin_mut().read_to_end(&mut data).unwrap();
+
+        let found_meta_data = cache.as_network_cache_mut().meta_data(&url);
+        assert!(found_meta_data == Some(meta_data));
+        assert_eq!((data, cache.cache_size()), (b"cached".to_vec(), 6));
+    }
+}
@@ -0,0 +1,238 @@
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+use cxx_qt::CxxQtType;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QList, QUrl};
+
+use crate::qobject::debug_qobject;
+use crate::{QNetworkCookie, QNetworkCookieJar};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-io/qnetworkcookie.h");
+        type QNetworkCookie = crate::QNetworkCookie;
+        include!("cxx-qt-io/qlist.h");
+        type QList_QNetworkCookie = cxx_qt_lib::QList<QNetworkCookie>;
+        include!("cxx-qt-io/qnetworkcookiejar.h");
+        type QNetworkCookieJar = crate::QNetworkCookieJar;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "RustQt" {
+        /// A [`QNetworkCookieJar`](crate::QNetworkCookieJar) subclass that consults a [`NetworkCookieJarPolicy`](crate::NetworkCookieJarPolicy) implemented in Rust when cookies are sent and stored.
+        ///
+        /// Cookies are stored by the default `QNetworkCookieJar` implementation. The policy can filter the cookies returned by [`cookies_for_url`](crate::QNetworkCookieJar::cookies_for_url), filter the cookies passed to [`set_cookies_from_url`](crate::QNetworkCookieJar::set_cookies_from_url), and reject individual cookies in addition to Qt's default validation.
+        ///
+        /// A `RustNetworkCookieJar` can be installed with [`QNetworkAccessManager::set_cookie_jar`](crate::QNetworkAccessManager::set_cookie_jar).
+        #[qobject]
+        #[base = QNetworkCookieJar]
+        type RustNetworkCookieJar = super::RustNetworkCookieJarRust;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "cookiesForUrl"]
+        fn policy_cookies_for_url(self: &RustNetworkCookieJar, url: &QUrl) -> QList_QNetworkCookie;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "setCookiesFromUrl"]
+        fn policy_set_cookies_from_url(
+            self: Pin<&mut RustNetworkCookieJar>,
+            cookie_list: &QList_QNetworkCookie,
+            url: &QUrl,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[qinvokable]
+        #[cxx_override]
+        #[cxx_name = "validateCookie"]
+        fn policy_validate_cookie(
+            self: &RustNetworkCookieJar,
+            cookie: &QNetworkCookie,
+            url: &QUrl,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "cookiesForUrl"]
+        fn base_cookies_for_url(self: &RustNetworkCookieJar, url: &QUrl) -> QList_QNetworkCookie;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "setCookiesFromUrl"]
+        fn base_set_cookies_from_url(
+            self: Pin<&mut RustNetworkCookieJar>,
+            cookie_list: &QList_QNetworkCookie,
+            url: &QUrl,
+        ) -> bool;
+
+        #[doc(hidden)]
+        #[inherit]
+        #[cxx_name = "validateCookie"]
+        fn base_validate_cookie(
+            self: &RustNetworkCookieJar,
+            cookie: &QNetworkCookie,
+            url: &QUrl,
+        ) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "rustnetworkcookiejar_init_default"]
+        fn make_unique() -> UniquePtr<RustNetworkCookieJar>;
+    }
+}
+
+pub use ffi::RustNetworkCookieJar;
+
+/// A cookie policy that can be applied to a [`QNetworkCookieJar`] through [`RustNetworkCookieJar`].
+///
+/// Every method has a default implementation that leaves Qt's behavior unchanged, so implementations only need to override the hooks they are interested in.
+pub trait NetworkCookieJarPolicy: 'static {
+    /// Filters the cookies that will be sent in a request to `url`. `cookies` holds the cookies selected by the default [`QNetworkCookieJar::cookies_for_url`] implementation, sorted decreasingly by path length.
+    ///
+    /// The default implementation returns `cookies` unchanged.
+    fn cookies_for_url(&self, url: &QUrl, cookies: QList<QNetworkCookie>) -> QList<QNetworkCookie> {
+        let _ = url;
+        cookies
+    }
+
+    /// Filters the cookies received in a reply from `url` before they are stored. The returned cookies are passed to the default [`QNetworkCookieJar::set_cookies_from_url`] implementation, which normalizes them and stores each cookie accepted by [`validate_cookie`](NetworkCookieJarPolicy::validate_cookie).
+    ///
+    /// The default implementation returns `cookie_list` unchanged.
+    fn set_cookies_from_url(
+        &mut self,
+        cookie_list: QList<QNetworkCookie>,
+        url: &QUrl,
+    ) -> QList<QNetworkCookie> {
+        let _ = url;
+        cookie_list
+    }
+
+    /// Returns `true` if `cookie`, received from `url`, should be stored. This is only called for cookies that pass Qt's default validation, which rejects cookies whose domain does not match `url` or is a public suffix.
+    ///
+    /// The default implementation returns `true`.
+    fn validate_cookie(&self, cookie: &QNetworkCookie, url: &QUrl) -> bool {
+        let _ = (cookie, url);
+        true
+    }
+}
+
+#[doc(hidden)]
+#[derive(Default)]
+pub struct RustNetworkCookieJarRust {
+    policy: Option<Box<dyn NetworkCookieJarPolicy>>,
+}
+
+impl fmt::Debug for RustNetworkCookieJar {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self.as_cookie_jar())
+    }
+}
+
+impl RustNetworkCookieJar {
+    /// Constructs a cookie jar that applies `policy`.
+    pub fn new<P: NetworkCookieJarPolicy>(policy: P) -> UniquePtr<Self> {
+        let mut jar = ffi::rustnetworkcookiejar_init_default();
+        jar.pin_mut().rust_mut().get_mut().policy = Some(Box::new(policy));
+        jar
+    }
+
+    /// Casts this object to `QNetworkCookieJar`.
+    pub fn as_cookie_jar(&self) -> &QNetworkCookieJar {
+        self.upcast()
+    }
+
+    /// Mutably casts this object to `QNetworkCookieJar`.
+    pub fn as_cookie_jar_mut<'a>(self: &'a mut Pin<&mut Self>) -> Pin<&'a mut QNetworkCookieJar> {
+        self.as_mut().upcast_pin()
+    }
+
+    fn policy_cookies_for_url(&self, url: &QUrl) -> QList<QNetworkCookie> {
+        let cookies = self.base_cookies_for_url(url);
+        match &self.rust().policy {
+            Some(policy) => policy.cookies_for_url(url, cookies),
+            None => cookies,
+        }
+    }
+
+    fn policy_set_cookies_from_url(
+        mut self: Pin<&mut Self>,
+        cookie_list: &QList<QNetworkCookie>,
+        url: &QUrl,
+    ) -> bool {
+        let cookie_list = match &mut self.as_mut().rust_mut().get_mut().policy {
+            Some(policy) => policy.set_cookies_from_url(cookie_list.clone(), url),
+            None => cookie_list.clone(),
+        };
+        self.base_set_cookies_from_url(&cookie_list, url)
+    }
+
+    fn policy_validate_cookie(&self, cookie: &QNetworkCookie, url: &QUrl) -> bool {
+        if !self.base_validate_cookie(cookie, url) {
+            return false;
+        }
+        match &self.rust().policy {
+            Some(policy) => policy.validate_cookie(cookie, url),
+            None => true,
+        }
+    }
+}
+
+impl Deref for RustNetworkCookieJar {
+    type Target = QNetworkCookieJar;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cxx_qt_lib::{QByteArray, QString};
+
+    use super::*;
+
+    struct BlockDomain(&'static str);
+
+    impl NetworkCookieJarPolicy for BlockDomain {
+        fn validate_cookie(&self, cookie: &QNetworkCookie, _url: &QUrl) -> bool {
+            !cookie.domain().to_string().ends_with(self.0)
+        }
+    }
+
+    #[test]
+    fn validate_cookie() {
+        let mut allowed = QNetworkCookie::new(&QByteArray::from("a"), &QByteArray::from("1"));
+        allowed.set_domain(&QString::from(".example.com"));
+        let mut blocked = QNetworkCookie::new(&QByteArray::from("b"), &QByteArray::from("2"));
+        blocked.set_domain(&QString::from(".tracker.example.com"));
+        let mut cookie_list = QList::default();
+        cookie_list.append(allowed.clone());
+        cookie_list.append(blocked);
+
+        let url = QUrl::from("https://tracker.example.com/");
+        let mut jar = RustNetworkCookieJar::new(BlockDomain("tracker.example.com"));
+        jar.pin_mut()
+            .as_cookie_jar_mut()
+            .set_cookies_from_url(&cookie_list, &url);
+
+        let names: Vec<QByteArray> = jar
+            .cookies_for_url(&url)
+            .iter()
+            .map(QNetworkCookie::name)
+            .collect();
+        assert_eq!(names, [allowed.name()]);
+    }
+}
@@ -1,18 +1,20 @@
 use std::fmt;
 use std::pin::Pin;
+use std::ptr;
 use std::time::Duration;
 
 use cxx::UniquePtr;
 use cxx::memory::UniquePtrTarget;
 use cxx_qt::casting::Upcast;
-use cxx_qt_lib::{QByteArray, QString};
+use cxx_qt_lib::{QByteArray, QString, QUrl};
 
 use crate::core::qobject::in_same_thread;
+use crate::net::qnetworkproxyfactory::set_manager_proxy_factory;
 use crate::qobject::debug_qobject;
 use crate::util::{unpin_for_qt, upcast_mut};
 use crate::{
-    QAbstractNetworkCache, QHttpMultiPart, QIODevice, QNetworkCookieJar, QNetworkReply,
-    QNetworkRequest,
+    NetworkProxyFactory, QAbstractNetworkCache, QHttpMultiPart, QIODevice, QNetworkCookieJar,
+    QNetworkReply, QNetworkRequest, RequestBuilder,
 };
 
 #[cxx_qt::bridge]
@@ -271,6 +273,39 @@ mod ffi {
         #[rust_name = "redirect_policy"]
         fn redirectPolicy(self: &QNetworkAccessManager) -> QNetworkRequestRedirectPolicy;
 
+        /// # Safety
+        ///
+        /// `data` must be null or opened for reading when this function is called, and must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "send_custom_request_device_raw"]
+        unsafe fn sendCustomRequest(
+            self: Pin<&mut QNetworkAccessManager>,
+            request: &QNetworkRequest,
+            verb: &QByteArray,
+            data: *mut QIODevice,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "send_custom_request_data_raw"]
+        fn sendCustomRequest(
+            self: Pin<&mut QNetworkAccessManager>,
+            request: &QNetworkRequest,
+            verb: &QByteArray,
+            data: &QByteArray,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `multi_part` must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "send_custom_request_http_raw"]
+        unsafe fn sendCustomRequest(
+            self: Pin<&mut QNetworkAccessManager>,
+            request: &QNetworkRequest,
+            verb: &QByteArray,
+            multi_part: *mut QHttpMultiPart,
+        ) -> *mut QNetworkReply;
+
         /// **Warning:** passing `false` to this function will cause memory leaks unless
         /// QNetworkReplies are manually deleted.
         #[doc(hidden)]
@@ -407,33 +442,6 @@ mod ffi {
 
     #[namespace = "rust::cxxqtio1"]
     unsafe extern "C++" {
-        #[rust_name = "qnetworkaccessmanager_options"]
-        fn qnetworkaccessmanagerOptions(
-            manager: Pin<&mut QNetworkAccessManager>,
-            request: &QNetworkRequest,
-        ) -> *mut QNetworkReply;
-
-        #[rust_name = "qnetworkaccessmanager_patch_data_raw"]
-        fn qnetworkaccessmanagerPatch(
-            manager: Pin<&mut QNetworkAccessManager>,
-            request: &QNetworkRequest,
-            data: &QByteArray,
-        ) -> *mut QNetworkReply;
-
-        #[rust_name = "qnetworkaccessmanager_patch_raw"]
-        unsafe fn qnetworkaccessmanagerPatch(
-            manager: Pin<&mut QNetworkAccessManager>,
-            request: &QNetworkRequest,
-            data: *mut QIODevice,
-        ) -> *mut QNetworkReply;
-
-        #[rust_name = "qnetworkaccessmanager_patch_http_raw"]
-        unsafe fn qnetworkaccessmanagerPatch(
-            manager: Pin<&mut QNetworkAccessManager>,
-            request: &QNetworkRequest,
-            multi_part: *mut QHttpMultiPart,
-        ) -> *mut QNetworkReply;
-
         #[rust_name = "qnetworkaccessmanager_set_transfer_timeout_msecs"]
         fn qnetworkaccessmanagerSetTransferTimeoutMsecs(
             manager: Pin<&mut QNetworkAccessManager>,
@@ -500,16 +508,14 @@ impl QNetworkAccessManager {
         unsafe { Pin::new_unchecked(&mut *reply) }
     }
 
-    /// Sends a PATCH request to obtain the network headers for request and returns a new `QNetworkReply` object which will contain such headers.
+    /// Sends an OPTIONS request to obtain the communication options available for the target resource of `request`, and returns a new `QNetworkReply` object which will contain the response.
     ///
     /// The function is named after the HTTP request associated (`OPTIONS`).
     pub fn options<'a>(
         self: Pin<&'a mut Self>,
         request: &QNetworkRequest,
     ) -> Pin<&'a mut QNetworkReply> {
-        let reply = ffi::qnetworkaccessmanager_options(self, request);
-        // SAFETY: This object is the parent of the reply, which is valid and non-null.
-        unsafe { Pin::new_unchecked(&mut *reply) }
+        self.send_custom_request(request, &QByteArray::from("OPTIONS"))
     }
 
     /// Sends an HTTP PATCH request to the destination specified by request and returns a new `QNetworkReply` object opened for reading that will contain the reply sent by the server. The contents of the `data` device will be uploaded to the server.
@@ -525,12 +531,7 @@ impl QNetworkAccessManager {
     where
         T: Upcast<QIODevice>,
     {
-        // SAFETY: The lifetime parameter prevents concurrent modification of `data`.
-        let reply = unsafe {
-            ffi::qnetworkaccessmanager_patch_raw(self, request, upcast_mut(unpin_for_qt(data)))
-        };
-        // SAFETY: This object is the parent of the reply, which is valid and non-null.
-        unsafe { Pin::new_unchecked(&mut *reply) }
+        self.send_custom_request_device(request, &QByteArray::from("PATCH"), data)
     }
 
     /// Sends the contents of the `data` byte array to the destination specified by `request`.
@@ -539,9 +540,7 @@ impl QNetworkAccessManager {
         request: &QNetworkRequest,
         data: &QByteArray,
     ) -> Pin<&mut QNetworkReply> {
-        let reply = ffi::qnetworkaccessmanager_patch_data_raw(self, request, data);
-        // SAFETY: This object is the parent of the reply, which is valid and non-null.
-        unsafe { Pin::new_unchecked(&mut *reply) }
+        self.send_custom_request_data(request, &QByteArray::from("PATCH"), data)
     }
 
     /// Sends the contents of the `multi_part` message to the destination specified by request.
@@ -552,13 +551,9 @@ impl QNetworkAccessManager {
         request: &QNetworkRequest,
         multi_part: Pin<&'a mut QHttpMultiPart>,
     ) -> Pin<&'a mut QNetworkReply> {
-        // SAFETY: The lifetime parameter prevents concurrent modification of `multi_part`.
-        let reply = unsafe {
-            ffi::qnetworkaccessmanager_patch_http_raw(self, request, unpin_for_qt(multi_part))
-        };
-        // SAFETY: This object is the parent of the reply, which is valid and non-null.
-        unsafe { Pin::new_unchecked(&mut *reply) }
+        self.send_custom_request_http(request, &QByteArray::from("PATCH"), multi_part)
     }
+
     /// Sends an HTTP POST request to the destination specified by request and returns a new `QNetworkReply` object opened for reading that will contain the reply sent by the server. The contents of the `data` device will be uploaded to the server.
     ///
     /// `data` must be open for reading.
@@ -649,6 +644,85 @@ impl QNetworkAccessManager {
         unsafe { Pin::new_unchecked(&mut *reply) }
     }
 
+    /// Returns a [`RequestBuilder`] for a request to `url` using `verb` as the HTTP method. The request is dispatched on this manager when [`RequestBuilder::send`] is called.
+    pub fn request<V: AsRef<[u8]>>(
+        self: Pin<&mut Self>,
+        verb: V,
+        url: &QUrl,
+    ) -> RequestBuilder<'_> {
+        RequestBuilder::new(self, verb.as_ref(), url)
+    }
+
+    /// Sends a custom request to the server identified by the URL of `request`, using `verb` as the HTTP method, and returns a new `QNetworkReply` object which will contain the response. No request body is sent.
+    ///
+    /// It is the user's responsibility to send a `verb` that is valid according to the HTTP specification.
+    ///
+    /// This method provides means to send verbs other than the common ones provided via [`get`](QNetworkAccessManager::get) or [`post`](QNetworkAccessManager::post) etc., for instance sending an HTTP PROPFIND or MKCOL command.
+    ///
+    /// **Note:** This feature is currently available for HTTP(S) only.
+    pub fn send_custom_request<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        verb: &QByteArray,
+    ) -> Pin<&'a mut QNetworkReply> {
+        // SAFETY: A null device means no body is sent.
+        let reply = unsafe { self.send_custom_request_device_raw(request, verb, ptr::null_mut()) };
+        // SAFETY: This object is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Sends a custom request to the server identified by the URL of `request`, using `verb` as the HTTP method, and returns a new `QNetworkReply` object which will contain the response. The contents of the `data` device will be uploaded to the server.
+    ///
+    /// `data` must be open for reading.
+    ///
+    /// **Note:** This feature is currently available for HTTP(S) only.
+    pub fn send_custom_request_device<'a, T>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        verb: &QByteArray,
+        data: Pin<&'a mut T>,
+    ) -> Pin<&'a mut QNetworkReply>
+    where
+        T: Upcast<QIODevice>,
+    {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `data`.
+        let reply = unsafe {
+            self.send_custom_request_device_raw(request, verb, upcast_mut(unpin_for_qt(data)))
+        };
+        // SAFETY: This object is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Sends a custom request to the server identified by the URL of `request`, using `verb` as the HTTP method, and returns a new `QNetworkReply` object which will contain the response. The contents of the `data` byte array will be uploaded to the server.
+    ///
+    /// **Note:** This feature is currently available for HTTP(S) only.
+    pub fn send_custom_request_data<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        verb: &QByteArray,
+        data: &QByteArray,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.send_custom_request_data_raw(request, verb, data);
+        // SAFETY: This object is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Sends a custom request to the server identified by the URL of `request`, using `verb` as the HTTP method, and returns a new `QNetworkReply` object which will contain the response. The contents of the `multi_part` message will be sent to the server.
+    ///
+    /// **Note:** This feature is currently available for HTTP(S) only.
+    pub fn send_custom_request_http<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        verb: &QByteArray,
+        multi_part: Pin<&'a mut QHttpMultiPart>,
+    ) -> Pin<&'a mut QNetworkReply> {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `multi_part`.
+        let reply =
+            unsafe { self.send_custom_request_http_raw(request, verb, unpin_for_qt(multi_part)) };
+        // SAFETY: This object is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
     /// Sets the manager's network cache to be the `cache` specified. The cache is used for all requests dispatched by the manager. **The manager takes ownership of `cache`.**
     ///
     /// Use this function to set the network cache object to a class that implements additional features, like saving the cookies to permanent storage.
@@ -685,6 +759,15 @@ impl QNetworkAccessManager {
         inner(self, upcast_mut(cookie_jar.into_raw()));
     }
 
+    /// Sets the proxy factory for this class to be `factory`. A proxy factory is used to determine a more specific list of proxies to be used for a given request, instead of trying to use the same proxy value for all requests.
+    ///
+    /// All queries sent by `QNetworkAccessManager` will have type [`QNetworkProxyQueryQueryType::UrlRequest`](crate::QNetworkProxyQueryQueryType::UrlRequest).
+    ///
+    /// Setting a proxy factory replaces the proxy set with [`set_proxy`](QNetworkAccessManager::set_proxy).
+    pub fn set_proxy_factory<F: NetworkProxyFactory>(self: Pin<&mut Self>, factory: F) {
+        set_manager_proxy_factory(self, factory);
+    }
+
     /// Sets `timeout` as the transfer timeout.
     ///
     /// Transfers are aborted if no bytes are transferred before the timeout expires. `None` means no timer is set. If this function is not called, the timeout is disabled.
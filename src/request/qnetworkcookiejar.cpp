@@ -0,0 +1,37 @@
+#include "cxx-qt-io/qnetworkcookiejar.h"
+
+namespace {
+// Grants access to protected members of QNetworkCookieJar.
+class QNetworkCookieJarAccessor : public QNetworkCookieJar
+{
+public:
+  static QList<QNetworkCookie> allCookiesOf(const QNetworkCookieJar& jar)
+  {
+    return (jar.*(&QNetworkCookieJarAccessor::allCookies))();
+  }
+
+  static void setAllCookiesOf(QNetworkCookieJar& jar,
+                              const QList<QNetworkCookie>& cookieList)
+  {
+    (jar.*(&QNetworkCookieJarAccessor::setAllCookies))(cookieList);
+  }
+};
+}
+
+namespace rust {
+namespace cxxqtio1 {
+QList<QNetworkCookie>
+qnetworkcookiejarAllCookies(const QNetworkCookieJar& jar)
+{
+  return QNetworkCookieJarAccessor::allCookiesOf(jar);
+}
+
+void
+qnetworkcookiejarSetAllCookies(QNetworkCookieJar& jar,
+                               const QList<QNetworkCookie>& cookieList)
+{
+  QNetworkCookieJarAccessor::setAllCookiesOf(jar, cookieList);
+}
+
+}
+}
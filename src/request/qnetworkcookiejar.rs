@@ -1,11 +1,15 @@
 use std::fmt;
+use std::io::{self, BufRead, Write};
 use std::ops::Deref;
+use std::pin::Pin;
 
 use cxx::UniquePtr;
 use cxx_qt::QObject;
 use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QByteArray, QDateTime, QList, QString, QTimeZone};
 
 use crate::qobject::debug_qobject;
+use crate::{QNetworkCookie, QNetworkCookieRawForm};
 
 #[cxx_qt::bridge]
 mod ffi {
@@ -71,6 +75,18 @@ mod ffi {
         fn updateCookie(self: Pin<&mut QNetworkCookieJar>, cookie: &QNetworkCookie) -> bool;
     }
 
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qnetworkcookiejar_all_cookies"]
+        fn qnetworkcookiejarAllCookies(jar: &QNetworkCookieJar) -> QList_QNetworkCookie;
+
+        #[rust_name = "qnetworkcookiejar_set_all_cookies"]
+        fn qnetworkcookiejarSetAllCookies(
+            jar: Pin<&mut QNetworkCookieJar>,
+            cookie_list: &QList_QNetworkCookie,
+        );
+    }
+
     #[namespace = "rust::cxxqtlib1"]
     unsafe extern "C++" {
         include!("cxx-qt-lib/common.h");
@@ -92,6 +108,146 @@ impl QNetworkCookieJar {
     pub fn new() -> UniquePtr<Self> {
         ffi::qnetworkcookiejar_default()
     }
+
+    /// Returns all cookies stored in this cookie jar, regardless of URL. This can be used to save cookies to disk, as well as to implement cookie expiration and other policies.
+    ///
+    /// This function is protected in Qt.
+    pub fn all_cookies(&self) -> QList<QNetworkCookie> {
+        ffi::qnetworkcookiejar_all_cookies(self)
+    }
+
+    /// Sets the internal list of cookies held by this cookie jar to be `cookie_list`, replacing any cookies it previously held. This can be used to restore cookies from permanent storage. Cookies are not validated or normalized.
+    ///
+    /// This function is protected in Qt.
+    pub fn set_all_cookies(self: Pin<&mut Self>, cookie_list: &QList<QNetworkCookie>) {
+        ffi::qnetworkcookiejar_set_all_cookies(self, cookie_list);
+    }
+
+    /// Replaces all cookies in this cookie jar with cookies read from `reader`, one per line in the raw form produced by [`save_raw_cookies`](QNetworkCookieJar::save_raw_cookies). Empty lines are ignored.
+    pub fn load_raw_cookies<R: BufRead>(self: Pin<&mut Self>, reader: R) -> io::Result<()> {
+        let mut cookie_list = QList::default();
+        for line in reader.split(b'\n') {
+            let line = line?;
+            let line = line.trim_ascii();
+            if line.is_empty() {
+                continue;
+            }
+            for cookie in &QNetworkCookie::parse_cookies(&line) {
+                cookie_list.append_clone(cookie);
+            }
+        }
+        self.set_all_cookies(&cookie_list);
+        Ok(())
+    }
+
+    /// Writes all cookies in this cookie jar to `writer`, one per line, using [`QNetworkCookieRawForm::Full`].
+    pub fn save_raw_cookies<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        for cookie in &self.all_cookies() {
+            writer.write_all(cookie.to_raw_form(QNetworkCookieRawForm::Full).as_slice())?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()
+    }
+
+    /// Replaces all cookies in this cookie jar with cookies read from `reader` in the Netscape `cookies.txt` format, as used by curl and wget.
+    ///
+    /// Each line holds seven tab-separated fields: domain, whether subdomains are included, path, whether the cookie is secure, expiration time in seconds since the Unix epoch (or 0 for a session cookie), name, and value. Lines starting with `#` are comments, except for the `#HttpOnly_` domain prefix. Empty lines are ignored.
+    ///
+    /// Returns an error of kind [`io::ErrorKind::InvalidData`] if a line is malformed.
+    pub fn load_cookies_txt<R: BufRead>(self: Pin<&mut Self>, reader: R) -> io::Result<()> {
+        let mut cookie_list = QList::default();
+        for line in reader.split(b'\n') {
+            let line = line?;
+            let line = line.strip_suffix(b"\r").unwrap_or(&line);
+            let (line, http_only) = match line.strip_prefix(HTTP_ONLY_PREFIX) {
+                Some(line) => (line, true),
+                None if line.starts_with(b"#") || line.trim_ascii().is_empty() => continue,
+                None => (line, false),
+            };
+            let mut cookie = parse_cookies_txt_line(line).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "malformed cookies.txt line: {}",
+                        String::from_utf8_lossy(line)
+                    ),
+                )
+            })?;
+            cookie.set_http_only(http_only);
+            cookie_list.append(cookie);
+        }
+        self.set_all_cookies(&cookie_list);
+        Ok(())
+    }
+
+    /// Writes all cookies in this cookie jar to `writer` in the Netscape `cookies.txt` format. See [`load_cookies_txt`](QNetworkCookieJar::load_cookies_txt) for a description of the format.
+    pub fn save_cookies_txt<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writer.write_all(b"# Netscape HTTP Cookie File\n")?;
+        for cookie in &self.all_cookies() {
+            let domain = cookie.domain().to_string();
+            let expiration = match cookie.expiration_date() {
+                Some(date) => i64::from(date.to_secs_since_epoch()),
+                None => 0,
+            };
+            if cookie.is_http_only() {
+                writer.write_all(HTTP_ONLY_PREFIX)?;
+            }
+            write!(
+                writer,
+                "{domain}\t{}\t{}\t{}\t{expiration}\t",
+                netscape_bool(domain.starts_with('.')),
+                cookie.path(),
+                netscape_bool(cookie.is_secure()),
+            )?;
+            writer.write_all(cookie.name().as_slice())?;
+            writer.write_all(b"\t")?;
+            writer.write_all(cookie.value().as_slice())?;
+            writer.write_all(b"\n")?;
+        }
+        writer.flush()
+    }
+}
+
+const HTTP_ONLY_PREFIX: &[u8] = b"#HttpOnly_";
+
+const fn netscape_bool(value: bool) -> &'static str {
+    if value { "TRUE" } else { "FALSE" }
+}
+
+fn parse_netscape_bool(field: &[u8]) -> Option<bool> {
+    if field.eq_ignore_ascii_case(b"TRUE") {
+        Some(true)
+    } else if field.eq_ignore_ascii_case(b"FALSE") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+fn parse_cookies_txt_line(line: &[u8]) -> Option<QNetworkCookie> {
+    let mut fields = line.splitn(7, |&c| c == b'\t');
+    let domain = std::str::from_utf8(fields.next()?).ok()?;
+    let include_subdomains = parse_netscape_bool(fields.next()?)?;
+    let path = std::str::from_utf8(fields.next()?).ok()?;
+    let secure = parse_netscape_bool(fields.next()?)?;
+    let expiration: i64 = std::str::from_utf8(fields.next()?).ok()?.parse().ok()?;
+    let name = fields.next()?;
+    let value = fields.next()?;
+
+    let mut cookie = QNetworkCookie::new(&QByteArray::from(name), &QByteArray::from(value));
+    let domain = domain.trim_start_matches('.');
+    if include_subdomains {
+        cookie.set_domain(&QString::from(&format!(".{domain}")));
+    } else {
+        cookie.set_domain(&QString::from(domain));
+    }
+    cookie.set_path(&QString::from(path));
+    cookie.set_secure(secure);
+    if expiration != 0 {
+        let date = QDateTime::from_secs_since_epoch(expiration.into(), &QTimeZone::utc());
+        cookie.set_expiration_date(Some(&date));
+    }
+    Some(cookie)
 }
 
 impl Deref for QNetworkCookieJar {
@@ -101,3 +257,60 @@ impl Deref for QNetworkCookieJar {
         self.upcast()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cookie_list() -> QList<QNetworkCookie> {
+        let mut session = QNetworkCookie::new(&QByteArray::from("session"), &QByteArray::from("1"));
+        session.set_domain(&QString::from("example.com"));
+        session.set_path(&QString::from("/"));
+        session.set_http_only(true);
+
+        let mut persistent =
+            QNetworkCookie::new(&QByteArray::from("persistent"), &QByteArray::from("2"));
+        persistent.set_domain(&QString::from(".example.org"));
+        persistent.set_path(&QString::from("/app"));
+        persistent.set_secure(true);
+        persistent.set_expiration_date(Some(&QDateTime::from_secs_since_epoch(
+            2_000_000_000.into(),
+            &QTimeZone::utc(),
+        )));
+
+        let mut cookie_list = QList::default();
+        cookie_list.append(session);
+        cookie_list.append(persistent);
+        cookie_list
+    }
+
+    #[test]
+    fn round_trip_cookies_txt() {
+        let mut jar = QNetworkCookieJar::new();
+        jar.pin_mut().set_all_cookies(&cookie_list());
+        let mut saved = Vec::new();
+        jar.save_cookies_txt(&mut saved).unwrap();
+
+        let mut restored = QNetworkCookieJar::new();
+        restored
+            .pin_mut()
+            .load_cookies_txt(saved.as_slice())
+            .unwrap();
+        assert_eq!(restored.all_cookies(), cookie_list());
+    }
+
+    #[test]
+    fn round_trip_raw_cookies() {
+        let mut jar = QNetworkCookieJar::new();
+        jar.pin_mut().set_all_cookies(&cookie_list());
+        let mut saved = Vec::new();
+        jar.save_raw_cookies(&mut saved).unwrap();
+
+        let mut restored = QNetworkCookieJar::new();
+        restored
+            .pin_mut()
+            .load_raw_cookies(saved.as_slice())
+            .unwrap();
+        assert_eq!(restored.all_cookies(), cookie_list());
+    }
+}
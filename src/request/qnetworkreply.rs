@@ -165,6 +165,8 @@ mod ffi {
     unsafe extern "C++Qt" {
         /// The `QNetworkReply` class contains the data and headers for a request sent with [`QNetworkAccessManager`].
         ///
+        /// A `Pin<&mut QNetworkReply>` can be awaited, which resolves to an [`HttpResponse`](crate::HttpResponse) once the reply has finished. See [`QNetworkReplyFuture`](crate::QNetworkReplyFuture).
+        ///
         /// Qt Documentation: [QNetworkReply](https://doc.qt.io/qt-6/qnetworkreply.html#details)
         #[qobject]
         #[base = QIODevice]
@@ -275,6 +277,10 @@ mod ffi {
         /// Returns the URL of the content downloaded or uploaded. Note that the URL may be different from that of the original request. If redirections were enabled in the request, then this function returns the current url that the network API is accessing, i.e the url of the resource the request got redirected to.
         fn url(self: &QNetworkReply) -> QUrl;
 
+        #[doc(hidden)]
+        #[qsignal]
+        fn destroyed(self: Pin<&mut QNetworkReply>);
+
         #[doc(hidden)]
         #[qsignal]
         #[rust_name = "download_progress_qint64"]
@@ -0,0 +1,114 @@
+use std::future::{Future, IntoFuture};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll, Waker};
+
+use cxx_qt::QMetaObjectConnectionGuard;
+
+use crate::{HttpResponse, QNetworkReply};
+
+#[derive(Default)]
+struct ReplyState {
+    resolved: bool,
+    response: Option<HttpResponse>,
+    waker: Option<Waker>,
+}
+
+impl ReplyState {
+    fn resolve(&mut self, response: HttpResponse) -> Option<Waker> {
+        if self.resolved {
+            return None;
+        }
+        self.resolved = true;
+        self.response = Some(response);
+        self.waker.take()
+    }
+}
+
+fn lock(state: &Mutex<ReplyState>) -> MutexGuard<'_, ReplyState> {
+    state.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// A future that resolves to an [`HttpResponse`] once a [`QNetworkReply`] has finished.
+///
+/// This future is driven by the Qt event loop: it completes when the reply emits [`QNetworkReply::finished`]. As such, the thread that owns the reply must be running an event loop (for example, via `QCoreApplication::exec`), and the future will never complete otherwise.
+///
+/// When the reply finishes, the future reads all remaining body data from it with [`read_all`](crate::QIODevice::read_all). Any other code reading from the same reply will therefore see an empty body once the future has resolved, and any body data read elsewhere beforehand will be missing from the [`HttpResponse`].
+///
+/// If the reply is destroyed before it finishes (for example, because its [`QNetworkAccessManager`](crate::QNetworkAccessManager) was dropped), the future resolves to a response with the error [`QNetworkReplyNetworkError::OperationCanceledError`](crate::QNetworkReplyNetworkError::OperationCanceledError).
+///
+/// Dropping this future does not abort the request. Use [`QNetworkReply::abort`] for that.
+///
+/// Created by awaiting a `Pin<&mut QNetworkReply>`, or by calling [`IntoFuture::into_future`] on one.
+///
+/// # Panics
+///
+/// Polling the future again after it has returned [`Poll::Ready`] panics.
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct QNetworkReplyFuture {
+    state: Arc<Mutex<ReplyState>>,
+    _connections: Option<[QMetaObjectConnectionGuard; 2]>,
+}
+
+impl Future for QNetworkReplyFuture {
+    type Output = HttpResponse;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut state = lock(&self.state);
+        if let Some(response) = state.response.take() {
+            return Poll::Ready(response);
+        }
+        assert!(
+            !state.resolved,
+            "`QNetworkReplyFuture` polled after completion"
+        );
+        match &mut state.waker {
+            Some(waker) => waker.clone_from(cx.waker()),
+            None => state.waker = Some(cx.waker().clone()),
+        }
+        Poll::Pending
+    }
+}
+
+impl IntoFuture for Pin<&mut QNetworkReply> {
+    type Output = HttpResponse;
+
+    type IntoFuture = QNetworkReplyFuture;
+
+    /// Creates a future that resolves to an [`HttpResponse`] once the reply has finished. If the reply has already finished, the future resolves immediately.
+    fn into_future(mut self) -> Self::IntoFuture {
+        let state = Arc::new(Mutex::new(ReplyState::default()));
+
+        if self.is_finished() {
+            lock(&state).resolve(HttpResponse::from_reply(self));
+            return QNetworkReplyFuture {
+                state,
+                _connections: None,
+            };
+        }
+
+        let finished_state = Arc::clone(&state);
+        let finished = self.as_mut().on_finished(move |reply| {
+            let response = HttpResponse::from_reply(reply);
+            let waker = lock(&finished_state).resolve(response);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        let destroyed_state = Arc::clone(&state);
+        let url = self.url();
+        // The reply is partially destroyed when this signal is emitted, so it must not be accessed.
+        let destroyed = self.as_mut().on_destroyed(move |_| {
+            let waker = lock(&destroyed_state).resolve(HttpResponse::destroyed(url.clone()));
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        });
+
+        QNetworkReplyFuture {
+            state,
+            _connections: Some([finished, destroyed]),
+        }
+    }
+}
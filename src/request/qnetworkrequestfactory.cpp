@@ -0,0 +1,47 @@
+#include "cxx-qt-io/qnetworkrequestfactory.h"
+
+#include <QtCore/QUrlQuery>
+
+#include <cxx-qt-io/assertion_utils.h>
+
+assert_shared_pointer_type(QNetworkRequestFactory);
+
+namespace rust {
+namespace cxxqtio1 {
+QNetworkRequest
+qnetworkrequestfactoryCreateRequest(const QNetworkRequestFactory& factory,
+                                    const QString& path,
+                                    const QString& query)
+{
+  return factory.createRequest(path, QUrlQuery(query));
+}
+
+QString
+qnetworkrequestfactoryQueryParameters(const QNetworkRequestFactory& factory)
+{
+  return factory.queryParameters().toString(QUrl::FullyEncoded);
+}
+
+void
+qnetworkrequestfactorySetQueryParameters(QNetworkRequestFactory& factory,
+                                         const QString& query)
+{
+  factory.setQueryParameters(QUrlQuery(query));
+}
+
+void
+qnetworkrequestfactorySetTransferTimeoutMsecs(QNetworkRequestFactory& factory,
+                                              ::std::int64_t timeout)
+{
+  factory.setTransferTimeout(::std::chrono::milliseconds{ timeout });
+}
+
+::std::int64_t
+qnetworkrequestfactoryTransferTimeoutMsecs(
+  const QNetworkRequestFactory& factory)
+{
+  return ::std::int64_t(factory.transferTimeout().count());
+}
+
+}
+}
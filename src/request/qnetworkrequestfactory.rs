@@ -0,0 +1,308 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+use std::time::Duration;
+
+use cxx::{ExternType, type_id};
+use cxx_qt_lib::{QString, QUrl};
+
+use crate::QNetworkRequest;
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-io/qhttpheaders.h");
+        type QHttpHeaders = crate::QHttpHeaders;
+        include!("cxx-qt-io/qnetworkrequest.h");
+        type QNetworkRequest = crate::QNetworkRequest;
+    }
+
+    #[cfg(feature = "ssl")]
+    extern "C++" {
+        include!("cxx-qt-io/qsslconfiguration.h");
+        type QSslConfiguration = crate::QSslConfiguration;
+    }
+
+    extern "C++" {
+        include!("cxx-qt-io/qnetworkrequestfactory.h");
+    }
+
+    unsafe extern "C++" {
+        type QNetworkRequestFactory = super::QNetworkRequestFactory;
+
+        /// Returns the base URL used for the individual requests.
+        ///
+        /// The base URL may contain a path component. This path is used as path "prefix" for the paths that are provided when generating individual requests.
+        #[rust_name = "base_url"]
+        fn baseUrl(&self) -> QUrl;
+
+        /// Returns the bearer token that has been set.
+        ///
+        /// The bearer token, if present, is used to set the `Authorization: Bearer my_token` header for requests. This is a common authorization convention and is provided as an additional convenience.
+        #[rust_name = "bearer_token"]
+        fn bearerToken(&self) -> QByteArray;
+
+        /// Clears the bearer token.
+        #[rust_name = "clear_bearer_token"]
+        fn clearBearerToken(&mut self);
+
+        /// Clears current headers.
+        #[rust_name = "clear_common_headers"]
+        fn clearCommonHeaders(&mut self);
+
+        /// Clears the password set to this factory.
+        #[rust_name = "clear_password"]
+        fn clearPassword(&mut self);
+
+        /// Clears the query parameters.
+        #[rust_name = "clear_query_parameters"]
+        fn clearQueryParameters(&mut self);
+
+        /// Clears the username set to this factory.
+        #[rust_name = "clear_user_name"]
+        fn clearUserName(&mut self);
+
+        /// Returns the currently set headers.
+        #[rust_name = "common_headers"]
+        fn commonHeaders(&self) -> QHttpHeaders;
+
+        /// Returns a [`QNetworkRequest`].
+        ///
+        /// The returned request is filled with the data that this factory has been configured with.
+        #[rust_name = "create_request"]
+        fn createRequest(&self) -> QNetworkRequest;
+
+        /// Creates a [`QNetworkRequest`].
+        ///
+        /// The returned request's URL is formed by appending the provided `path` to the [`base_url`](QNetworkRequestFactory::base_url) (which may itself have a path component).
+        #[rust_name = "create_request_for_path"]
+        fn createRequest(&self, path: &QString) -> QNetworkRequest;
+
+        /// Returns the password set to this factory.
+        fn password(&self) -> QString;
+
+        /// Sets the base URL used in individual requests to `url`.
+        #[rust_name = "set_base_url"]
+        fn setBaseUrl(&mut self, url: &QUrl);
+
+        /// Sets the bearer token to be `token`.
+        ///
+        /// If a bearer token is set, the `Authorization: Bearer my_token` header is set for requests, where `my_token` is the token.
+        #[rust_name = "set_bearer_token"]
+        fn setBearerToken(&mut self, token: &QByteArray);
+
+        /// Sets `headers` that are common to all requests.
+        ///
+        /// These headers are added to individual requests' headers. This is a convenience mechanism for setting headers that repeat across requests.
+        #[rust_name = "set_common_headers"]
+        fn setCommonHeaders(&mut self, headers: &QHttpHeaders);
+
+        /// Sets the password of this factory to `password`.
+        ///
+        /// The username and password are set in the request URL when [`create_request`](QNetworkRequestFactory::create_request) is called. The URL is set with [`QUrlParsingMode::StrictMode`](cxx_qt_lib::QUrlParsingMode::StrictMode) mode.
+        #[rust_name = "set_password"]
+        fn setPassword(&mut self, password: &QString);
+
+        /// Sets the SSL configuration to `configuration`.
+        #[cfg(feature = "ssl")]
+        #[rust_name = "set_ssl_configuration"]
+        fn setSslConfiguration(&mut self, configuration: &QSslConfiguration);
+
+        /// Sets the username of this factory to `user_name`.
+        ///
+        /// The username is set in the request URL when [`create_request`](QNetworkRequestFactory::create_request) is called. The URL is set with [`QUrlParsingMode::StrictMode`](cxx_qt_lib::QUrlParsingMode::StrictMode) mode.
+        #[rust_name = "set_user_name"]
+        fn setUserName(&mut self, user_name: &QString);
+
+        /// Returns the SSL configuration set to this factory. The SSL configuration is set to each individual request.
+        #[cfg(feature = "ssl")]
+        #[rust_name = "ssl_configuration"]
+        fn sslConfiguration(&self) -> QSslConfiguration;
+
+        /// Returns the username set to this factory.
+        #[rust_name = "user_name"]
+        fn userName(&self) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qnetworkrequestfactory_create_request"]
+        fn qnetworkrequestfactoryCreateRequest(
+            factory: &QNetworkRequestFactory,
+            path: &QString,
+            query: &QString,
+        ) -> QNetworkRequest;
+
+        #[rust_name = "qnetworkrequestfactory_query_parameters"]
+        fn qnetworkrequestfactoryQueryParameters(factory: &QNetworkRequestFactory) -> QString;
+
+        #[rust_name = "qnetworkrequestfactory_set_query_parameters"]
+        fn qnetworkrequestfactorySetQueryParameters(
+            factory: &mut QNetworkRequestFactory,
+            query: &QString,
+        );
+
+        #[rust_name = "qnetworkrequestfactory_set_transfer_timeout_msecs"]
+        fn qnetworkrequestfactorySetTransferTimeoutMsecs(
+            factory: &mut QNetworkRequestFactory,
+            timeout: i64,
+        );
+
+        #[rust_name = "qnetworkrequestfactory_transfer_timeout_msecs"]
+        fn qnetworkrequestfactoryTransferTimeoutMsecs(factory: &QNetworkRequestFactory) -> i64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qnetworkrequestfactory_drop"]
+        fn drop(factory: &mut QNetworkRequestFactory);
+
+        #[rust_name = "qnetworkrequestfactory_init_default"]
+        fn construct() -> QNetworkRequestFactory;
+        #[rust_name = "qnetworkrequestfactory_init_url"]
+        fn construct(base_url: &QUrl) -> QNetworkRequestFactory;
+        #[rust_name = "qnetworkrequestfactory_clone"]
+        fn construct(other: &QNetworkRequestFactory) -> QNetworkRequestFactory;
+    }
+}
+
+/// Convenience class for grouping remote server endpoints that share common network request properties.
+///
+/// REST servers often have endpoints that require the same headers and other data. Grouping such endpoints with a `QNetworkRequestFactory` makes it more convenient to issue requests to these endpoints; only the typically varying parts such as *path* and *query parameters* are provided when creating a new request.
+///
+/// Introduced in Qt 6.7.
+///
+/// Qt Documentation: [QNetworkRequestFactory](https://doc.qt.io/qt-6/qnetworkrequestfactory.html#details)
+#[repr(C)]
+pub struct QNetworkRequestFactory {
+    _space: MaybeUninit<usize>,
+}
+
+impl Clone for QNetworkRequestFactory {
+    fn clone(&self) -> Self {
+        ffi::qnetworkrequestfactory_clone(self)
+    }
+}
+
+impl Default for QNetworkRequestFactory {
+    /// Creates a new `QNetworkRequestFactory` object. Use [`set_base_url`](QNetworkRequestFactory::set_base_url) to set a valid base URL for the requests.
+    fn default() -> Self {
+        ffi::qnetworkrequestfactory_init_default()
+    }
+}
+
+impl Drop for QNetworkRequestFactory {
+    fn drop(&mut self) {
+        ffi::qnetworkrequestfactory_drop(self);
+    }
+}
+
+impl fmt::Debug for QNetworkRequestFactory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QNetworkRequestFactory")
+            .field("base_url", &self.base_url())
+            .field("common_headers", &self.common_headers())
+            .field("query_parameters", &self.query_parameters())
+            .field("transfer_timeout", &self.transfer_timeout())
+            .finish_non_exhaustive()
+    }
+}
+
+impl QNetworkRequestFactory {
+    /// Creates a new `QNetworkRequestFactory` object, initializing the base URL to `base_url`. The base URL is used to populate the URL of the requests created by this factory.
+    pub fn new(base_url: &QUrl) -> Self {
+        ffi::qnetworkrequestfactory_init_url(base_url)
+    }
+
+    /// Creates a [`QNetworkRequest`].
+    ///
+    /// The returned request's URL is formed by appending the provided `path` to the [`base_url`](QNetworkRequestFactory::base_url) (which may itself have a path component). The URL's query parameters are a combination of the [`query_parameters`](QNetworkRequestFactory::query_parameters) of this factory and `query`, which is a percent-encoded query string such as `"key1=value1&key2=value2"`.
+    pub fn create_request_with_query(&self, path: &QString, query: &QString) -> QNetworkRequest {
+        ffi::qnetworkrequestfactory_create_request(self, path, query)
+    }
+
+    /// Returns the query parameters set to this factory, as a percent-encoded query string.
+    pub fn query_parameters(&self) -> QString {
+        ffi::qnetworkrequestfactory_query_parameters(self)
+    }
+
+    /// Sets `query` as query parameters that are common to all requests, where `query` is a percent-encoded query string such as `"key1=value1&key2=value2"`.
+    ///
+    /// These parameters are added to any query parameters defined for each individual request.
+    pub fn set_query_parameters(&mut self, query: &QString) {
+        ffi::qnetworkrequestfactory_set_query_parameters(self, query);
+    }
+
+    /// Sets `timeout` used for transfers.
+    ///
+    /// Transfers are aborted if no bytes are transferred before the timeout expires. `None` means no timer is set.
+    pub fn set_transfer_timeout(&mut self, duration: Option<Duration>) {
+        let msecs = match duration {
+            Some(duration) => duration.as_millis().try_into().unwrap_or(i64::MAX),
+            None => 0,
+        };
+        ffi::qnetworkrequestfactory_set_transfer_timeout_msecs(self, msecs);
+    }
+
+    /// Returns the timeout used for transfers.
+    pub fn transfer_timeout(&self) -> Option<Duration> {
+        let transfer_timeout = ffi::qnetworkrequestfactory_transfer_timeout_msecs(self);
+        let msecs = u64::try_from(transfer_timeout).ok()?;
+        if msecs == 0 {
+            None
+        } else {
+            Some(Duration::from_millis(msecs))
+        }
+    }
+}
+
+impl From<&QUrl> for QNetworkRequestFactory {
+    fn from(value: &QUrl) -> Self {
+        Self::new(value)
+    }
+}
+
+// SAFETY: Static checks on the C++ side to ensure the size is the same.
+unsafe impl ExternType for QNetworkRequestFactory {
+    type Id = type_id!("QNetworkRequestFactory");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cfg(test)]
+mod tests {
+    use cxx_qt_lib::QByteArray;
+
+    use super::*;
+
+    #[test]
+    fn create_request() {
+        let mut factory = QNetworkRequestFactory::new(&QUrl::from("https://example.com/api"));
+        factory.set_bearer_token(&QByteArray::from("token"));
+        factory.set_query_parameters(&QString::from("a=1"));
+        factory.set_transfer_timeout(Some(Duration::from_secs(5)));
+
+        let request =
+            factory.create_request_with_query(&QString::from("items"), &QString::from("b=2"));
+
+        assert_eq!(
+            (
+                request.url(),
+                request.raw_header(&QByteArray::from("Authorization")),
+                request.transfer_timeout(),
+            ),
+            (
+                QUrl::from("https://example.com/api/items?a=1&b=2"),
+                QByteArray::from("Bearer token"),
+                Some(Duration::from_secs(5)),
+            )
+        );
+    }
+}
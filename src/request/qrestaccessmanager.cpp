@@ -0,0 +1,14 @@
+#include "cxx-qt-io/qrestaccessmanager.h"
+
+namespace rust {
+namespace cxxqtio1 {
+::std::unique_ptr<QRestAccessManager>
+qrestaccessmanagerNew(::std::unique_ptr<QNetworkAccessManager> manager)
+{
+  auto restManager = ::std::make_unique<QRestAccessManager>(manager.get());
+  manager.release()->setParent(restManager.get());
+  return restManager;
+}
+
+}
+}
@@ -0,0 +1,425 @@
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::QByteArray;
+
+use crate::qobject::debug_qobject;
+use crate::util::{unpin_for_qt, upcast_mut};
+use crate::{QHttpMultiPart, QIODevice, QNetworkAccessManager, QNetworkReply, QNetworkRequest};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+
+        include!("cxx-qt-io/qhttpmultipart.h");
+        type QHttpMultiPart = crate::QHttpMultiPart;
+        include!("cxx-qt-io/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+        include!("cxx-qt-io/qnetworkaccessmanager.h");
+        type QNetworkAccessManager = crate::QNetworkAccessManager;
+        include!("cxx-qt-io/qnetworkreply.h");
+        type QNetworkReply = crate::QNetworkReply;
+        include!("cxx-qt-io/qnetworkrequest.h");
+        type QNetworkRequest = crate::QNetworkRequest;
+    }
+
+    extern "C++" {
+        include!("cxx-qt-io/qrestaccessmanager.h");
+    }
+
+    unsafe extern "C++Qt" {
+        /// The `QRestAccessManager` is a convenience wrapper for [`QNetworkAccessManager`](crate::QNetworkAccessManager).
+        ///
+        /// `QRestAccessManager` is a convenience wrapper on top of `QNetworkAccessManager`. It amends datatypes and HTTP methods that are useful for typical RESTful client applications. Responses can be processed with [`QRestReply`](crate::QRestReply).
+        ///
+        /// Introduced in Qt 6.7.
+        ///
+        /// Qt Documentation: [QRestAccessManager](https://doc.qt.io/qt-6/qrestaccessmanager.html#details)
+        #[qobject]
+        #[base = QObject]
+        type QRestAccessManager;
+
+        #[doc(hidden)]
+        #[rust_name = "delete_raw"]
+        fn deleteResource(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "get_raw"]
+        fn get(self: Pin<&mut QRestAccessManager>, request: &QNetworkRequest)
+        -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "get_data_raw"]
+        fn get(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            data: &QByteArray,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "head_raw"]
+        fn head(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "network_access_manager_raw"]
+        fn networkAccessManager(self: &QRestAccessManager) -> *mut QNetworkAccessManager;
+
+        #[doc(hidden)]
+        #[rust_name = "patch_data_raw"]
+        fn patch(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            data: &QByteArray,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `data` must be opened for reading when this function is called and must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "patch_device_raw"]
+        unsafe fn patch(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            data: *mut QIODevice,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "post_data_raw"]
+        fn post(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            data: &QByteArray,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `data` must be opened for reading when this function is called and must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "post_device_raw"]
+        unsafe fn post(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            data: *mut QIODevice,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `multi_part` must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "post_http_raw"]
+        unsafe fn post(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            multi_part: *mut QHttpMultiPart,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "put_data_raw"]
+        fn put(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            data: &QByteArray,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `data` must be opened for reading when this function is called and must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "put_device_raw"]
+        unsafe fn put(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            data: *mut QIODevice,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `multi_part` must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "put_http_raw"]
+        unsafe fn put(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            multi_part: *mut QHttpMultiPart,
+        ) -> *mut QNetworkReply;
+
+        #[doc(hidden)]
+        #[rust_name = "send_custom_request_data_raw"]
+        fn sendCustomRequest(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            method: &QByteArray,
+            data: &QByteArray,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `data` must be opened for reading when this function is called and must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "send_custom_request_device_raw"]
+        unsafe fn sendCustomRequest(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            method: &QByteArray,
+            data: *mut QIODevice,
+        ) -> *mut QNetworkReply;
+
+        /// # Safety
+        ///
+        /// `multi_part` must remain valid until the `finished` signal is emitted for this reply.
+        #[doc(hidden)]
+        #[rust_name = "send_custom_request_http_raw"]
+        unsafe fn sendCustomRequest(
+            self: Pin<&mut QRestAccessManager>,
+            request: &QNetworkRequest,
+            method: &QByteArray,
+            multi_part: *mut QHttpMultiPart,
+        ) -> *mut QNetworkReply;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qrestaccessmanager_new"]
+        fn qrestaccessmanagerNew(
+            manager: UniquePtr<QNetworkAccessManager>,
+        ) -> UniquePtr<QRestAccessManager>;
+    }
+}
+
+pub use ffi::QRestAccessManager;
+
+impl fmt::Debug for QRestAccessManager {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QRestAccessManager {
+    /// Constructs a `QRestAccessManager` that dispatches requests through `manager`. The `QRestAccessManager` takes ownership of `manager`.
+    ///
+    /// `manager` must live in the current thread.
+    pub fn new(manager: UniquePtr<QNetworkAccessManager>) -> UniquePtr<Self> {
+        ffi::qrestaccessmanager_new(manager)
+    }
+
+    /// Issues an HTTP DELETE based on `request`.
+    pub fn delete<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.delete_raw(request);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP GET based on `request`.
+    pub fn get<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.get_raw(request);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP GET based on `request` and provided `data`.
+    pub fn get_data<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        data: &QByteArray,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.get_data_raw(request, data);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP HEAD based on `request`.
+    pub fn head<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.head_raw(request);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Returns the underlying `QNetworkAccessManager` instance.
+    pub fn network_access_manager(self: Pin<&mut Self>) -> Pin<&mut QNetworkAccessManager> {
+        // SAFETY: The network access manager is owned by this object and is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *self.network_access_manager_raw()) }
+    }
+
+    /// Issues an HTTP PATCH based on `request`. The contents of the `data` device will be uploaded to the server.
+    ///
+    /// `data` must be open for reading.
+    pub fn patch<'a, T>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        data: Pin<&'a mut T>,
+    ) -> Pin<&'a mut QNetworkReply>
+    where
+        T: Upcast<QIODevice>,
+    {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `data`.
+        let reply = unsafe { self.patch_device_raw(request, upcast_mut(unpin_for_qt(data))) };
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP PATCH based on `request` and provided `data`.
+    pub fn patch_data<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        data: &QByteArray,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.patch_data_raw(request, data);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP POST based on `request`. The contents of the `data` device will be uploaded to the server.
+    ///
+    /// `data` must be open for reading.
+    pub fn post<'a, T>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        data: Pin<&'a mut T>,
+    ) -> Pin<&'a mut QNetworkReply>
+    where
+        T: Upcast<QIODevice>,
+    {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `data`.
+        let reply = unsafe { self.post_device_raw(request, upcast_mut(unpin_for_qt(data))) };
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP POST based on `request` and provided `data`.
+    pub fn post_data<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        data: &QByteArray,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.post_data_raw(request, data);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP POST based on `request`. The contents of the `multi_part` message will be sent to the server.
+    pub fn post_http<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        multi_part: Pin<&'a mut QHttpMultiPart>,
+    ) -> Pin<&'a mut QNetworkReply> {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `multi_part`.
+        let reply = unsafe { self.post_http_raw(request, unpin_for_qt(multi_part)) };
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP PUT based on `request`. The contents of the `data` device will be uploaded to the server.
+    ///
+    /// `data` must be open for reading.
+    pub fn put<'a, T>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        data: Pin<&'a mut T>,
+    ) -> Pin<&'a mut QNetworkReply>
+    where
+        T: Upcast<QIODevice>,
+    {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `data`.
+        let reply = unsafe { self.put_device_raw(request, upcast_mut(unpin_for_qt(data))) };
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP PUT based on `request` and provided `data`.
+    pub fn put_data<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        data: &QByteArray,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.put_data_raw(request, data);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues an HTTP PUT based on `request`. The contents of the `multi_part` message will be sent to the server.
+    pub fn put_http<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        multi_part: Pin<&'a mut QHttpMultiPart>,
+    ) -> Pin<&'a mut QNetworkReply> {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `multi_part`.
+        let reply = unsafe { self.put_http_raw(request, unpin_for_qt(multi_part)) };
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues `request` based HTTP request with custom `method`. The contents of the `data` device will be uploaded to the server.
+    ///
+    /// `data` must be open for reading.
+    pub fn send_custom_request<'a, T>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        method: &QByteArray,
+        data: Pin<&'a mut T>,
+    ) -> Pin<&'a mut QNetworkReply>
+    where
+        T: Upcast<QIODevice>,
+    {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `data`.
+        let reply = unsafe {
+            self.send_custom_request_device_raw(request, method, upcast_mut(unpin_for_qt(data)))
+        };
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues `request` based HTTP request with custom `method` and provided `data`.
+    pub fn send_custom_request_data<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        method: &QByteArray,
+        data: &QByteArray,
+    ) -> Pin<&'a mut QNetworkReply> {
+        let reply = self.send_custom_request_data_raw(request, method, data);
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+
+    /// Issues `request` based HTTP request with custom `method`. The contents of the `multi_part` message will be sent to the server.
+    pub fn send_custom_request_http<'a>(
+        self: Pin<&'a mut Self>,
+        request: &QNetworkRequest,
+        method: &QByteArray,
+        multi_part: Pin<&'a mut QHttpMultiPart>,
+    ) -> Pin<&'a mut QNetworkReply> {
+        // SAFETY: The lifetime parameter prevents concurrent modification of `multi_part`.
+        let reply =
+            unsafe { self.send_custom_request_http_raw(request, method, unpin_for_qt(multi_part)) };
+        // SAFETY: The network access manager is the parent of the reply, which is valid and non-null.
+        unsafe { Pin::new_unchecked(&mut *reply) }
+    }
+}
+
+impl Deref for QRestAccessManager {
+    type Target = QObject;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
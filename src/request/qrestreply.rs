@@ -0,0 +1,129 @@
+use std::fmt;
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+
+use crate::QNetworkReply;
+use crate::util::unpin_for_qt;
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+
+        include!("cxx-qt-io/qnetworkreply.h");
+        type QNetworkReply = crate::QNetworkReply;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        type QNetworkReplyNetworkError = crate::QNetworkReplyNetworkError;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qrestreply.h");
+        /// `QRestReply` is a convenience wrapper for [`QNetworkReply`](crate::QNetworkReply).
+        ///
+        /// `QRestReply` wraps a `QNetworkReply` and provides convenience methods for data and status handling. The methods provide convenience for typical RESTful client applications.
+        ///
+        /// `QRestReply` doesn't take ownership of the wrapped `QNetworkReply`, and the lifetime and ownership of the reply is as defined by [`QNetworkAccessManager`](crate::QNetworkAccessManager) documentation.
+        ///
+        /// Introduced in Qt 6.7.
+        ///
+        /// Qt Documentation: [QRestReply](https://doc.qt.io/qt-6/qrestreply.html#details)
+        type QRestReply;
+
+        /// Returns the last error, if any. The errors include errors such as network and protocol errors, but exclude cases when the server successfully responded with an HTTP error status (for example `500 Internal Server Error`). Use [`http_status`](QRestReply::http_status) or [`is_http_status_success`](QRestReply::is_http_status_success) to get the HTTP status information.
+        fn error(self: &QRestReply) -> QNetworkReplyNetworkError;
+
+        /// Returns a human-readable description of the last network error.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QRestReply) -> QString;
+
+        /// Returns whether an error has occurred. This includes errors such as network and protocol errors, but excludes cases where the server successfully responded with an HTTP error status (for example `500 Internal Server Error`). Use [`http_status`](QRestReply::http_status) or [`is_http_status_success`](QRestReply::is_http_status_success) to get the HTTP status information.
+        #[rust_name = "has_error"]
+        fn hasError(self: &QRestReply) -> bool;
+
+        /// Returns the HTTP status received in the server response. The value is 0 if not available (the status line has not been received, yet).
+        ///
+        /// **Note:** The HTTP status is reported as indicated by the received HTTP response. An error may occur after receiving the status, for instance due to network disconnection while receiving a long response. These potential subsequent errors are not represented by the reported HTTP status.
+        #[rust_name = "http_status"]
+        fn httpStatus(self: &QRestReply) -> i32;
+
+        /// Returns whether the HTTP status is between 200..299.
+        #[rust_name = "is_http_status_success"]
+        fn isHttpStatusSuccess(self: &QRestReply) -> bool;
+
+        /// Returns whether the HTTP status is between 200..299 and no further errors have occurred while receiving the response (for example, abrupt disconnection while receiving the body data). This function is a convenient way to check whether the response is considered successful.
+        #[rust_name = "is_success"]
+        fn isSuccess(self: &QRestReply) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "network_reply_raw"]
+        fn networkReply(self: &QRestReply) -> *mut QNetworkReply;
+
+        /// Returns the received data as a `QByteArray`.
+        ///
+        /// Calling this function consumes the data received so far, and any further calls to get response data will return empty until further data has been received.
+        #[rust_name = "read_body"]
+        fn readBody(self: Pin<&mut QRestReply>) -> QByteArray;
+
+        /// Returns the received data as a `QString`.
+        ///
+        /// The received data is decoded into a `QString` (UTF-16). If available, the decoding uses the `Content-Type` header's charset parameter to determine the source encoding. If the encoding information is not available or not supported by `QStringConverter`, UTF-8 is used by default.
+        ///
+        /// Calling this function consumes the data received so far. Returns a default constructed value if no new data is available, or if the decoding is not supported by `QStringConverter`, or if the decoding has errors (for example invalid characters).
+        #[rust_name = "read_text"]
+        fn readText(self: Pin<&mut QRestReply>) -> QString;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qrestreply_new"]
+        unsafe fn make_unique(reply: *mut QNetworkReply) -> UniquePtr<QRestReply>;
+    }
+}
+
+pub use ffi::QRestReply;
+
+impl fmt::Debug for QRestReply {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QRestReply")
+            .field("http_status", &self.http_status())
+            .field("error", &self.error())
+            .finish()
+    }
+}
+
+impl QRestReply {
+    /// Creates a `QRestReply` and initializes the wrapped [`QNetworkReply`] to `reply`.
+    pub fn new(reply: Pin<&mut QNetworkReply>) -> UniquePtr<Self> {
+        // SAFETY: QRestReply tracks the reply with a QPointer, so it is safe if the reply is deleted.
+        unsafe { ffi::qrestreply_new(unpin_for_qt(reply)) }
+    }
+
+    /// Returns a pointer to the underlying `QNetworkReply` wrapped by this object, or `None` if the reply has been deleted.
+    pub fn network_reply(self: Pin<&mut Self>) -> Option<Pin<&mut QNetworkReply>> {
+        let reply = self.network_reply_raw();
+        if reply.is_null() {
+            return None;
+        }
+        // SAFETY: `reply` is valid and non-null.
+        Some(unsafe { Pin::new_unchecked(&mut *reply) })
+    }
+
+    /// Deserializes the received data as JSON.
+    ///
+    /// Calling this function consumes the data received so far. See [`read_body`](QRestReply::read_body).
+    #[cfg(feature = "json")]
+    pub fn read_json<T: serde::de::DeserializeOwned>(
+        self: Pin<&mut Self>,
+    ) -> serde_json::Result<T> {
+        serde_json::from_slice(self.read_body().as_slice())
+    }
+}
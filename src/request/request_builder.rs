@@ -0,0 +1,356 @@
+use std::fmt::{self, Write as _};
+use std::pin::Pin;
+use std::time::Duration;
+
+use cxx_qt_lib::{QByteArray, QFlags, QString, QUrl};
+
+use crate::{QNetworkAccessManager, QNetworkReply, QNetworkRequest};
+
+/// A builder for a [`QNetworkRequest`] that is dispatched on a [`QNetworkAccessManager`].
+///
+/// Created by [`QNetworkAccessManager::request`].
+///
+/// # Examples
+///
+/// ```ignore
+/// let reply = manager
+///     .request("POST", &QUrl::from("https://example.com/api/items"))
+///     .query([("page", "1")])
+///     .bearer_auth("token")
+///     .form([("name", "value")])
+///     .timeout(Duration::from_secs(30))
+///     .send();
+/// ```
+#[must_use = "a RequestBuilder does nothing until it is sent"]
+pub struct RequestBuilder<'a> {
+    manager: Pin<&'a mut QNetworkAccessManager>,
+    verb: Vec<u8>,
+    url: QUrl,
+    query: String,
+    request: QNetworkRequest,
+    body: Option<Vec<u8>>,
+}
+
+impl fmt::Debug for RequestBuilder<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("RequestBuilder")
+            .field("verb", &String::from_utf8_lossy(&self.verb))
+            .field("url", &self.url)
+            .field("query", &self.query)
+            .field("body", &self.body.as_ref().map(Vec::len))
+            .finish_non_exhaustive()
+    }
+}
+
+impl<'a> RequestBuilder<'a> {
+    pub(crate) fn new(
+        manager: Pin<&'a mut QNetworkAccessManager>,
+        verb: &[u8],
+        url: &QUrl,
+    ) -> Self {
+        Self {
+            manager,
+            verb: verb.to_vec(),
+            url: url.clone(),
+            query: String::new(),
+            request: QNetworkRequest::default(),
+            body: None,
+        }
+    }
+
+    /// Enables HTTP basic authentication with `username` and, optionally, `password`.
+    pub fn basic_auth(self, username: &str, password: Option<&str>) -> Self {
+        let credentials = match password {
+            Some(password) => format!("{username}:{password}"),
+            None => format!("{username}:"),
+        };
+        let encoded = QByteArray::from(credentials.as_str()).to_base64(QFlags::default());
+        let mut value = b"Basic ".to_vec();
+        value.extend_from_slice(encoded.as_slice());
+        self.header("Authorization", value)
+    }
+
+    /// Enables HTTP bearer authentication with `token`.
+    pub fn bearer_auth(self, token: &str) -> Self {
+        self.header("Authorization", format!("Bearer {token}"))
+    }
+
+    /// Sets the request body to `body`.
+    pub fn body<T: Into<Vec<u8>>>(mut self, body: T) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// Sets the `Content-Type` header to `content_type`.
+    pub fn content_type<T: AsRef<[u8]>>(self, content_type: T) -> Self {
+        self.header("Content-Type", content_type)
+    }
+
+    /// Sets the request body to `pairs`, encoded as `application/x-www-form-urlencoded`, and sets the `Content-Type` header accordingly.
+    pub fn form<K, V, I>(self, pairs: I) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut body = String::new();
+        append_form_pairs(&mut body, pairs);
+        self.content_type("application/x-www-form-urlencoded")
+            .body(body)
+    }
+
+    /// Sets a raw header named `name` to `value`, replacing any previous value.
+    pub fn header<N: AsRef<[u8]>, V: AsRef<[u8]>>(mut self, name: N, value: V) -> Self {
+        self.request.set_raw_header(
+            &QByteArray::from(name.as_ref()),
+            &QByteArray::from(value.as_ref()),
+        );
+        self
+    }
+
+    /// Serializes `json` as the request body and sets the `Content-Type` header to `application/json`.
+    ///
+    /// Returns an error if `json` cannot be serialized.
+    #[cfg(feature = "json")]
+    pub fn json<T: serde::Serialize + ?Sized>(self, json: &T) -> serde_json::Result<Self> {
+        let body = serde_json::to_vec(json)?;
+        Ok(self.content_type("application/json").body(body))
+    }
+
+    /// Appends `pairs` to the query of the request URL, encoded as `application/x-www-form-urlencoded`.
+    pub fn query<K, V, I>(mut self, pairs: I) -> Self
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        append_form_pairs(&mut self.query, pairs);
+        self
+    }
+
+    /// Modifies the underlying `QNetworkRequest` with `f`. This can be used to set attributes and other options that the builder does not provide.
+    pub fn with_request<F: FnOnce(&mut QNetworkRequest)>(mut self, f: F) -> Self {
+        f(&mut self.request);
+        self
+    }
+
+    /// Sets `timeout` as the transfer timeout. Transfers are aborted if no bytes are transferred before the timeout expires.
+    ///
+    /// See [`QNetworkRequest::set_transfer_timeout`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.request.set_transfer_timeout(Some(timeout));
+        self
+    }
+
+    /// Builds the request, returning the `QNetworkRequest` and the request body.
+    pub fn build(mut self) -> (QNetworkRequest, Option<Vec<u8>>) {
+        self.finish_url();
+        (self.request, self.body)
+    }
+
+    /// Builds the request and dispatches it on the `QNetworkAccessManager`, returning a new `QNetworkReply` for it.
+    ///
+    /// `GET`, `HEAD`, `DELETE`, `POST`, and `PUT` requests are dispatched through their dedicated `QNetworkAccessManager` functions. Other verbs, including lowercase spellings of those methods, are sent unchanged with [`QNetworkAccessManager::send_custom_request`].
+    pub fn send(mut self) -> Pin<&'a mut QNetworkReply> {
+        self.finish_url();
+        let Self {
+            manager,
+            verb,
+            request,
+            body,
+            ..
+        } = self;
+        match (verb.as_slice(), body) {
+            (b"GET", None) => manager.get(&request),
+            (b"HEAD", None) => manager.head(&request),
+            (b"DELETE", None) => {
+                let reply = manager.delete(&request);
+                // SAFETY: This object is the parent of the reply, which is valid and non-null.
+                unsafe { Pin::new_unchecked(&mut *reply) }
+            }
+            (b"POST", Some(body)) => manager.post_data(&request, &QByteArray::from(&body[..])),
+            (b"PUT", Some(body)) => manager.put_data(&request, &QByteArray::from(&body[..])),
+            (verb, None) => manager.send_custom_request(&request, &QByteArray::from(verb)),
+            (verb, Some(body)) => manager.send_custom_request_data(
+                &request,
+                &QByteArray::from(verb),
+                &QByteArray::from(&body[..]),
+            ),
+        }
+    }
+
+    fn finish_url(&mut self) {
+        if !self.query.is_empty() {
+            let mut query = self.url.query().to_string();
+            if !query.is_empty() {
+                query.push('&');
+            }
+            query.push_str(&self.query);
+            self.url.set_query(&QString::from(&query));
+        }
+        self.request.set_url(&self.url);
+    }
+}
+
+fn append_form_pairs<K, V, I>(target: &mut String, pairs: I)
+where
+    K: AsRef<str>,
+    V: AsRef<str>,
+    I: IntoIterator<Item = (K, V)>,
+{
+    for (key, value) in pairs {
+        if !target.is_empty() {
+            target.push('&');
+        }
+        append_form_encoded(target, key.as_ref());
+        target.push('=');
+        append_form_encoded(target, value.as_ref());
+    }
+}
+
+/// Encodes `input` according to the `application/x-www-form-urlencoded` serializer in the WHATWG URL Standard.
+fn append_form_encoded(target: &mut String, input: &str) {
+    for &byte in input.as_bytes() {
+        match byte {
+            b'*' | b'-' | b'.' | b'_' | b'0'..=b'9' | b'A'..=b'Z' | b'a'..=b'z' => {
+                target.push(char::from(byte));
+            }
+            b' ' => target.push('+'),
+            _ => {
+                let _ = write!(target, "%{byte:02X}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    use super::*;
+    use crate::{
+        QNetworkAccessManagerOperation, QNetworkRequestAttribute, RustNetworkAccessManager,
+    };
+
+    fn build_url(url: &str, pairs: &[(&str, &str)]) -> String {
+        let mut manager = QNetworkAccessManager::new();
+        let (request, body) = manager
+            .pin_mut()
+            .request("GET", &QUrl::from(url))
+            .query(pairs.iter().copied())
+            .build();
+        assert_eq!(body, None);
+        request.url().to_string()
+    }
+
+    fn authorization(builder: RequestBuilder<'_>) -> QByteArray {
+        let (request, _) = builder.build();
+        request.raw_header(&QByteArray::from("Authorization"))
+    }
+
+    #[test]
+    fn build() {
+        let mut manager = QNetworkAccessManager::new();
+        let (request, body) = manager
+            .pin_mut()
+            .request("POST", &QUrl::from("https://example.com/items"))
+            .header("X-Custom", "value")
+            .form([("name", "a b")])
+            .build();
+        assert_eq!(
+            (
+                request.url().to_string(),
+                request.raw_header(&QByteArray::from("X-Custom")),
+                request.raw_header(&QByteArray::from("Content-Type")),
+                body,
+            ),
+            (
+                "https://example.com/items".to_owned(),
+                QByteArray::from("value"),
+                QByteArray::from("application/x-www-form-urlencoded"),
+                Some(b"name=a+b".to_vec()),
+            )
+        );
+    }
+
+    #[test]
+    fn finish_url_merges_query() {
+        assert_eq!(
+            (
+                build_url("https://example.com/path", &[]),
+                build_url("https://example.com/path", &[("b", "2 3")]),
+                build_url("https://example.com/path?a=1", &[("b", "2"), ("c", "&")]),
+            ),
+            (
+                "https://example.com/path".to_owned(),
+                "https://example.com/path?b=2+3".to_owned(),
+                "https://example.com/path?a=1&b=2&c=%26".to_owned(),
+            )
+        );
+    }
+
+    #[test]
+    fn auth_headers() {
+        let mut manager = QNetworkAccessManager::new();
+        let url = QUrl::from("https://example.com");
+        assert_eq!(
+            (
+                authorization(
+                    manager
+                        .pin_mut()
+                        .request("GET", &url)
+                        .basic_auth("user", Some("pass"))
+                ),
+                authorization(
+                    manager
+                        .pin_mut()
+                        .request("GET", &url)
+                        .basic_auth("user", None)
+                ),
+                authorization(manager.pin_mut().request("GET", &url).bearer_auth("token")),
+            ),
+            (
+                QByteArray::from("Basic dXNlcjpwYXNz"),
+                QByteArray::from("Basic dXNlcjo="),
+                QByteArray::from("Bearer token"),
+            )
+        );
+    }
+
+    #[test]
+    fn custom_verb_unchanged() {
+        let sent = Rc::new(RefCell::new(None));
+        let handler_sent = Rc::clone(&sent);
+        let mut manager_ptr = RustNetworkAccessManager::new(
+            move |operation: QNetworkAccessManagerOperation,
+                  request: &QNetworkRequest,
+                  _: &[u8]| {
+                let verb = request
+                    .attribute(QNetworkRequestAttribute::CustomVerbAttribute)
+                    .and_then(|verb| verb.value::<QByteArray>());
+                *handler_sent.borrow_mut() = Some((operation, verb));
+                Some(MockResponse::new())
+            },
+        );
+        let mut manager = manager_ptr.pin_mut();
+        manager
+            .as_network_access_manager_mut()
+            .request("purge", &QUrl::from("https://example.com"))
+            .send();
+        assert_eq!(
+            sent.borrow_mut().take(),
+            Some((
+                QNetworkAccessManagerOperation::CustomOperation,
+                Some(QByteArray::from("purge")),
+            ))
+        );
+    }
+
+    #[test]
+    fn form_encoding() {
+        let mut encoded = String::new();
+        append_form_pairs(&mut encoded, [("a b", "c&d"), ("é", "1+1=2")]);
+        assert_eq!(encoded, "a+b=c%26d&%C3%A9=1%2B1%3D2");
+    }
+}
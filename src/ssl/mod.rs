@@ -1,5 +1,5 @@
 mod qcryptographichash;
-pub use qcryptographichash::QCryptographicHashAlgorithm;
+pub use qcryptographichash::{QCryptographicHash, QCryptographicHashAlgorithm};
 
 mod qdtls;
 pub use qdtls::{QDtls, QDtlsError, QDtlsHandshakeState};
@@ -10,9 +10,15 @@ pub use qdtlsclientverifier::QDtlsClientVerifier;
 mod qdtlsgeneratorparameters;
 pub use qdtlsgeneratorparameters::QDtlsGeneratorParameters;
 
+mod qmessageauthenticationcode;
+pub use qmessageauthenticationcode::QMessageAuthenticationCode;
+
 mod qocspresponse;
 pub use qocspresponse::{QOcspCertificateStatus, QOcspResponse, QOcspRevocationReason};
 
+mod qpassworddigestor;
+pub use qpassworddigestor::QPasswordDigestor;
+
 mod qssl;
 pub use qssl::{QSslAlertLevel, QSslAlertType};
 pub use qssl::{
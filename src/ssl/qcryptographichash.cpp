@@ -0,0 +1,32 @@
+#include "cxx-qt-io/qcryptographichash.h"
+
+namespace rust {
+namespace cxxqtio1 {
+void
+qcryptographichashAddData(QCryptographicHash& hash,
+                          ::rust::Slice<const ::std::uint8_t> data)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  hash.addData(QByteArrayView(data.data(), data.size()));
+#else
+  hash.addData(reinterpret_cast<const char*>(data.data()), data.size());
+#endif
+}
+
+QByteArray
+qcryptographichashHash(::rust::Slice<const ::std::uint8_t> data,
+                       QCryptographicHashAlgorithm method)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  return QCryptographicHash::hash(QByteArrayView(data.data(), data.size()),
+                                  method);
+#else
+  return QCryptographicHash::hash(
+    QByteArray::fromRawData(reinterpret_cast<const char*>(data.data()),
+                            data.size()),
+    method);
+#endif
+}
+
+}
+}
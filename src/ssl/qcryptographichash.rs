@@ -1,4 +1,13 @@
 use std::fmt;
+use std::io::{self, Write};
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::QByteArray;
+
+use crate::QIODevice;
+use crate::util::{unpin_for_qt, upcast_mut};
 
 #[cxx::bridge]
 mod ffi {
@@ -31,14 +40,138 @@ mod ffi {
         Blake2s_256,
     }
 
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+
+        include!("cxx-qt-io/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+    }
+
     #[namespace = "rust::cxxqtio1"]
     extern "C++" {
         include!("cxx-qt-io/qcryptographichash.h");
         type QCryptographicHashAlgorithm;
     }
+
+    unsafe extern "C++" {
+        /// The `QCryptographicHash` class provides a way to generate cryptographic hashes.
+        ///
+        /// `QCryptographicHash` can be used to generate cryptographic hashes of binary or text data. Data can be added incrementally with [`add_data`](QCryptographicHash::add_data), [`add_data_device`](QCryptographicHash::add_data_device), or through the [`Write`](std::io::Write) implementation, or hashed in one go with [`QCryptographicHash::hash`].
+        ///
+        /// Qt Documentation: [QCryptographicHash](https://doc.qt.io/qt-6/qcryptographichash.html#details)
+        type QCryptographicHash;
+
+        /// # Safety
+        ///
+        /// `device` must be valid.
+        #[doc(hidden)]
+        #[rust_name = "add_data_device_raw"]
+        unsafe fn addData(self: Pin<&mut QCryptographicHash>, device: *mut QIODevice) -> bool;
+
+        /// Returns the algorithm used to generate the cryptographic hash.
+        ///
+        /// Introduced in Qt 6.5.
+        #[cfg(cxxqt_qt_version_at_least_6_5)]
+        fn algorithm(self: &QCryptographicHash) -> QCryptographicHashAlgorithm;
+
+        /// Resets the object.
+        fn reset(self: Pin<&mut QCryptographicHash>);
+
+        /// Returns the final hash value.
+        fn result(self: &QCryptographicHash) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qcryptographichash_add_data"]
+        fn qcryptographichashAddData(hash: Pin<&mut QCryptographicHash>, data: &[u8]);
+
+        #[rust_name = "qcryptographichash_hash"]
+        fn qcryptographichashHash(data: &[u8], method: QCryptographicHashAlgorithm) -> QByteArray;
+
+        #[rust_name = "qcryptographichash_hash_length"]
+        fn qcryptographichashHashLength(method: QCryptographicHashAlgorithm) -> i32;
+
+        #[cfg(cxxqt_qt_version_at_least_6_5)]
+        #[rust_name = "qcryptographichash_supports_algorithm"]
+        fn qcryptographichashSupportsAlgorithm(method: QCryptographicHashAlgorithm) -> bool;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qcryptographichash_new"]
+        fn make_unique(method: QCryptographicHashAlgorithm) -> UniquePtr<QCryptographicHash>;
+    }
 }
 
-pub use ffi::QCryptographicHashAlgorithm;
+pub use ffi::{QCryptographicHash, QCryptographicHashAlgorithm};
+
+impl fmt::Debug for QCryptographicHash {
+    #[cfg(cxxqt_qt_version_at_least_6_5)]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QCryptographicHash")
+            .field("algorithm", &self.algorithm())
+            .finish_non_exhaustive()
+    }
+
+    #[cfg(not(cxxqt_qt_version_at_least_6_5))]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QCryptographicHash").finish_non_exhaustive()
+    }
+}
+
+impl QCryptographicHash {
+    /// Constructs an object that can be used to create a cryptographic hash from data using `method`.
+    pub fn new(method: QCryptographicHashAlgorithm) -> UniquePtr<Self> {
+        ffi::qcryptographichash_new(method)
+    }
+
+    /// Adds `data` to the cryptographic hash.
+    pub fn add_data(self: Pin<&mut Self>, data: &[u8]) {
+        ffi::qcryptographichash_add_data(self, data);
+    }
+
+    /// Reads the data from the open `device` until it ends and hashes it. Returns `true` if reading was successful.
+    pub fn add_data_device<T>(self: Pin<&mut Self>, device: Pin<&mut T>) -> bool
+    where
+        T: Upcast<QIODevice>,
+    {
+        // SAFETY: `device` is valid for the duration of the call.
+        unsafe { self.add_data_device_raw(upcast_mut(unpin_for_qt(device))) }
+    }
+
+    /// Returns the hash of `data` using `method`.
+    pub fn hash(data: &[u8], method: QCryptographicHashAlgorithm) -> QByteArray {
+        ffi::qcryptographichash_hash(data, method)
+    }
+
+    /// Returns the size of the output of the selected hash `method` in bytes. If the algorithm is unsupported, returns 0.
+    pub fn hash_length(method: QCryptographicHashAlgorithm) -> i32 {
+        ffi::qcryptographichash_hash_length(method)
+    }
+
+    /// Returns whether the selected algorithm `method` is supported and if [`result`](QCryptographicHash::result) will return a value when the `method` is used.
+    ///
+    /// Introduced in Qt 6.5.
+    #[cfg(cxxqt_qt_version_at_least_6_5)]
+    pub fn supports_algorithm(method: QCryptographicHashAlgorithm) -> bool {
+        ffi::qcryptographichash_supports_algorithm(method)
+    }
+}
+
+impl Write for Pin<&mut QCryptographicHash> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_mut().add_data(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
 
 impl fmt::Display for QCryptographicHashAlgorithm {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -70,3 +203,28 @@ impl fmt::Display for QCryptographicHashAlgorithm {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SHA256_ABC: &[u8] = &[
+        0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae, 0x22,
+        0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61, 0xf2, 0x00,
+        0x15, 0xad,
+    ];
+
+    #[test]
+    fn hash() {
+        let hash = QCryptographicHash::hash(b"abc", QCryptographicHashAlgorithm::Sha256);
+        assert_eq!(hash.as_slice(), SHA256_ABC);
+    }
+
+    #[test]
+    fn write() {
+        let mut hash = QCryptographicHash::new(QCryptographicHashAlgorithm::Sha256);
+        hash.pin_mut().write_all(b"a").unwrap();
+        hash.pin_mut().write_all(b"bc").unwrap();
+        assert_eq!(hash.result().as_slice(), SHA256_ABC);
+    }
+}
@@ -0,0 +1,41 @@
+#include "cxx-qt-io/qmessageauthenticationcode.h"
+
+namespace {
+QByteArray
+toByteArray(::rust::Slice<const ::std::uint8_t> data)
+{
+  return QByteArray(reinterpret_cast<const char*>(data.data()), data.size());
+}
+}
+
+namespace rust {
+namespace cxxqtio1 {
+void
+qmessageauthenticationcodeAddData(QMessageAuthenticationCode& code,
+                                  ::rust::Slice<const ::std::uint8_t> data)
+{
+#if (QT_VERSION >= QT_VERSION_CHECK(6, 3, 0))
+  code.addData(QByteArrayView(data.data(), data.size()));
+#else
+  code.addData(reinterpret_cast<const char*>(data.data()), data.size());
+#endif
+}
+
+QByteArray
+qmessageauthenticationcodeHash(::rust::Slice<const ::std::uint8_t> message,
+                               ::rust::Slice<const ::std::uint8_t> key,
+                               QCryptographicHash::Algorithm method)
+{
+  return QMessageAuthenticationCode::hash(
+    toByteArray(message), toByteArray(key), method);
+}
+
+void
+qmessageauthenticationcodeSetKey(QMessageAuthenticationCode& code,
+                                 ::rust::Slice<const ::std::uint8_t> key)
+{
+  code.setKey(toByteArray(key));
+}
+
+}
+}
@@ -0,0 +1,169 @@
+use std::fmt;
+use std::io::{self, Write};
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::QByteArray;
+
+use crate::util::{unpin_for_qt, upcast_mut};
+use crate::{QCryptographicHashAlgorithm, QIODevice};
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+
+        include!("cxx-qt-io/qiodevice.h");
+        type QIODevice = crate::QIODevice;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qcryptographichash.h");
+        type QCryptographicHashAlgorithm = crate::QCryptographicHashAlgorithm;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qmessageauthenticationcode.h");
+        /// The `QMessageAuthenticationCode` class provides a way to generate hash-based message authentication codes.
+        ///
+        /// `QMessageAuthenticationCode` supports all cryptographic hashes which are supported by [`QCryptographicHash`](crate::QCryptographicHash).
+        ///
+        /// To generate a message authentication code, pass a hash algorithm and a key to [`QMessageAuthenticationCode::new`]. Then add data with [`add_data`](QMessageAuthenticationCode::add_data), [`add_data_device`](QMessageAuthenticationCode::add_data_device), or through the [`Write`](std::io::Write) implementation, and call [`result`](QMessageAuthenticationCode::result). Alternatively, [`QMessageAuthenticationCode::hash`] generates a message authentication code in one go.
+        ///
+        /// Qt Documentation: [QMessageAuthenticationCode](https://doc.qt.io/qt-6/qmessageauthenticationcode.html#details)
+        type QMessageAuthenticationCode;
+
+        /// # Safety
+        ///
+        /// `device` must be valid.
+        #[doc(hidden)]
+        #[rust_name = "add_data_device_raw"]
+        unsafe fn addData(
+            self: Pin<&mut QMessageAuthenticationCode>,
+            device: *mut QIODevice,
+        ) -> bool;
+
+        /// Resets message data. Calling this function doesn't affect the key.
+        fn reset(self: Pin<&mut QMessageAuthenticationCode>);
+
+        /// Returns the final authentication code.
+        fn result(self: &QMessageAuthenticationCode) -> QByteArray;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        #[rust_name = "qmessageauthenticationcode_add_data"]
+        fn qmessageauthenticationcodeAddData(
+            code: Pin<&mut QMessageAuthenticationCode>,
+            data: &[u8],
+        );
+
+        #[rust_name = "qmessageauthenticationcode_hash"]
+        fn qmessageauthenticationcodeHash(
+            message: &[u8],
+            key: &[u8],
+            method: QCryptographicHashAlgorithm,
+        ) -> QByteArray;
+
+        #[rust_name = "qmessageauthenticationcode_set_key"]
+        fn qmessageauthenticationcodeSetKey(code: Pin<&mut QMessageAuthenticationCode>, key: &[u8]);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qmessageauthenticationcode_new"]
+        fn make_unique(
+            method: QCryptographicHashAlgorithm,
+            key: &QByteArray,
+        ) -> UniquePtr<QMessageAuthenticationCode>;
+    }
+}
+
+pub use ffi::QMessageAuthenticationCode;
+
+impl fmt::Debug for QMessageAuthenticationCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("QMessageAuthenticationCode")
+            .finish_non_exhaustive()
+    }
+}
+
+impl QMessageAuthenticationCode {
+    /// Constructs an object that can be used to create a cryptographic hash from data using `method` and `key`.
+    pub fn new(method: QCryptographicHashAlgorithm, key: &[u8]) -> UniquePtr<Self> {
+        ffi::qmessageauthenticationcode_new(method, &QByteArray::from(key))
+    }
+
+    /// Adds `data` to the message.
+    pub fn add_data(self: Pin<&mut Self>, data: &[u8]) {
+        ffi::qmessageauthenticationcode_add_data(self, data);
+    }
+
+    /// Reads the data from the open `device` until it ends and adds it to the message. Returns `true` if reading was successful.
+    pub fn add_data_device<T>(self: Pin<&mut Self>, device: Pin<&mut T>) -> bool
+    where
+        T: Upcast<QIODevice>,
+    {
+        // SAFETY: `device` is valid for the duration of the call.
+        unsafe { self.add_data_device_raw(upcast_mut(unpin_for_qt(device))) }
+    }
+
+    /// Returns the authentication code for the message `message` using the key `key` and the method `method`.
+    pub fn hash(message: &[u8], key: &[u8], method: QCryptographicHashAlgorithm) -> QByteArray {
+        ffi::qmessageauthenticationcode_hash(message, key, method)
+    }
+
+    /// Sets secret `key`.
+    ///
+    /// **Note:** In Qt versions prior to 6.6, calling this function automatically calls [`reset`](QMessageAuthenticationCode::reset).
+    pub fn set_key(self: Pin<&mut Self>, key: &[u8]) {
+        ffi::qmessageauthenticationcode_set_key(self, key);
+    }
+}
+
+impl Write for Pin<&mut QMessageAuthenticationCode> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.as_mut().add_data(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &[u8] = b"key";
+
+    const MESSAGE: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+    const HMAC_SHA256: &[u8] = &[
+        0xf7, 0xbc, 0x83, 0xf4, 0x30, 0x53, 0x84, 0x24, 0xb1, 0x32, 0x98, 0xe6, 0xaa, 0x6f, 0xb1,
+        0x43, 0xef, 0x4d, 0x59, 0xa1, 0x49, 0x46, 0x17, 0x59, 0x97, 0x47, 0x9d, 0xbc, 0x2d, 0x1a,
+        0x3c, 0xd8,
+    ];
+
+    #[test]
+    fn hash() {
+        let code =
+            QMessageAuthenticationCode::hash(MESSAGE, KEY, QCryptographicHashAlgorithm::Sha256);
+        assert_eq!(code.as_slice(), HMAC_SHA256);
+    }
+
+    #[test]
+    fn write() {
+        let mut code = QMessageAuthenticationCode::new(QCryptographicHashAlgorithm::Sha256, KEY);
+        let (start, end) = MESSAGE.split_at(10);
+        code.pin_mut().write_all(start).unwrap();
+        code.pin_mut().write_all(end).unwrap();
+        assert_eq!(code.result().as_slice(), HMAC_SHA256);
+    }
+}
@@ -0,0 +1,105 @@
+use cxx_qt_lib::QByteArray;
+
+use crate::QCryptographicHashAlgorithm;
+
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qtypes.h");
+        type quint64 = cxx_qt_lib::quint64;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qcryptographichash.h");
+        type QCryptographicHashAlgorithm = crate::QCryptographicHashAlgorithm;
+    }
+
+    #[namespace = "QPasswordDigestor"]
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qpassworddigestor.h");
+
+        #[rust_name = "derive_key_pbkdf1_quint64"]
+        fn deriveKeyPbkdf1(
+            algorithm: QCryptographicHashAlgorithm,
+            data: &QByteArray,
+            salt: &QByteArray,
+            iterations: i32,
+            dk_len: quint64,
+        ) -> QByteArray;
+
+        #[rust_name = "derive_key_pbkdf2_quint64"]
+        fn deriveKeyPbkdf2(
+            algorithm: QCryptographicHashAlgorithm,
+            data: &QByteArray,
+            salt: &QByteArray,
+            iterations: i32,
+            dk_len: quint64,
+        ) -> QByteArray;
+    }
+}
+
+/// The `QPasswordDigestor` namespace contains functions which you can use to generate hashes or keys.
+///
+/// This type cannot be constructed. It only serves as a namespace for its associated functions.
+///
+/// Qt Documentation: [QPasswordDigestor](https://doc.qt.io/qt-6/qpassworddigestor.html#details)
+#[derive(Debug)]
+pub enum QPasswordDigestor {}
+
+impl QPasswordDigestor {
+    /// Returns a hash computed using the PBKDF1-algorithm as defined in [RFC 8018, section 5.1](https://datatracker.ietf.org/doc/html/rfc8018#section-5.1).
+    ///
+    /// The function takes the `data` and `salt`, and then hashes it repeatedly for `iterations` iterations using the specified hash `algorithm`. If the resulting hash is longer than `dk_len` then it is truncated before it is returned.
+    ///
+    /// This function only supports SHA-1 and MD5! The max output size is 160 bits (20 bytes) when using SHA-1, or 128 bits (16 bytes) when using MD5. Specifying a value for `dk_len` which is greater than this limit will result in a warning and an empty `QByteArray` will be returned. To programmatically check this limit you can use [`QCryptographicHash::hash_length`](crate::QCryptographicHash::hash_length). Furthermore: the `salt` must always be 8 bytes long!
+    ///
+    /// **Note:** This function is provided for use with legacy applications and all new applications are recommended to use PBKDF2.
+    pub fn derive_key_pbkdf1(
+        algorithm: QCryptographicHashAlgorithm,
+        data: &QByteArray,
+        salt: &QByteArray,
+        iterations: i32,
+        dk_len: u64,
+    ) -> QByteArray {
+        ffi::derive_key_pbkdf1_quint64(algorithm, data, salt, iterations, dk_len.into())
+    }
+
+    /// Derive a key using the PBKDF2-algorithm as defined in [RFC 8018, section 5.2](https://datatracker.ietf.org/doc/html/rfc8018#section-5.2).
+    ///
+    /// This function takes the `data` and `salt`, and then applies HMAC-X, where the X is `algorithm`, repeatedly. It internally concatenates intermediate results to the final output until at least `dk_len` amount of bytes have been computed and it will execute HMAC-X `iterations` times each time a concatenation is required. The total number of times it will execute HMAC-X depends on `iterations`, `dk_len` and `algorithm` and can be calculated as `iterations * ceil(dk_len / hash_length(algorithm))`.
+    pub fn derive_key_pbkdf2(
+        algorithm: QCryptographicHashAlgorithm,
+        data: &QByteArray,
+        salt: &QByteArray,
+        iterations: i32,
+        dk_len: u64,
+    ) -> QByteArray {
+        ffi::derive_key_pbkdf2_quint64(algorithm, data, salt, iterations, dk_len.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derive_key_pbkdf2() {
+        let key = QPasswordDigestor::derive_key_pbkdf2(
+            QCryptographicHashAlgorithm::Sha1,
+            &QByteArray::from("password"),
+            &QByteArray::from("salt"),
+            2,
+            20,
+        );
+        assert_eq!(
+            key.as_slice(),
+            &[
+                0xea, 0x6c, 0x01, 0x4d, 0xc7, 0x2d, 0x6f, 0x8c, 0xcd, 0x1e, 0xd9, 0x2a, 0xce, 0x1d,
+                0x41, 0xf0, 0xd8, 0xde, 0x89, 0x57,
+            ]
+        );
+    }
+}
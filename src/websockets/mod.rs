@@ -0,0 +1,11 @@
+mod qwebsocket;
+pub use qwebsocket::QWebSocket;
+
+mod qwebsocketcorsauthenticator;
+pub use qwebsocketcorsauthenticator::QWebSocketCorsAuthenticator;
+
+mod qwebsocketprotocol;
+pub use qwebsocketprotocol::{QWebSocketProtocolCloseCode, QWebSocketProtocolVersion};
+
+mod qwebsocketserver;
+pub use qwebsocketserver::{QWebSocketServer, QWebSocketServerSslMode};
@@ -0,0 +1,661 @@
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::{QByteArray, QString};
+
+use crate::qobject::debug_qobject;
+use crate::util::IsNonNull;
+use crate::{QHostAddress, QWebSocketProtocolVersion};
+
+#[cxx_qt::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qbytearray.h");
+        type QByteArray = cxx_qt_lib::QByteArray;
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qint64 = cxx_qt_lib::qint64;
+        type quint64 = cxx_qt_lib::quint64;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-io/qauthenticator.h");
+        type QAuthenticator = crate::QAuthenticator;
+        include!("cxx-qt-io/qhostaddress.h");
+        type QHostAddress = crate::QHostAddress;
+        include!("cxx-qt-io/qnetworkproxy.h");
+        type QNetworkProxy = crate::QNetworkProxy;
+        include!("cxx-qt-io/qnetworkrequest.h");
+        type QNetworkRequest = crate::QNetworkRequest;
+    }
+
+    #[cfg(feature = "ssl")]
+    extern "C++" {
+        include!("cxx-qt-io/qsslconfiguration.h");
+        type QSslConfiguration = crate::QSslConfiguration;
+        include!("cxx-qt-io/qsslerror.h");
+        type QSslError = crate::QSslError;
+        include!("cxx-qt-io/qsslpresharedkeyauthenticator.h");
+        type QSslPreSharedKeyAuthenticator = crate::QSslPreSharedKeyAuthenticator;
+        include!("cxx-qt-io/qlist.h");
+        type QList_QSslError = cxx_qt_lib::QList<QSslError>;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qabstractsocket.h");
+        type QAbstractSocketPauseModes = crate::QAbstractSocketPauseModes;
+        type QAbstractSocketSocketError = crate::QAbstractSocketSocketError;
+        type QAbstractSocketSocketState = crate::QAbstractSocketSocketState;
+        include!("cxx-qt-io/qwebsocketprotocol.h");
+        type QWebSocketProtocolCloseCode = crate::QWebSocketProtocolCloseCode;
+        type QWebSocketProtocolVersion = crate::QWebSocketProtocolVersion;
+    }
+
+    unsafe extern "C++Qt" {
+        /// Implements a TCP socket that talks the WebSocket protocol.
+        ///
+        /// WebSockets is a web technology providing full-duplex communications channels over a single TCP connection. The WebSocket protocol was standardized by the IETF as [RFC 6455](https://datatracker.ietf.org/doc/html/rfc6455) in 2011. `QWebSocket` can both be used in a client application and server application.
+        ///
+        /// This class was modeled after [`QAbstractSocket`](crate::QAbstractSocket).
+        ///
+        /// Qt Documentation: [QWebSocket](https://doc.qt.io/qt-6/qwebsocket.html#details)
+        #[qobject]
+        #[base = QObject]
+        type QWebSocket;
+
+        /// Aborts the current socket and resets the socket. Unlike [`close`](QWebSocket::close), this function immediately closes the socket, discarding any pending data in the write buffer.
+        fn abort(self: Pin<&mut QWebSocket>);
+
+        #[doc(hidden)]
+        #[rust_name = "bytes_to_write_qint64"]
+        fn bytesToWrite(self: &QWebSocket) -> qint64;
+
+        /// Gracefully closes the socket with the given `close_code` and `reason`.
+        ///
+        /// Any data in the write buffer is flushed before the socket is closed. The `close_code` is a [`QWebSocketProtocolCloseCode`] indicating the reason to close, and `reason` describes the reason of the closure more in detail. All control frames, including the Close frame, are limited to 125 bytes. Since two of these are used for `close_code` the maximum length of `reason` is 123!
+        fn close(
+            self: Pin<&mut QWebSocket>,
+            close_code: QWebSocketProtocolCloseCode,
+            reason: &QString,
+        );
+
+        /// Returns the code indicating why the socket was closed.
+        #[rust_name = "close_code"]
+        fn closeCode(self: &QWebSocket) -> QWebSocketProtocolCloseCode;
+
+        /// Returns the reason why the socket was closed.
+        #[rust_name = "close_reason"]
+        fn closeReason(self: &QWebSocket) -> QString;
+
+        /// Returns the type of error that last occurred.
+        fn error(self: &QWebSocket) -> QAbstractSocketSocketError;
+
+        /// Returns a human-readable description of the last error that occurred.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QWebSocket) -> QString;
+
+        /// This function writes as much as possible from the internal write buffer to the underlying network socket, without blocking. If any data was written, this function returns `true`; otherwise `false` is returned. Call this function if you need `QWebSocket` to start sending buffered data immediately. The number of bytes successfully written depends on the operating system. In most cases, you do not need to call this function, because `QWebSocket` will start sending data automatically once control goes back to the event loop.
+        fn flush(self: Pin<&mut QWebSocket>) -> bool;
+
+        /// Will ignore all SSL errors.
+        ///
+        /// See [`QSslSocket::ignore_all_ssl_errors`](crate::QSslSocket::ignore_all_ssl_errors) for more information.
+        #[cfg(feature = "ssl")]
+        #[rust_name = "ignore_all_ssl_errors"]
+        fn ignoreSslErrors(self: Pin<&mut QWebSocket>);
+
+        /// This function tells `QWebSocket` to ignore the errors given in `errors`.
+        ///
+        /// See [`QSslSocket::ignore_ssl_errors`](crate::QSslSocket::ignore_ssl_errors) for more information.
+        #[cfg(feature = "ssl")]
+        #[rust_name = "ignore_ssl_errors"]
+        fn ignoreSslErrors(self: Pin<&mut QWebSocket>, errors: &QList_QSslError);
+
+        /// Returns `true` if the socket is ready for reading and writing; otherwise returns `false`.
+        #[rust_name = "is_valid"]
+        fn isValid(self: &QWebSocket) -> bool;
+
+        #[doc(hidden)]
+        #[rust_name = "local_address_or_null"]
+        fn localAddress(self: &QWebSocket) -> QHostAddress;
+
+        /// Returns the host port number of the local socket if available; otherwise returns 0.
+        #[rust_name = "local_port"]
+        fn localPort(self: &QWebSocket) -> u16;
+
+        #[doc(hidden)]
+        #[rust_name = "max_allowed_incoming_frame_size_quint64"]
+        fn maxAllowedIncomingFrameSize(self: &QWebSocket) -> quint64;
+
+        #[doc(hidden)]
+        #[rust_name = "max_allowed_incoming_message_size_quint64"]
+        fn maxAllowedIncomingMessageSize(self: &QWebSocket) -> quint64;
+
+        /// Opens a WebSocket connection using the given `url`.
+        ///
+        /// If the url contains newline characters (`\r\n`), then the [`error_occurred`](QWebSocket::error_occurred) signal will be emitted with [`QAbstractSocketSocketError::ConnectionRefusedError`](crate::QAbstractSocketSocketError::ConnectionRefusedError) as error type.
+        fn open(self: Pin<&mut QWebSocket>, url: &QUrl);
+
+        /// Opens a WebSocket connection using the given `request`.
+        ///
+        /// The `request` url will be used to open the WebSocket connection. Headers present in the request will be sent to the server in the upgrade request, together with the ones needed for the websocket handshake.
+        #[rust_name = "open_request"]
+        fn open(self: Pin<&mut QWebSocket>, request: &QNetworkRequest);
+
+        /// Returns the current origin.
+        fn origin(self: &QWebSocket) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "outgoing_frame_size_quint64"]
+        fn outgoingFrameSize(self: &QWebSocket) -> quint64;
+
+        /// Returns the pause mode of this socket.
+        #[rust_name = "pause_mode"]
+        fn pauseMode(self: &QWebSocket) -> QAbstractSocketPauseModes;
+
+        #[doc(hidden)]
+        #[rust_name = "peer_address_or_null"]
+        fn peerAddress(self: &QWebSocket) -> QHostAddress;
+
+        #[doc(hidden)]
+        #[rust_name = "peer_name_or_empty"]
+        fn peerName(self: &QWebSocket) -> QString;
+
+        /// Returns the port of the connected peer if the socket is in [`QAbstractSocketSocketState::ConnectedState`](crate::QAbstractSocketSocketState::ConnectedState); otherwise returns 0.
+        #[rust_name = "peer_port"]
+        fn peerPort(self: &QWebSocket) -> u16;
+
+        /// Pings the server to indicate that the connection is still alive. Additional `payload` can be sent along the ping message.
+        ///
+        /// The size of the `payload` cannot be bigger than 125. If it is larger, the `payload` is clipped to 125 bytes.
+        ///
+        /// **Note:** `QWebSocket` and [`QWebSocketServer`](crate::QWebSocketServer) handles ping requests internally, which means they automatically send back a pong response to the peer.
+        fn ping(self: Pin<&mut QWebSocket>, payload: &QByteArray);
+
+        /// Returns the network proxy for this socket. By default [`QNetworkProxyProxyType::DefaultProxy`](crate::QNetworkProxyProxyType::DefaultProxy) is used.
+        fn proxy(self: &QWebSocket) -> QNetworkProxy;
+
+        #[doc(hidden)]
+        #[rust_name = "read_buffer_size_qint64"]
+        fn readBufferSize(self: &QWebSocket) -> qint64;
+
+        /// Returns the request that was or will be used to open this socket.
+        fn request(self: &QWebSocket) -> QNetworkRequest;
+
+        /// Returns the url the socket is connected to or will connect to.
+        #[rust_name = "request_url"]
+        fn requestUrl(self: &QWebSocket) -> QUrl;
+
+        /// Returns the name of the resource that the socket is connected to or will connect to.
+        #[rust_name = "resource_name"]
+        fn resourceName(self: &QWebSocket) -> QString;
+
+        /// Continues data transfer on the socket. This method should only be used after the socket has been set to pause upon notifications and a notification has been received. The only notification currently supported is [`ssl_errors`](QWebSocket::ssl_errors). Calling this method if the socket is not paused results in undefined behavior.
+        fn resume(self: Pin<&mut QWebSocket>);
+
+        #[doc(hidden)]
+        #[rust_name = "send_binary_message_qint64"]
+        fn sendBinaryMessage(self: Pin<&mut QWebSocket>, data: &QByteArray) -> qint64;
+
+        #[doc(hidden)]
+        #[rust_name = "send_text_message_qint64"]
+        fn sendTextMessage(self: Pin<&mut QWebSocket>, message: &QString) -> qint64;
+
+        #[doc(hidden)]
+        #[rust_name = "set_max_allowed_incoming_frame_size_quint64"]
+        fn setMaxAllowedIncomingFrameSize(
+            self: Pin<&mut QWebSocket>,
+            max_allowed_incoming_frame_size: quint64,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "set_max_allowed_incoming_message_size_quint64"]
+        fn setMaxAllowedIncomingMessageSize(
+            self: Pin<&mut QWebSocket>,
+            max_allowed_incoming_message_size: quint64,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "set_outgoing_frame_size_quint64"]
+        fn setOutgoingFrameSize(self: Pin<&mut QWebSocket>, outgoing_frame_size: quint64);
+
+        /// Controls whether to pause upon receiving a notification. The `pause_mode` parameter specifies the conditions in which the socket should be paused.
+        ///
+        /// The only notification currently supported is [`ssl_errors`](QWebSocket::ssl_errors). If set to [`QAbstractSocketPauseMode::PauseOnSslErrors`](crate::QAbstractSocketPauseMode::PauseOnSslErrors), data transfer on the socket will be paused and needs to be enabled explicitly again by calling [`resume`](QWebSocket::resume). By default, this option is set to [`QAbstractSocketPauseMode::PauseNever`](crate::QAbstractSocketPauseMode::PauseNever). This option must be called before connecting to the server, otherwise it will result in undefined behavior.
+        #[rust_name = "set_pause_mode"]
+        fn setPauseMode(self: Pin<&mut QWebSocket>, pause_mode: QAbstractSocketPauseModes);
+
+        /// Sets the proxy to `network_proxy`.
+        #[rust_name = "set_proxy"]
+        fn setProxy(self: Pin<&mut QWebSocket>, network_proxy: &QNetworkProxy);
+
+        #[doc(hidden)]
+        #[rust_name = "set_read_buffer_size_qint64"]
+        fn setReadBufferSize(self: Pin<&mut QWebSocket>, size: qint64);
+
+        /// Sets the socket's SSL configuration to be the contents of `ssl_configuration`.
+        ///
+        /// This function sets the local certificate, the ciphers, the private key and the CA certificates to those stored in `ssl_configuration`. It is not possible to set the SSL-state related fields.
+        #[cfg(feature = "ssl")]
+        #[rust_name = "set_ssl_configuration"]
+        fn setSslConfiguration(self: Pin<&mut QWebSocket>, ssl_configuration: &QSslConfiguration);
+
+        /// Returns the socket's SSL configuration state. The default SSL configuration of a socket is to use the default ciphers, default CA certificates, no local private key or certificate.
+        ///
+        /// The SSL configuration also contains fields that can change with time without notice.
+        #[cfg(feature = "ssl")]
+        #[rust_name = "ssl_configuration"]
+        fn sslConfiguration(self: &QWebSocket) -> QSslConfiguration;
+
+        /// Returns the current state of the socket.
+        fn state(self: &QWebSocket) -> QAbstractSocketSocketState;
+
+        /// Returns the used WebSocket protocol subprotocol.
+        ///
+        /// Introduced in Qt 6.4.
+        #[cfg(cxxqt_qt_version_at_least_6_4)]
+        fn subprotocol(self: &QWebSocket) -> QString;
+
+        /// Returns the version the socket is currently using.
+        fn version(self: &QWebSocket) -> QWebSocketProtocolVersion;
+
+        /// This signal is emitted when the socket is about to close. Connect this signal if you have operations that need to be performed before the socket closes (e.g., if you have data in a separate buffer that needs to be written to the device).
+        #[qsignal]
+        #[rust_name = "about_to_close"]
+        fn aboutToClose(self: Pin<&mut QWebSocket>);
+
+        /// This signal is emitted when the server requires authentication. The `authenticator` object must then be filled in with the required details to allow authentication and continue the connection.
+        ///
+        /// If you know that the server may require authentication, you can set the username and password on the initial [`QUrl`], using [`QUrl::set_user_name`](cxx_qt_lib::QUrl::set_user_name) and [`QUrl::set_password`](cxx_qt_lib::QUrl::set_password). `QWebSocket` will still try to connect once without using the authenticator.
+        ///
+        /// **Note:** It is not possible to use a [`ConnectionType::QueuedConnection`](cxx_qt::ConnectionType::QueuedConnection) to connect to this signal, as the connection will fail if the authenticator has not been filled in with new information when the signal returns.
+        ///
+        /// Introduced in Qt 6.6.
+        ///
+        /// # Safety
+        ///
+        /// `authenticator` must be valid.
+        #[cfg(cxxqt_qt_version_at_least_6_6)]
+        #[qsignal]
+        #[rust_name = "authentication_required"]
+        unsafe fn authenticationRequired(
+            self: Pin<&mut QWebSocket>,
+            authenticator: *mut QAuthenticator,
+        );
+
+        /// This signal is emitted whenever a binary frame is received. The `frame` contains the data and `is_last_frame` indicates whether this is the last frame of the complete message.
+        ///
+        /// This signal can be used to process large messages frame by frame, instead of waiting for the complete message to arrive.
+        #[qsignal]
+        #[rust_name = "binary_frame_received"]
+        fn binaryFrameReceived(self: Pin<&mut QWebSocket>, frame: &QByteArray, is_last_frame: bool);
+
+        /// This signal is emitted whenever a binary message is received. The `message` contains the received bytes.
+        #[qsignal]
+        #[rust_name = "binary_message_received"]
+        fn binaryMessageReceived(self: Pin<&mut QWebSocket>, message: &QByteArray);
+
+        #[doc(hidden)]
+        #[qsignal]
+        #[rust_name = "bytes_written_qint64"]
+        fn bytesWritten(self: Pin<&mut QWebSocket>, bytes: qint64);
+
+        /// Emitted when a connection is successfully established. A connection is successfully established when the socket is connected and the handshake was successful.
+        #[qsignal]
+        fn connected(self: Pin<&mut QWebSocket>);
+
+        /// Emitted when the socket is disconnected.
+        #[qsignal]
+        fn disconnected(self: Pin<&mut QWebSocket>);
+
+        /// This signal is emitted after an error occurred. The `error` parameter describes the type of error that occurred.
+        ///
+        /// Introduced in Qt 6.5.
+        #[cfg(cxxqt_qt_version_at_least_6_5)]
+        #[qsignal]
+        #[rust_name = "error_occurred"]
+        fn errorOccurred(self: Pin<&mut QWebSocket>, error: QAbstractSocketSocketError);
+
+        /// `QWebSocket` can emit this signal several times during the SSL handshake, before encryption has been established, to indicate that an error has occurred while establishing the identity of the peer. The `error` is usually an indication that `QWebSocket` is unable to securely identify the peer.
+        ///
+        /// This signal provides you with an early indication when something's wrong. By connecting to this signal, you can manually choose to tear down the connection from inside the connected slot before the handshake has completed. If no action is taken, `QWebSocket` will proceed to emitting [`ssl_errors`](QWebSocket::ssl_errors).
+        #[cfg(feature = "ssl")]
+        #[qsignal]
+        #[rust_name = "peer_verify_error"]
+        fn peerVerifyError(self: Pin<&mut QWebSocket>, error: &QSslError);
+
+        #[doc(hidden)]
+        #[qsignal]
+        #[rust_name = "pong_quint64"]
+        fn pong(self: Pin<&mut QWebSocket>, elapsed_time: quint64, payload: &QByteArray);
+
+        /// This signal is emitted if the SSL/TLS handshake negotiates a PSK ciphersuite, and therefore a PSK authentication is then required.
+        ///
+        /// When using PSK, the client must send to the server a valid identity and a valid pre shared key, in order for the SSL handshake to continue. Applications can provide this information in a slot connected to this signal, by filling in the passed `authenticator` object according to their needs.
+        ///
+        /// **Note:** Ignoring this signal, or failing to provide the required credentials, will cause the handshake to fail, and therefore the connection to be aborted.
+        ///
+        /// **Note:** The `authenticator` object is owned by the socket and must not be deleted by the application.
+        #[cfg(feature = "ssl")]
+        #[qsignal]
+        #[rust_name = "pre_shared_key_authentication_required"]
+        unsafe fn preSharedKeyAuthenticationRequired(
+            self: Pin<&mut QWebSocket>,
+            authenticator: *mut QSslPreSharedKeyAuthenticator,
+        );
+
+        /// This signal can be emitted when a `proxy` that requires authentication is used. The `authenticator` object can then be filled in with the required details to allow authentication and continue the connection.
+        ///
+        /// **Note:** It is not possible to use a [`ConnectionType::QueuedConnection`](cxx_qt::ConnectionType::QueuedConnection) to connect to this signal, as the connection will fail if the authenticator has not been filled in with new information when the signal returns.
+        ///
+        /// # Safety
+        ///
+        /// `authenticator` must be valid.
+        #[qsignal]
+        #[rust_name = "proxy_authentication_required"]
+        unsafe fn proxyAuthenticationRequired(
+            self: Pin<&mut QWebSocket>,
+            proxy: &QNetworkProxy,
+            authenticator: *mut QAuthenticator,
+        );
+
+        /// This signal is emitted when the input (reading) stream is closed in this device. It is emitted as soon as the closing is detected.
+        #[qsignal]
+        #[rust_name = "read_channel_finished"]
+        fn readChannelFinished(self: Pin<&mut QWebSocket>);
+
+        /// `QWebSocket` emits this signal after the SSL handshake to indicate that one or more errors have occurred while establishing the identity of the peer. The errors are usually an indication that `QWebSocket` is unable to securely identify the peer. Unless any action is taken, the connection will be dropped after this signal has been emitted. If you want to continue connecting despite the errors that have occurred, you must call [`ignore_all_ssl_errors`](QWebSocket::ignore_all_ssl_errors) from inside a slot connected to this signal.
+        ///
+        /// `errors` contains one or more errors that prevent `QWebSocket` from verifying the identity of the peer.
+        ///
+        /// **Note:** You cannot use [`ConnectionType::QueuedConnection`](cxx_qt::ConnectionType::QueuedConnection) when connecting to this signal, or calling [`ignore_all_ssl_errors`](QWebSocket::ignore_all_ssl_errors) will have no effect.
+        #[cfg(feature = "ssl")]
+        #[qsignal]
+        #[rust_name = "ssl_errors"]
+        fn sslErrors(self: Pin<&mut QWebSocket>, errors: &QList_QSslError);
+
+        /// This signal is emitted whenever `QWebSocket`'s state changes. The `state` parameter is the new state.
+        ///
+        /// **Note:** [`QAbstractSocketSocketState::ConnectedState`](crate::QAbstractSocketSocketState::ConnectedState) is emitted after the handshake with the server has succeeded.
+        #[qsignal]
+        #[rust_name = "state_changed"]
+        fn stateChanged(self: Pin<&mut QWebSocket>, state: QAbstractSocketSocketState);
+
+        /// This signal is emitted whenever a text frame is received. The `frame` contains the data and `is_last_frame` indicates whether this is the last frame of the complete message.
+        ///
+        /// This signal can be used to process large messages frame by frame, instead of waiting for the complete message to arrive.
+        #[qsignal]
+        #[rust_name = "text_frame_received"]
+        fn textFrameReceived(self: Pin<&mut QWebSocket>, frame: &QString, is_last_frame: bool);
+
+        /// This signal is emitted whenever a text message is received. The `message` contains the received text.
+        #[qsignal]
+        #[rust_name = "text_message_received"]
+        fn textMessageReceived(self: Pin<&mut QWebSocket>, message: &QString);
+    }
+
+    #[cfg(feature = "ssl")]
+    unsafe extern "C++Qt" {
+        /// Continues the WebSocket handshake when either [`ssl_errors`](QWebSocket::ssl_errors) or [`peer_verify_error`](QWebSocket::peer_verify_error) has been emitted and the handshake was paused by [`QAbstractSocketPauseMode::PauseOnSslErrors`](crate::QAbstractSocketPauseMode::PauseOnSslErrors).
+        #[rust_name = "continue_interrupted_handshake"]
+        fn continueInterruptedHandshake(self: Pin<&mut QWebSocket>);
+
+        /// `QWebSocket` emits this signal if a certificate verification error was found and if early error reporting was enabled in [`QSslConfiguration`](crate::QSslConfiguration). An application is expected to inspect the error and decide if it wants to continue the handshake, or abort it and send an alert message to the peer. The signal-slot connection must be direct.
+        #[qsignal]
+        #[rust_name = "handshake_interrupted_on_error"]
+        fn handshakeInterruptedOnError(self: Pin<&mut QWebSocket>, error: &QSslError);
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qwebsocket.h");
+
+        #[rust_name = "qwebsocket_max_incoming_frame_size"]
+        fn qwebsocketMaxIncomingFrameSize() -> quint64;
+
+        #[rust_name = "qwebsocket_max_incoming_message_size"]
+        fn qwebsocketMaxIncomingMessageSize() -> quint64;
+
+        #[rust_name = "qwebsocket_max_outgoing_frame_size"]
+        fn qwebsocketMaxOutgoingFrameSize() -> quint64;
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qwebsocket_init_default"]
+        fn make_unique() -> UniquePtr<QWebSocket>;
+        #[rust_name = "qwebsocket_init_origin"]
+        fn make_unique(
+            origin: &QString,
+            version: QWebSocketProtocolVersion,
+        ) -> UniquePtr<QWebSocket>;
+    }
+}
+
+pub use ffi::QWebSocket;
+
+impl fmt::Debug for QWebSocket {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QWebSocket {
+    /// Creates a new `QWebSocket` that uses the latest version of the WebSocket protocol and no origin.
+    pub fn new() -> UniquePtr<Self> {
+        ffi::qwebsocket_init_default()
+    }
+
+    /// Creates a new `QWebSocket` with the given `origin` and `version` of the protocol to use.
+    ///
+    /// The `origin` of the client is as specified in [RFC 6454](https://datatracker.ietf.org/doc/html/rfc6454). (The `origin` is not required for non-web browser clients (see [RFC 6455](https://datatracker.ietf.org/doc/html/rfc6455))).
+    ///
+    /// **Note:** Currently only V13 ([RFC 6455](https://datatracker.ietf.org/doc/html/rfc6455)) is supported.
+    pub fn with_origin(origin: &QString, version: QWebSocketProtocolVersion) -> UniquePtr<Self> {
+        ffi::qwebsocket_init_origin(origin, version)
+    }
+
+    /// Returns the number of bytes that are waiting to be written. The bytes are written when control goes back to the event loop or when [`flush`](QWebSocket::flush) is called.
+    pub fn bytes_to_write(&self) -> i64 {
+        self.bytes_to_write_qint64().into()
+    }
+
+    /// Returns the local address if available; otherwise returns `None`.
+    pub fn local_address(&self) -> Option<QHostAddress> {
+        self.local_address_or_null().nonnull()
+    }
+
+    /// Returns the maximum allowed size of an incoming websocket frame.
+    pub fn max_allowed_incoming_frame_size(&self) -> u64 {
+        self.max_allowed_incoming_frame_size_quint64().into()
+    }
+
+    /// Returns the maximum allowed size of an incoming websocket message.
+    pub fn max_allowed_incoming_message_size(&self) -> u64 {
+        self.max_allowed_incoming_message_size_quint64().into()
+    }
+
+    /// Returns the maximum size of an outgoing websocket frame.
+    pub fn outgoing_frame_size(&self) -> u64 {
+        self.outgoing_frame_size_quint64().into()
+    }
+
+    /// Returns the address of the peer if the socket is in [`QAbstractSocketSocketState::ConnectedState`](crate::QAbstractSocketSocketState::ConnectedState); otherwise returns `None`.
+    pub fn peer_address(&self) -> Option<QHostAddress> {
+        self.peer_address_or_null().nonnull()
+    }
+
+    /// Returns the name of the peer as specified by [`open`](QWebSocket::open), or `None` if no peer name is available.
+    pub fn peer_name(&self) -> Option<QString> {
+        self.peer_name_or_empty().nonnull()
+    }
+
+    /// Returns the size in bytes of the read buffer. A read buffer size of 0 (the default) means that the buffer has no size limit.
+    pub fn read_buffer_size(&self) -> i64 {
+        self.read_buffer_size_qint64().into()
+    }
+
+    /// Sends the given `data` over the socket as a binary message and returns the number of bytes actually sent.
+    pub fn send_binary_message(self: Pin<&mut Self>, data: &QByteArray) -> i64 {
+        self.send_binary_message_qint64(data).into()
+    }
+
+    /// Sends the given `message` over the socket as a text message and returns the number of bytes actually sent.
+    pub fn send_text_message(self: Pin<&mut Self>, message: &QString) -> i64 {
+        self.send_text_message_qint64(message).into()
+    }
+
+    /// Sets the maximum allowed size of an incoming websocket frame to `max_allowed_incoming_frame_size`. If an incoming frame exceeds this limit, the peer gets disconnected. The accepted range is between 0 and [`QWebSocket::max_incoming_frame_size`]; the default is [`QWebSocket::max_incoming_frame_size`]. The purpose of this function is to avoid exhausting virtual memory.
+    pub fn set_max_allowed_incoming_frame_size(
+        self: Pin<&mut Self>,
+        max_allowed_incoming_frame_size: u64,
+    ) {
+        self.set_max_allowed_incoming_frame_size_quint64(max_allowed_incoming_frame_size.into());
+    }
+
+    /// Sets the maximum allowed size of an incoming websocket message to `max_allowed_incoming_message_size`. If an incoming message exceeds this limit, the peer gets disconnected. The accepted range is between 0 and [`QWebSocket::max_incoming_message_size`]; the default is [`QWebSocket::max_incoming_message_size`]. The purpose of this function is to avoid exhausting virtual memory.
+    pub fn set_max_allowed_incoming_message_size(
+        self: Pin<&mut Self>,
+        max_allowed_incoming_message_size: u64,
+    ) {
+        self.set_max_allowed_incoming_message_size_quint64(
+            max_allowed_incoming_message_size.into(),
+        );
+    }
+
+    /// Sets the maximum size of an outgoing websocket frame to `outgoing_frame_size`. The accepted range is between 0 and [`QWebSocket::max_outgoing_frame_size`]; the default is 512 KiB. The purpose of this function is to adapt to the maximum allowed frame size of the receiver.
+    pub fn set_outgoing_frame_size(self: Pin<&mut Self>, outgoing_frame_size: u64) {
+        self.set_outgoing_frame_size_quint64(outgoing_frame_size.into());
+    }
+
+    /// Sets the size of `QWebSocket`'s internal read buffer to be `size` bytes.
+    ///
+    /// If the buffer size is limited to a certain size, `QWebSocket` won't buffer more than this size of data. Exceptionally, a buffer size of 0 means that the read buffer is unlimited and all incoming data is buffered. This is the default. This option is useful if you only read the data at certain points in time (e.g., in a real-time streaming application) or if you want to protect your socket against receiving too much data, which may eventually cause your application to run out of memory.
+    pub fn set_read_buffer_size(self: Pin<&mut Self>, size: i64) {
+        self.set_read_buffer_size_qint64(size.into());
+    }
+
+    /// Returns the maximum supported size of an incoming websocket frame.
+    pub fn max_incoming_frame_size() -> u64 {
+        ffi::qwebsocket_max_incoming_frame_size().into()
+    }
+
+    /// Returns the maximum supported size of an incoming websocket message.
+    pub fn max_incoming_message_size() -> u64 {
+        ffi::qwebsocket_max_incoming_message_size().into()
+    }
+
+    /// Returns the maximum supported size of an outgoing websocket frame.
+    pub fn max_outgoing_frame_size() -> u64 {
+        ffi::qwebsocket_max_outgoing_frame_size().into()
+    }
+
+    wrap_qsignal! {
+        /// This signal is emitted every time a payload of data has been written to the socket. The `bytes` argument is set to the number of bytes that were written in this payload.
+        ///
+        /// **Note:** This signal has the same meaning as the [`QAbstractSocket`](crate::QAbstractSocket) signal of the same name.
+        bytes_written(bytes_written_qint64)(bytes: i64);
+        connect_bytes_written(connect_bytes_written_qint64);
+        on_bytes_written(on_bytes_written_qint64);
+        "bytesWritten"
+    }
+
+    wrap_qsignal! {
+        /// Emitted when a pong message is received in reply to a previous ping. `elapsed_time` contains the roundtrip time in milliseconds and `payload` contains an optional payload that was sent with the ping.
+        pong(pong_quint64)(elapsed_time: u64, payload: &QByteArray);
+        connect_pong(connect_pong_quint64);
+        on_pong(on_pong_quint64);
+        "pong"
+    }
+}
+
+impl Deref for QWebSocket {
+    type Target = QObject;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QAbstractSocketSocketState, QNetworkProxy};
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QWebSocketProps {
+            max_allowed_incoming_frame_size: u64,
+            max_allowed_incoming_message_size: u64,
+            outgoing_frame_size: u64,
+            proxy: QNetworkProxy,
+            read_buffer_size: i64,
+        }
+
+        let mut proxy = QNetworkProxy::default();
+        proxy.set_host_name(&QString::from("host"));
+
+        let props = QWebSocketProps {
+            max_allowed_incoming_frame_size: 1024,
+            max_allowed_incoming_message_size: 4096,
+            outgoing_frame_size: 512,
+            proxy,
+            read_buffer_size: 2048,
+        };
+
+        let mut socket = QWebSocket::new();
+
+        socket
+            .pin_mut()
+            .set_max_allowed_incoming_frame_size(props.max_allowed_incoming_frame_size);
+        socket
+            .pin_mut()
+            .set_max_allowed_incoming_message_size(props.max_allowed_incoming_message_size);
+        socket
+            .pin_mut()
+            .set_outgoing_frame_size(props.outgoing_frame_size);
+        socket.pin_mut().set_proxy(&props.proxy);
+        socket
+            .pin_mut()
+            .set_read_buffer_size(props.read_buffer_size);
+
+        let actual_props = QWebSocketProps {
+            max_allowed_incoming_frame_size: socket.max_allowed_incoming_frame_size(),
+            max_allowed_incoming_message_size: socket.max_allowed_incoming_message_size(),
+            outgoing_frame_size: socket.outgoing_frame_size(),
+            proxy: socket.proxy(),
+            read_buffer_size: socket.read_buffer_size(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+
+    #[test]
+    fn with_origin() {
+        let origin = QString::from("https://example.com");
+        let socket = QWebSocket::with_origin(&origin, QWebSocketProtocolVersion::Version13);
+        assert_eq!(
+            (
+                socket.origin(),
+                socket.version(),
+                socket.state(),
+                socket.peer_address()
+            ),
+            (
+                origin,
+                QWebSocketProtocolVersion::Version13,
+                QAbstractSocketSocketState::UnconnectedState,
+                None
+            )
+        );
+    }
+}
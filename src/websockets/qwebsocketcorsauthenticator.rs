@@ -0,0 +1,36 @@
+#[cxx::bridge]
+mod ffi {
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+    }
+
+    unsafe extern "C++" {
+        include!("cxx-qt-io/qwebsocketcorsauthenticator.h");
+
+        /// The `QWebSocketCorsAuthenticator` class provides an authenticator object for Cross Origin Requests (CORS).
+        ///
+        /// The `QWebSocketCorsAuthenticator` class is used in the [`QWebSocketServer::origin_authentication_required`](crate::QWebSocketServer::origin_authentication_required) signal. The class provides a way to pass back the required information to the [`QWebSocketServer`](crate::QWebSocketServer). It provides applications with fine-grained control over which origin URLs are allowed and which aren't. By default, every origin is accepted. To get fine-grained control, an application connects the signal to a slot. When the [`origin`](QWebSocketCorsAuthenticator::origin) is accepted, it calls [`set_allowed(true)`](QWebSocketCorsAuthenticator::set_allowed).
+        ///
+        /// **Note:** Checking on the origin does not make much sense when the server is accessed via a non-browser client, as that client can set whatever origin header it likes. In case of a browser client, the server SHOULD check the validity of the origin.
+        ///
+        /// Qt Documentation: [QWebSocketCorsAuthenticator](https://doc.qt.io/qt-6/qwebsocketcorsauthenticator.html#details)
+        type QWebSocketCorsAuthenticator;
+
+        /// Returns `true` if the origin is allowed, otherwise returns `false`.
+        ///
+        /// **Note:** By default, every origin is accepted.
+        fn allowed(self: &QWebSocketCorsAuthenticator) -> bool;
+
+        /// Returns the origin this authenticator is handling about.
+        fn origin(self: &QWebSocketCorsAuthenticator) -> QString;
+
+        /// Allows or disallows the origin. Setting `allowed` to `true`, will accept the connection request for the given origin. Setting `allowed` to `false`, will reject the connection request.
+        ///
+        /// **Note:** By default, all origins are accepted.
+        #[rust_name = "set_allowed"]
+        fn setAllowed(self: Pin<&mut QWebSocketCorsAuthenticator>, allowed: bool);
+    }
+}
+
+pub use ffi::QWebSocketCorsAuthenticator;
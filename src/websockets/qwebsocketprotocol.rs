@@ -0,0 +1,67 @@
+#[cxx::bridge]
+mod ffi {
+    /// The close codes supported by WebSockets V13.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QWebSocketProtocolCloseCode {
+        /// Normal closure.
+        CloseCodeNormal = 1000,
+        /// Going away.
+        CloseCodeGoingAway = 1001,
+        /// Protocol error.
+        CloseCodeProtocolError = 1002,
+        /// Unsupported data.
+        CloseCodeDatatypeNotSupported = 1003,
+        /// Reserved.
+        CloseCodeReserved1004 = 1004,
+        /// No status received.
+        CloseCodeMissingStatusCode = 1005,
+        /// Abnormal closure.
+        CloseCodeAbnormalDisconnection = 1006,
+        /// Invalid frame payload data.
+        CloseCodeWrongDatatype = 1007,
+        /// Policy violation.
+        CloseCodePolicyViolated = 1008,
+        /// Message too big.
+        CloseCodeTooMuchData = 1009,
+        /// Mandatory extension missing.
+        CloseCodeMissingExtension = 1010,
+        /// Internal server error.
+        CloseCodeBadOperation = 1011,
+        /// TLS handshake failed.
+        CloseCodeTlsHandshakeFailed = 1015,
+    }
+
+    /// This enum describes the possible versions of the WebSocket protocol. Version 13 is the latest version, and is used by default.
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QWebSocketProtocolVersion {
+        /// Unknown or unspecified version.
+        VersionUnknown = -1,
+        /// [hixie76](https://tools.ietf.org/html/draft-hixie-thewebsocketprotocol-76) and [hybi-00](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-00).
+        Version0 = 0,
+        /// [hybi-04](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-04).
+        Version4 = 4,
+        /// [hybi-05](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-05).
+        Version5 = 5,
+        /// [hybi-06](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-06).
+        Version6 = 6,
+        /// [hybi-07](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-07).
+        Version7 = 7,
+        /// [hybi-08](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-08), [hybi-09](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-09), [hybi-10](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-10), [hybi-11](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-11) and [hybi-12](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-12).
+        Version8 = 8,
+        /// [hybi-13](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-13), [hybi-14](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-14), [hybi-15](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-15), [hybi-16](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-16), [hybi-17](https://tools.ietf.org/html/draft-ietf-hybi-thewebsocketprotocol-17) and [RFC 6455](https://tools.ietf.org/html/rfc6455). This is the latest version.
+        Version13 = 13,
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qwebsocketprotocol.h");
+        type QWebSocketProtocolCloseCode;
+        type QWebSocketProtocolVersion;
+    }
+}
+
+pub use ffi::{QWebSocketProtocolCloseCode, QWebSocketProtocolVersion};
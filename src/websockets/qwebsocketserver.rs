@@ -0,0 +1,413 @@
+use std::fmt;
+use std::ops::Deref;
+use std::pin::Pin;
+use std::time::Duration;
+
+use cxx::UniquePtr;
+use cxx_qt::QObject;
+use cxx_qt::casting::Upcast;
+use cxx_qt_lib::QString;
+
+use crate::qobject::debug_qobject;
+use crate::util::IsNonNull;
+use crate::util::MSecs;
+use crate::{QHostAddress, QWebSocket, SocketDescriptor};
+
+#[cxx_qt::bridge]
+mod ffi {
+    /// Indicates whether the server operates over wss (`SecureMode`) or ws (`NonSecureMode`).
+    #[repr(i32)]
+    #[derive(Debug)]
+    #[namespace = "rust::cxxqtio1"]
+    enum QWebSocketServerSslMode {
+        /// The server operates in secure mode (over wss).
+        SecureMode,
+        /// The server operates in non-secure mode (over ws).
+        NonSecureMode,
+    }
+
+    extern "C++" {
+        include!("cxx-qt-lib/qstring.h");
+        type QString = cxx_qt_lib::QString;
+        include!("cxx-qt-lib/qtypes.h");
+        type qintptr = cxx_qt_lib::qintptr;
+        include!("cxx-qt-lib/qurl.h");
+        type QUrl = cxx_qt_lib::QUrl;
+
+        include!("cxx-qt-io/qhostaddress.h");
+        type QHostAddress = crate::QHostAddress;
+        include!("cxx-qt-io/qnetworkproxy.h");
+        type QNetworkProxy = crate::QNetworkProxy;
+        include!("cxx-qt-io/qwebsocket.h");
+        type QWebSocket = crate::QWebSocket;
+        include!("cxx-qt-io/qwebsocketcorsauthenticator.h");
+        type QWebSocketCorsAuthenticator = crate::QWebSocketCorsAuthenticator;
+    }
+
+    #[cfg(feature = "ssl")]
+    extern "C++" {
+        include!("cxx-qt-io/qsslconfiguration.h");
+        type QSslConfiguration = crate::QSslConfiguration;
+        include!("cxx-qt-io/qsslerror.h");
+        type QSslError = crate::QSslError;
+        include!("cxx-qt-io/qsslpresharedkeyauthenticator.h");
+        type QSslPreSharedKeyAuthenticator = crate::QSslPreSharedKeyAuthenticator;
+        include!("cxx-qt-io/qlist.h");
+        type QList_QSslError = cxx_qt_lib::QList<QSslError>;
+    }
+
+    #[namespace = "rust::cxxqtio1"]
+    extern "C++" {
+        include!("cxx-qt-io/qabstractsocket.h");
+        type QAbstractSocketSocketError = crate::QAbstractSocketSocketError;
+        include!("cxx-qt-io/qwebsocketprotocol.h");
+        type QWebSocketProtocolCloseCode = crate::QWebSocketProtocolCloseCode;
+        include!("cxx-qt-io/qwebsocketserver.h");
+        type QWebSocketServerSslMode;
+    }
+
+    unsafe extern "C++Qt" {
+        /// Implements a WebSocket-based server.
+        ///
+        /// It is modeled after [`QTcpServer`](crate::QTcpServer), and behaves the same. So, if you know how to use `QTcpServer`, you know how to use `QWebSocketServer`. This class makes it possible to accept incoming WebSocket connections. You can specify the port or have `QWebSocketServer` pick one automatically. You can listen on a specific address or on all the machine's addresses. Call [`listen`](QWebSocketServer::listen) to have the server listen for incoming connections.
+        ///
+        /// The [`new_connection`](QWebSocketServer::new_connection) signal is then emitted each time a client connects to the server. Call [`next_pending_connection`](QWebSocketServer::next_pending_connection) to accept the pending connection as a connected [`QWebSocket`]. The function returns a pointer to a [`QWebSocket`] in [`QAbstractSocketSocketState::ConnectedState`](crate::QAbstractSocketSocketState::ConnectedState) that you can use for communicating with the client.
+        ///
+        /// If an error occurs, [`error`](QWebSocketServer::error) returns the type of error, and [`error_string`](QWebSocketServer::error_string) can be called to get a human readable description of what happened.
+        ///
+        /// When listening for connections, the address and port on which the server is listening are available as [`server_address`](QWebSocketServer::server_address) and [`server_port`](QWebSocketServer::server_port).
+        ///
+        /// Calling [`close`](QWebSocketServer::close) makes `QWebSocketServer` stop listening for incoming connections.
+        ///
+        /// `QWebSocketServer` currently does not support [WebSocket Extensions](https://datatracker.ietf.org/doc/html/rfc6455#page-39) and [WebSocket Subprotocols](https://datatracker.ietf.org/doc/html/rfc6455#page-12) before Qt 6.4.
+        ///
+        /// Qt Documentation: [QWebSocketServer](https://doc.qt.io/qt-6/qwebsocketserver.html#details)
+        #[qobject]
+        #[base = QObject]
+        type QWebSocketServer;
+
+        /// Closes the server. The server will no longer listen for incoming connections.
+        fn close(self: Pin<&mut QWebSocketServer>);
+
+        /// Returns an error code for the last error that occurred. If no error occurred, [`QWebSocketProtocolCloseCode::CloseCodeNormal`](crate::QWebSocketProtocolCloseCode::CloseCodeNormal) is returned.
+        fn error(self: &QWebSocketServer) -> QWebSocketProtocolCloseCode;
+
+        /// Returns a human readable description of the last error that occurred. If no error occurred, an empty string is returned.
+        #[rust_name = "error_string"]
+        fn errorString(self: &QWebSocketServer) -> QString;
+
+        #[doc(hidden)]
+        #[rust_name = "handshake_timeout_msecs"]
+        fn handshakeTimeoutMS(self: &QWebSocketServer) -> i32;
+
+        /// Returns `true` if the server has pending connections; otherwise returns `false`.
+        #[rust_name = "has_pending_connections"]
+        fn hasPendingConnections(self: &QWebSocketServer) -> bool;
+
+        /// Returns `true` if the server is currently listening for incoming connections.
+        #[rust_name = "is_listening"]
+        fn isListening(self: &QWebSocketServer) -> bool;
+
+        /// Tells the server to listen for incoming connections on address `address` and port `port`. If `port` is 0, a port is chosen automatically. If address is [`QHostAddressSpecialAddress::Any`](crate::QHostAddressSpecialAddress::Any), the server will listen on all network interfaces.
+        ///
+        /// Returns `true` on success; otherwise returns `false`.
+        fn listen(self: Pin<&mut QWebSocketServer>, address: &QHostAddress, port: u16) -> bool;
+
+        /// Returns the maximum number of pending accepted connections. The default is 30.
+        #[rust_name = "max_pending_connections"]
+        fn maxPendingConnections(self: &QWebSocketServer) -> i32;
+
+        #[doc(hidden)]
+        #[rust_name = "next_pending_connection_raw"]
+        fn nextPendingConnection(self: Pin<&mut QWebSocketServer>) -> *mut QWebSocket;
+
+        /// Pauses incoming new connections. Queued connections will remain in queue.
+        #[rust_name = "pause_accepting"]
+        fn pauseAccepting(self: Pin<&mut QWebSocketServer>);
+
+        /// Returns the network proxy for this server. By default [`QNetworkProxyProxyType::DefaultProxy`](crate::QNetworkProxyProxyType::DefaultProxy) is used.
+        fn proxy(self: &QWebSocketServer) -> QNetworkProxy;
+
+        /// Resumes accepting new connections.
+        #[rust_name = "resume_accepting"]
+        fn resumeAccepting(self: Pin<&mut QWebSocketServer>);
+
+        /// Returns the secure mode the server is running in.
+        #[rust_name = "secure_mode"]
+        fn secureMode(self: &QWebSocketServer) -> QWebSocketServerSslMode;
+
+        #[doc(hidden)]
+        #[rust_name = "server_address_or_null"]
+        fn serverAddress(self: &QWebSocketServer) -> QHostAddress;
+
+        /// Returns the server name that is used during the http handshake phase.
+        #[rust_name = "server_name"]
+        fn serverName(self: &QWebSocketServer) -> QString;
+
+        /// Returns the server's port if the server is listening for connections; otherwise returns 0.
+        #[rust_name = "server_port"]
+        fn serverPort(self: &QWebSocketServer) -> u16;
+
+        /// Returns a URL clients can use to connect to this server if the server is listening for connections. Otherwise an invalid URL is returned.
+        #[rust_name = "server_url"]
+        fn serverUrl(self: &QWebSocketServer) -> QUrl;
+
+        #[doc(hidden)]
+        #[rust_name = "set_handshake_timeout_msecs"]
+        fn setHandshakeTimeout(self: Pin<&mut QWebSocketServer>, msec: i32);
+
+        /// Sets the maximum number of pending accepted connections to `num_connections`. WebSocketServer will accept no more than `num_connections` incoming connections before [`next_pending_connection`](QWebSocketServer::next_pending_connection) is called. By default, the limit is 30 pending connections.
+        ///
+        /// `QWebSocketServer` will emit the [`server_error`](QWebSocketServer::server_error) signal with the [`QWebSocketProtocolCloseCode::CloseCodeAbnormalDisconnection`](crate::QWebSocketProtocolCloseCode::CloseCodeAbnormalDisconnection) close code when the maximum of connections has been reached. The WebSocket handshake will fail and the socket will be closed.
+        #[rust_name = "set_max_pending_connections"]
+        fn setMaxPendingConnections(self: Pin<&mut QWebSocketServer>, num_connections: i32);
+
+        /// Sets the explicit network proxy for this server to `network_proxy`.
+        ///
+        /// To disable the use of a proxy, use the [`QNetworkProxyProxyType::NoProxy`](crate::QNetworkProxyProxyType::NoProxy) proxy type.
+        #[rust_name = "set_proxy"]
+        fn setProxy(self: Pin<&mut QWebSocketServer>, network_proxy: &QNetworkProxy);
+
+        /// Sets the server name that will be used during the HTTP handshake phase to the given `server_name`. The `server_name` can be empty, in which case an empty server name will be sent to the client. Existing connected clients will not be notified of this change, only newly connecting clients will see this new name.
+        #[rust_name = "set_server_name"]
+        fn setServerName(self: Pin<&mut QWebSocketServer>, server_name: &QString);
+
+        #[doc(hidden)]
+        #[rust_name = "set_socket_descriptor_qintptr"]
+        fn setSocketDescriptor(
+            self: Pin<&mut QWebSocketServer>,
+            socket_descriptor: qintptr,
+        ) -> bool;
+
+        /// Sets the SSL configuration for the `QWebSocketServer` to `ssl_configuration`. This method has no effect if `QWebSocketServer` runs in non-secure mode ([`QWebSocketServerSslMode::NonSecureMode`]).
+        #[cfg(feature = "ssl")]
+        #[rust_name = "set_ssl_configuration"]
+        fn setSslConfiguration(
+            self: Pin<&mut QWebSocketServer>,
+            ssl_configuration: &QSslConfiguration,
+        );
+
+        #[doc(hidden)]
+        #[rust_name = "socket_descriptor_or_negative"]
+        fn socketDescriptor(self: &QWebSocketServer) -> qintptr;
+
+        /// Returns the SSL configuration used by the `QWebSocketServer`. If the server is not running in secure mode ([`QWebSocketServerSslMode::SecureMode`]), this method returns a default-constructed [`QSslConfiguration`].
+        #[cfg(feature = "ssl")]
+        #[rust_name = "ssl_configuration"]
+        fn sslConfiguration(self: &QWebSocketServer) -> QSslConfiguration;
+
+        /// This signal is emitted when accepting a new connection results in an error. The `socket_error` parameter describes the type of error that occurred.
+        #[qsignal]
+        #[rust_name = "accept_error"]
+        fn acceptError(self: Pin<&mut QWebSocketServer>, socket_error: QAbstractSocketSocketError);
+
+        /// This signal is emitted when the server closed its connection.
+        #[qsignal]
+        fn closed(self: Pin<&mut QWebSocketServer>);
+
+        /// This signal is emitted every time a new connection is available.
+        #[qsignal]
+        #[rust_name = "new_connection"]
+        fn newConnection(self: Pin<&mut QWebSocketServer>);
+
+        /// This signal is emitted when a new connection is requested. The slot connected to this signal should indicate whether the origin (which can be determined by [`QWebSocketCorsAuthenticator::origin`](crate::QWebSocketCorsAuthenticator::origin)) is allowed in the `authenticator` object (by issuing [`QWebSocketCorsAuthenticator::set_allowed`](crate::QWebSocketCorsAuthenticator::set_allowed)).
+        ///
+        /// If no slot is connected to this signal, all origins will be accepted by default.
+        ///
+        /// **Note:** It is not possible to use a [`ConnectionType::QueuedConnection`](cxx_qt::ConnectionType::QueuedConnection) to connect to this signal, as the connection will always succeed.
+        ///
+        /// # Safety
+        ///
+        /// `authenticator` must be valid.
+        #[qsignal]
+        #[rust_name = "origin_authentication_required"]
+        unsafe fn originAuthenticationRequired(
+            self: Pin<&mut QWebSocketServer>,
+            authenticator: *mut QWebSocketCorsAuthenticator,
+        );
+
+        /// `QWebSocketServer` can emit this signal several times during the SSL handshake, before encryption has been established, to indicate that an error has occurred while establishing the identity of the peer. The `error` is usually an indication that `QWebSocketServer` is unable to securely identify the peer.
+        ///
+        /// This signal provides you with an early indication when something's wrong. By connecting to this signal, you can manually choose to tear down the connection from inside the connected slot before the handshake has completed. If no action is taken, `QWebSocketServer` will proceed to emitting [`ssl_errors`](QWebSocketServer::ssl_errors).
+        #[cfg(feature = "ssl")]
+        #[qsignal]
+        #[rust_name = "peer_verify_error"]
+        fn peerVerifyError(self: Pin<&mut QWebSocketServer>, error: &QSslError);
+
+        /// `QWebSocketServer` emits this signal when it negotiates a PSK ciphersuite, and therefore a PSK authentication is then required.
+        ///
+        /// When using PSK, the client must send to the server a valid identity and a valid pre shared key, in order for the SSL handshake to continue. Applications can provide this information in a slot connected to this signal, by filling in the passed `authenticator` object according to their needs.
+        ///
+        /// **Note:** Ignoring this signal, or failing to provide the required credentials, will cause the handshake to fail, and therefore the connection to be aborted.
+        ///
+        /// **Note:** The `authenticator` object is owned by the socket and must not be deleted by the application.
+        #[cfg(feature = "ssl")]
+        #[qsignal]
+        #[rust_name = "pre_shared_key_authentication_required"]
+        unsafe fn preSharedKeyAuthenticationRequired(
+            self: Pin<&mut QWebSocketServer>,
+            authenticator: *mut QSslPreSharedKeyAuthenticator,
+        );
+
+        /// This signal is emitted when an error occurs during the setup of a WebSocket connection. The `close_code` parameter describes the type of error that occurred.
+        #[qsignal]
+        #[rust_name = "server_error"]
+        fn serverError(self: Pin<&mut QWebSocketServer>, close_code: QWebSocketProtocolCloseCode);
+
+        /// `QWebSocketServer` emits this signal after the SSL handshake to indicate that one or more errors have occurred while establishing the identity of the peer. The errors are usually an indication that `QWebSocketServer` is unable to securely identify the peer. Unless any action is taken, the connection will be dropped after this signal has been emitted.
+        ///
+        /// `errors` contains one or more errors that prevent `QWebSocketServer` from verifying the identity of the peer.
+        #[cfg(feature = "ssl")]
+        #[qsignal]
+        #[rust_name = "ssl_errors"]
+        fn sslErrors(self: Pin<&mut QWebSocketServer>, errors: &QList_QSslError);
+    }
+
+    #[namespace = "rust::cxxqtlib1"]
+    unsafe extern "C++" {
+        include!("cxx-qt-lib/common.h");
+
+        #[rust_name = "qwebsocketserver_new"]
+        fn make_unique(
+            server_name: &QString,
+            secure_mode: QWebSocketServerSslMode,
+        ) -> UniquePtr<QWebSocketServer>;
+    }
+}
+
+pub use ffi::{QWebSocketServer, QWebSocketServerSslMode};
+
+impl fmt::Debug for QWebSocketServer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        debug_qobject(f, self)
+    }
+}
+
+impl QWebSocketServer {
+    /// Constructs a new `QWebSocketServer` with the given `server_name`. The `server_name` will be used in the HTTP handshake phase to identify the server. It can be empty, in which case an empty server name will be sent to the client. The `secure_mode` parameter indicates whether the server operates over wss ([`QWebSocketServerSslMode::SecureMode`]) or over ws ([`QWebSocketServerSslMode::NonSecureMode`]).
+    pub fn new(server_name: &QString, secure_mode: QWebSocketServerSslMode) -> UniquePtr<Self> {
+        ffi::qwebsocketserver_new(server_name, secure_mode)
+    }
+
+    /// Returns the handshake timeout for new connections, or `None` if there is no timeout.
+    pub fn handshake_timeout(&self) -> Option<Duration> {
+        let msecs = u64::try_from(self.handshake_timeout_msecs()).ok()?;
+        Some(Duration::from_millis(msecs))
+    }
+
+    /// Returns the next pending connection as a connected [`QWebSocket`] object. `QWebSocketServer` does not take ownership of the returned [`QWebSocket`] object.
+    ///
+    /// A null pointer is returned if this function is called when there are no pending connections.
+    ///
+    /// **Note:** The returned [`QWebSocket`] object cannot be used from another thread.
+    pub fn next_pending_connection(self: Pin<&mut Self>) -> UniquePtr<QWebSocket> {
+        let conn = self.next_pending_connection_raw();
+        // SAFETY: `conn` is valid and Qt expects us to delete it when done.
+        unsafe { UniquePtr::from_raw(conn) }
+    }
+
+    /// Returns the server's address if the server is listening for connections; otherwise returns `None`.
+    pub fn server_address(&self) -> Option<QHostAddress> {
+        self.server_address_or_null().nonnull()
+    }
+
+    /// Sets the handshake timeout for new connections to `timeout`. If `timeout` is `None`, there is no timeout.
+    ///
+    /// This timeout is used to prevent clients from keeping connections alive without completing the handshake. By default, the timeout is 10 seconds.
+    pub fn set_handshake_timeout(self: Pin<&mut Self>, timeout: Option<Duration>) {
+        self.set_handshake_timeout_msecs(timeout.msecs());
+    }
+
+    /// Sets the socket descriptor this server should use when listening for incoming connections to `socket_descriptor`. Returns `true` if the socket is set successfully; otherwise returns `false`.
+    ///
+    /// The socket is assumed to be in listening state.
+    pub fn set_socket_descriptor(
+        self: Pin<&mut Self>,
+        socket_descriptor: SocketDescriptor,
+    ) -> bool {
+        self.set_socket_descriptor_qintptr(socket_descriptor.into())
+    }
+
+    /// Returns the native socket descriptor the server uses to listen for incoming instructions, or `None` if the server is not listening.
+    pub fn socket_descriptor(&self) -> Option<SocketDescriptor> {
+        SocketDescriptor::from(self.socket_descriptor_or_negative()).nonnull()
+    }
+}
+
+impl Deref for QWebSocketServer {
+    type Target = QObject;
+
+    fn deref(&self) -> &Self::Target {
+        self.upcast()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{QHostAddressSpecialAddress, QNetworkProxy};
+
+    #[test]
+    fn props() {
+        #[derive(Debug, PartialEq, Eq)]
+        struct QWebSocketServerProps {
+            handshake_timeout: Option<Duration>,
+            max_pending_connections: i32,
+            proxy: QNetworkProxy,
+            server_name: QString,
+        }
+
+        let mut proxy = QNetworkProxy::default();
+        proxy.set_host_name(&QString::from("host"));
+
+        let props = QWebSocketServerProps {
+            handshake_timeout: Some(Duration::from_secs(3)),
+            max_pending_connections: 15,
+            proxy,
+            server_name: QString::from("server"),
+        };
+
+        let mut server =
+            QWebSocketServer::new(&QString::default(), QWebSocketServerSslMode::NonSecureMode);
+
+        server
+            .pin_mut()
+            .set_handshake_timeout(props.handshake_timeout);
+        server
+            .pin_mut()
+            .set_max_pending_connections(props.max_pending_connections);
+        server.pin_mut().set_proxy(&props.proxy);
+        server.pin_mut().set_server_name(&props.server_name);
+
+        let actual_props = QWebSocketServerProps {
+            handshake_timeout: server.handshake_timeout(),
+            max_pending_connections: server.max_pending_connections(),
+            proxy: server.proxy(),
+            server_name: server.server_name(),
+        };
+
+        assert_eq!(actual_props, props);
+    }
+
+    #[test]
+    fn listen() {
+        let mut server =
+            QWebSocketServer::new(&QString::default(), QWebSocketServerSslMode::NonSecureMode);
+        let address = QHostAddress::from(QHostAddressSpecialAddress::LocalHost);
+        assert!(server.pin_mut().listen(&address, 0));
+        assert_eq!(
+            (
+                server.secure_mode(),
+                server.server_address(),
+                server.has_pending_connections(),
+                server.server_url().scheme().to_string(),
+            ),
+            (
+                QWebSocketServerSslMode::NonSecureMode,
+                Some(address),
+                false,
+                "ws".to_owned(),
+            )
+        );
+    }
+}
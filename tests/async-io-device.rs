@@ -0,0 +1,184 @@
+#![cfg(all(feature = "futures_io", feature = "net"))]
+mod common;
+use std::future::{Future, poll_fn};
+use std::io;
+use std::pin::{Pin, pin};
+use std::task::Poll;
+use std::time::Duration;
+
+use common::{ConnectErrors, block_on, run_inside_app};
+use cxx_qt_io::{
+    AsyncIODevice, QHostAddressSpecialAddress, QIODevice, QLocalServer, QLocalSocket, QTcpServer,
+    QTcpSocket,
+};
+use cxx_qt_lib::QString;
+use futures_io::{AsyncRead, AsyncWrite};
+
+const SERVER_NAME: &str = "cxx-qt-io-async-round-trip";
+const PORT: u16 = 8013;
+const PAYLOAD_LEN: usize = 1024 * 1024;
+const TIMEOUT: Option<Duration> = Some(Duration::from_secs(500));
+
+#[test]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+fn async_local_round_trip() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut server_ptr = QLocalServer::new();
+        let mut socket_ptr = QLocalSocket::new();
+        let mut server = server_ptr.pin_mut();
+        let mut client_socket = socket_ptr.pin_mut();
+        client_socket.as_mut().connect_errors("client_socket");
+
+        let name = QString::from(SERVER_NAME);
+
+        QLocalServer::remove_server(&name);
+        assert!(server.as_mut().listen(&name), "failed to listen");
+
+        client_socket
+            .as_mut()
+            .connect_to_server(&name, QIODevice::ReadWrite);
+
+        assert!(
+            server.as_mut().wait_for_new_connection(TIMEOUT),
+            "failed to acquire connection"
+        );
+
+        let mut server_socket_ptr = server.as_mut().next_pending_connection();
+        let mut server_socket = server_socket_ptr.as_mut().expect("received null socket");
+        server_socket.as_mut().connect_errors("server_socket");
+
+        client_socket.as_mut().wait_for_connected(TIMEOUT);
+
+        round_trip(
+            AsyncIODevice::new(client_socket.as_io_device_mut()),
+            AsyncIODevice::new(server_socket.as_io_device_mut()),
+        );
+    });
+}
+
+#[test]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+fn async_tcp_round_trip() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut server_ptr = QTcpServer::new();
+        let mut socket_ptr = QTcpSocket::new();
+        let mut server = server_ptr.pin_mut();
+        let mut client_socket = socket_ptr.pin_mut();
+        server.as_mut().connect_errors("server");
+        client_socket.as_mut().connect_errors("client_socket");
+
+        let addr = QHostAddressSpecialAddress::LocalHost.into();
+
+        server.as_mut().listen(&addr, PORT);
+
+        client_socket
+            .as_abstract_socket_mut()
+            .connect_to_host((addr, PORT), QIODevice::ReadWrite);
+
+        assert!(
+            server.as_mut().wait_for_new_connection(TIMEOUT),
+            "failed to acquire connection"
+        );
+
+        let mut server_socket_ptr = server.as_mut().next_pending_connection();
+        let mut server_socket = server_socket_ptr.as_mut().expect("received null socket");
+        server_socket.as_mut().connect_errors("server_socket");
+
+        client_socket
+            .as_abstract_socket_mut()
+            .wait_for_connected(TIMEOUT);
+
+        round_trip(
+            AsyncIODevice::new(client_socket.as_io_device_mut()),
+            AsyncIODevice::new(server_socket.as_io_device_mut()),
+        );
+    });
+}
+
+#[allow(clippy::unwrap_used)]
+fn round_trip(mut client: AsyncIODevice<'_>, mut server: AsyncIODevice<'_>) {
+    let request: Vec<u8> = (0..=u8::MAX).cycle().take(PAYLOAD_LEN).collect();
+
+    let (written, received) = block_on(join(
+        write_all(&mut client, &request),
+        read_exact(&mut server, PAYLOAD_LEN),
+    ));
+    written.unwrap();
+    assert_eq!(received.unwrap(), request);
+
+    let (written, received) = block_on(join(
+        async {
+            write_all(&mut server, b"response").await?;
+            poll_fn(|cx| Pin::new(&mut server).poll_close(cx)).await
+        },
+        read_to_end(&mut client),
+    ));
+    written.unwrap();
+    assert_eq!(received.unwrap(), b"response");
+}
+
+async fn write_all(device: &mut AsyncIODevice<'_>, mut buf: &[u8]) -> io::Result<()> {
+    while !buf.is_empty() {
+        let n = poll_fn(|cx| Pin::new(&mut *device).poll_write(cx, buf)).await?;
+        if n == 0 {
+            return Err(io::ErrorKind::WriteZero.into());
+        }
+        buf = &buf[n..];
+    }
+    poll_fn(|cx| Pin::new(&mut *device).poll_flush(cx)).await
+}
+
+async fn read_exact(device: &mut AsyncIODevice<'_>, len: usize) -> io::Result<Vec<u8>> {
+    let mut buf = vec![0; len];
+    let mut filled = 0;
+    while filled < len {
+        let n = poll_fn(|cx| Pin::new(&mut *device).poll_read(cx, &mut buf[filled..])).await?;
+        if n == 0 {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        filled += n;
+    }
+    Ok(buf)
+}
+
+async fn read_to_end(device: &mut AsyncIODevice<'_>) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
+    let mut chunk = [0; 1024];
+    loop {
+        let n = poll_fn(|cx| Pin::new(&mut *device).poll_read(cx, &mut chunk)).await?;
+        if n == 0 {
+            return Ok(buf);
+        }
+        buf.extend_from_slice(&chunk[..n]);
+    }
+}
+
+#[allow(clippy::unwrap_used)]
+async fn join<A: Future, B: Future>(a: A, b: B) -> (A::Output, B::Output) {
+    let mut a = pin!(a);
+    let mut b = pin!(b);
+    let mut a_output = None;
+    let mut b_output = None;
+    poll_fn(|cx| {
+        if a_output.is_none()
+            && let Poll::Ready(output) = a.as_mut().poll(cx)
+        {
+            a_output = Some(output);
+        }
+        if b_output.is_none()
+            && let Poll::Ready(output) = b.as_mut().poll(cx)
+        {
+            b_output = Some(output);
+        }
+        if a_output.is_some() && b_output.is_some() {
+            Poll::Ready((a_output.take().unwrap(), b_output.take().unwrap()))
+        } else {
+            Poll::Pending
+        }
+    })
+    .await
+}
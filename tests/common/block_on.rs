@@ -0,0 +1,23 @@
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+use std::time::{Duration, Instant};
+
+use cxx_qt_lib_extras::QEventLoop;
+
+const TIMEOUT: Duration = Duration::from_secs(500);
+
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    let mut event_loop_ptr = QEventLoop::new();
+    let mut event_loop = event_loop_ptr.pin_mut();
+    let now = Instant::now();
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        assert!(now.elapsed() < TIMEOUT, "future timed out");
+        event_loop.as_mut().process_all_events();
+    }
+}
@@ -91,3 +91,13 @@ impl ConnectErrors for cxx_qt_io::QSslSocket {
         self.as_tcp_socket_mut().connect_errors(context);
     }
 }
+
+#[cfg(feature = "net")]
+impl ConnectErrors for cxx_qt_io::QLocalSocket {
+    fn connect_errors(self: Pin<&mut Self>, context: &'static str) {
+        self.on_error_occurred(move |_, error| {
+            eprintln!("[{context}] QLocalSocket::error_occurred: {error}");
+        })
+        .release();
+    }
+}
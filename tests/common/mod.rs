@@ -1,5 +1,8 @@
 #![allow(unused)]
 
+mod block_on;
+pub use block_on::block_on;
+
 mod connect_errors;
 pub use connect_errors::ConnectErrors;
 
@@ -0,0 +1,43 @@
+#![cfg(feature = "net")]
+mod common;
+use std::cell::RefCell;
+use std::net::IpAddr;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+use common::run_inside_app;
+use cxx_qt_io::{QHostInfo, QHostInfoHostInfoError};
+use cxx_qt_lib::QString;
+use cxx_qt_lib_extras::QEventLoop;
+
+const TIMEOUT: Duration = Duration::from_secs(500);
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn lookup_localhost() {
+    init_crates!();
+    run_inside_app(|| {
+        let result: Rc<RefCell<Option<(QHostInfoHostInfoError, Vec<IpAddr>)>>> = Rc::default();
+        {
+            let result = result.clone();
+            QHostInfo::lookup_host(&QString::from("localhost"), move |info| {
+                *result.borrow_mut() = Some((info.error(), info.ip_addresses()));
+            });
+        }
+
+        let now = Instant::now();
+        let mut event_loop_ptr = QEventLoop::new();
+        let mut event_loop = event_loop_ptr.pin_mut();
+        while result.borrow().is_none() {
+            assert!(now.elapsed() < TIMEOUT, "timed out waiting for lookup");
+            event_loop.as_mut().process_all_events();
+        }
+
+        let (error, addresses) = result.borrow_mut().take().unwrap();
+        assert_eq!(error, QHostInfoHostInfoError::NoError);
+        assert!(
+            addresses.iter().any(IpAddr::is_loopback),
+            "no loopback address in {addresses:?}"
+        );
+    });
+}
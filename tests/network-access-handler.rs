@@ -0,0 +1,413 @@
+#![cfg(feature = "request")]
+mod common;
+use std::cell::Cell;
+use std::collections::{HashMap, VecDeque};
+use std::future::IntoFuture;
+use std::io::{self, Read};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use common::{block_on, run_inside_app};
+use cxx::UniquePtr;
+use cxx_qt_io::{
+    HttpResponse, MockResponse, NetworkCacheBackend, QBuffer, QIODeviceOpenModeFlag,
+    QNetworkCacheMetaData, QNetworkReplyNetworkError, QNetworkRequest, QNetworkRequestAttribute,
+    QNetworkRequestCacheLoadControl, RustIODevice, RustNetworkAccessManager, RustNetworkCache,
+};
+use cxx_qt_lib::{QByteArray, QString, QUrl};
+
+const DELAY: Duration = Duration::from_millis(200);
+const STREAM_LEN: usize = 40 * 1024;
+
+/// Yields its chunks one read at a time, failing with `WouldBlock` between them.
+struct ChunkedReader {
+    chunks: VecDeque<Vec<u8>>,
+    blocked: bool,
+}
+
+impl Read for ChunkedReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.blocked = !self.blocked;
+        if self.blocked {
+            return Err(io::ErrorKind::WouldBlock.into());
+        }
+        let Some(mut chunk) = self.chunks.pop_front() else {
+            return Ok(0);
+        };
+        let len = chunk.len().min(buf.len());
+        buf[..len].copy_from_slice(&chunk[..len]);
+        if len < chunk.len() {
+            self.chunks.push_front(chunk.split_off(len));
+        }
+        Ok(len)
+    }
+}
+
+fn new_manager() -> UniquePtr<RustNetworkAccessManager> {
+    RustNetworkAccessManager::new(|_, request: &QNetworkRequest, _: &[u8]| {
+        match String::from(&request.url().path()).as_str() {
+            "/ok" => Some(
+                MockResponse::new()
+                    .status(200)
+                    .reason("OK")
+                    .header("Content-Type", "text/plain")
+                    .header("X-Request-Id", "42")
+                    .body("test message"),
+            ),
+            "/missing" => Some(
+                MockResponse::new()
+                    .status(404)
+                    .reason("Not Found")
+                    .body("no such resource")
+                    .error(QNetworkReplyNetworkError::ContentNotFoundError, "not found"),
+            ),
+            "/slow" => Some(MockResponse::new().status(200).delay(DELAY)),
+            _ => None,
+        }
+    })
+}
+
+fn get(manager: &mut UniquePtr<RustNetworkAccessManager>, url: &str) -> HttpResponse {
+    let request = QNetworkRequest::from(&QUrl::from(url));
+    let mut manager = manager.pin_mut();
+    let reply = manager.as_network_access_manager_mut().get(&request);
+    block_on(reply.into_future())
+}
+
+#[test]
+fn mock_response() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager = new_manager();
+        let response = get(&mut manager, "http://example.test/ok");
+
+        assert_eq!(
+            (
+                response.error(),
+                response.status(),
+                response.reason(),
+                response.headers(),
+                response.body(),
+            ),
+            (
+                QNetworkReplyNetworkError::NoError,
+                Some(200),
+                Some("OK"),
+                &[
+                    (b"Content-Type".to_vec(), b"text/plain".to_vec()),
+                    (b"X-Request-Id".to_vec(), b"42".to_vec()),
+                ][..],
+                &b"test message"[..],
+            )
+        );
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn mock_error() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager_ptr = new_manager();
+        let request = QNetworkRequest::from(&QUrl::from("http://example.test/missing"));
+        let mut manager = manager_ptr.pin_mut();
+        let mut reply = manager.as_network_access_manager_mut().get(&request);
+
+        let emitted = Arc::new(Mutex::new(Vec::new()));
+        let signal_emitted = Arc::clone(&emitted);
+        reply
+            .as_mut()
+            .on_error_occurred(move |_, code| signal_emitted.lock().unwrap().push(code))
+            .release();
+
+        let response = block_on(reply.as_mut().into_future());
+
+        assert_eq!(
+            (
+                response.error(),
+                response.status(),
+                response.reason(),
+                response.body(),
+            ),
+            (
+                QNetworkReplyNetworkError::ContentNotFoundError,
+                Some(404),
+                Some("Not Found"),
+                &b"no such resource"[..],
+            )
+        );
+        assert_eq!(
+            (
+                reply.error(),
+                reply.as_io_device().error_string(),
+                emitted.lock().unwrap().clone(),
+            ),
+            (
+                QNetworkReplyNetworkError::ContentNotFoundError,
+                QString::from("not found"),
+                vec![QNetworkReplyNetworkError::ContentNotFoundError],
+            )
+        );
+    });
+}
+
+#[test]
+fn mock_delay() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager = new_manager();
+        let start = Instant::now();
+        let response = get(&mut manager, "http://example.test/slow");
+        let elapsed = start.elapsed();
+
+        assert_eq!(response.status(), Some(200));
+        assert!(elapsed >= DELAY, "response delivered after {elapsed:?}");
+    });
+}
+
+#[test]
+fn scheme_handler() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager = RustNetworkAccessManager::new(|_, _: &QNetworkRequest, _: &[u8]| None);
+        manager
+            .pin_mut()
+            .register_scheme("app", |_, request: &QNetworkRequest, _: &[u8]| {
+                let path = String::from(&request.url().path());
+                Some(MockResponse::new().body(format!("resource {path}")))
+            });
+
+        let response = get(&mut manager, "app:/index.html");
+
+        assert_eq!(
+            (response.error(), response.text().into_owned()),
+            (
+                QNetworkReplyNetworkError::NoError,
+                "resource /index.html".to_owned()
+            )
+        );
+    });
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn streaming_body() {
+    init_crates!();
+    run_inside_app(|| {
+        let payload: Vec<u8> = (0..=u8::MAX).cycle().take(STREAM_LEN).collect();
+        let chunks: VecDeque<Vec<u8>> = payload.chunks(10_000).map(<[u8]>::to_vec).collect();
+        let mut manager_ptr =
+            RustNetworkAccessManager::new(|_, _: &QNetworkRequest, _: &[u8]| None);
+        manager_ptr
+            .pin_mut()
+            .register_scheme("app", move |_, _: &QNetworkRequest, _: &[u8]| {
+                Some(MockResponse::new().body_reader(ChunkedReader {
+                    chunks: chunks.clone(),
+                    blocked: false,
+                }))
+            });
+
+        let request = QNetworkRequest::from(&QUrl::from("app:/stream"));
+        let mut manager = manager_ptr.pin_mut();
+        let mut reply = manager.as_network_access_manager_mut().get(&request);
+
+        let progress = Arc::new(Mutex::new(Vec::new()));
+        let signal_progress = Arc::clone(&progress);
+        reply
+            .as_mut()
+            .on_download_progress(move |_, received, total| {
+                signal_progress.lock().unwrap().push((received, total));
+            })
+            .release();
+
+        let response = block_on(reply.into_future());
+        let progress = progress.lock().unwrap();
+        let len = i64::try_from(STREAM_LEN).unwrap();
+
+        assert_eq!(response.error(), QNetworkReplyNetworkError::NoError);
+        assert_eq!(response.body(), payload);
+        assert!(progress.len() > 2, "expected progress for each chunk");
+        assert_eq!(progress.last(), Some(&(len, len)));
+    });
+}
+
+fn echo_manager() -> UniquePtr<RustNetworkAccessManager> {
+    let mut manager = RustNetworkAccessManager::new(|_, _: &QNetworkRequest, _: &[u8]| None);
+    manager
+        .pin_mut()
+        .register_scheme("app", |_, _: &QNetworkRequest, body: &[u8]| {
+            Some(MockResponse::new().body(body))
+        });
+    manager
+}
+
+#[test]
+fn sequential_upload() {
+    init_crates!();
+    run_inside_app(|| {
+        let payload: Vec<u8> = (0..=u8::MAX).cycle().take(STREAM_LEN).collect();
+        let mut manager_ptr = echo_manager();
+        let mut device = RustIODevice::from_reader(io::Cursor::new(payload.clone()));
+
+        let request = QNetworkRequest::from(&QUrl::from("app:/upload"));
+        let mut manager = manager_ptr.pin_mut();
+        let reply = manager
+            .as_network_access_manager_mut()
+            .post(&request, device.pin_mut());
+        let response = block_on(reply.into_future());
+
+        assert_eq!(
+            (response.error(), response.body()),
+            (QNetworkReplyNetworkError::NoError, payload.as_slice())
+        );
+    });
+}
+
+#[test]
+fn random_access_upload() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager_ptr = echo_manager();
+        let mut buffer_ptr = QBuffer::new();
+        let mut buffer = buffer_ptr.pin_mut();
+        buffer.as_mut().set_data("skipped test message");
+        buffer
+            .as_io_device_mut()
+            .open(QIODeviceOpenModeFlag::ReadOnly.into());
+        buffer.as_io_device_mut().seek(8);
+
+        let request = QNetworkRequest::from(&QUrl::from("app:/upload"));
+        let mut manager = manager_ptr.pin_mut();
+        let reply = manager
+            .as_network_access_manager_mut()
+            .post(&request, buffer);
+        let response = block_on(reply.into_future());
+
+        assert_eq!(
+            (response.error(), response.body()),
+            (QNetworkReplyNetworkError::NoError, &b"test message"[..])
+        );
+    });
+}
+
+#[test]
+fn cookie_round_trip() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager = RustNetworkAccessManager::new(|_, _: &QNetworkRequest, _: &[u8]| None);
+        manager
+            .pin_mut()
+            .register_scheme("app", |_, request: &QNetworkRequest, _: &[u8]| {
+                if String::from(&request.url().path()) == "/login" {
+                    return Some(MockResponse::new().header("Set-Cookie", "session=1234; Path=/"));
+                }
+                let cookie = request.raw_header(&QByteArray::from("Cookie"));
+                Some(MockResponse::new().body(cookie.as_slice()))
+            });
+
+        get(&mut manager, "app://example.com/login");
+        let response = get(&mut manager, "app://example.com/profile");
+
+        assert_eq!(response.text(), "session=1234");
+    });
+}
+
+/// Stores cache entries in memory, keyed by URL.
+#[derive(Default)]
+struct MemoryCache {
+    entries: HashMap<String, (QNetworkCacheMetaData, Vec<u8>)>,
+}
+
+impl NetworkCacheBackend for MemoryCache {
+    fn meta_data(&mut self, url: &QUrl) -> Option<QNetworkCacheMetaData> {
+        let (meta_data, _) = self.entries.get(&url.to_string())?;
+        Some(meta_data.clone())
+    }
+
+    fn update_meta_data(&mut self, meta_data: &QNetworkCacheMetaData) {
+        if let Some(entry) = self.entries.get_mut(&meta_data.url().to_string()) {
+            entry.0 = meta_data.clone();
+        }
+    }
+
+    fn data(&mut self, url: &QUrl) -> Option<Vec<u8>> {
+        let (_, data) = self.entries.get(&url.to_string())?;
+        Some(data.clone())
+    }
+
+    fn insert(&mut self, meta_data: QNetworkCacheMetaData, data: Vec<u8>) {
+        self.entries
+            .insert(meta_data.url().to_string(), (meta_data, data));
+    }
+
+    fn remove(&mut self, url: &QUrl) -> bool {
+        self.entries.remove(&url.to_string()).is_some()
+    }
+
+    fn cache_size(&self) -> i64 {
+        self.entries
+            .values()
+            .map(|(_, data)| i64::try_from(data.len()).unwrap_or(i64::MAX))
+            .sum()
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[test]
+fn cache_hit() {
+    init_crates!();
+    run_inside_app(|| {
+        let calls = Rc::new(Cell::new(0));
+        let handler_calls = Rc::clone(&calls);
+        let mut manager_ptr =
+            RustNetworkAccessManager::new(|_, _: &QNetworkRequest, _: &[u8]| None);
+        manager_ptr
+            .pin_mut()
+            .register_scheme("app", move |_, _: &QNetworkRequest, _: &[u8]| {
+                handler_calls.set(handler_calls.get() + 1);
+                Some(MockResponse::new().status(200).body("test message"))
+            });
+        let mut manager = manager_ptr.pin_mut();
+        manager
+            .as_network_access_manager_mut()
+            .set_cache(RustNetworkCache::new(MemoryCache::default()));
+
+        let mut request = QNetworkRequest::from(&QUrl::from("app://example.com/resource"));
+        let first = block_on(
+            manager
+                .as_network_access_manager_mut()
+                .get(&request)
+                .into_future(),
+        );
+        request.set_attribute(
+            QNetworkRequestAttribute::CacheLoadControlAttribute,
+            &QNetworkRequestCacheLoadControl::PreferCache.repr,
+        );
+        let mut reply = manager.as_network_access_manager_mut().get(&request);
+        let second = block_on(reply.as_mut().into_future());
+        let from_cache = reply
+            .attribute(QNetworkRequestAttribute::SourceIsFromCacheAttribute)
+            .and_then(|value| value.value::<bool>());
+
+        assert_eq!(
+            (
+                first.text(),
+                second.status(),
+                second.text(),
+                from_cache,
+                calls.get()
+            ),
+            (
+                "test message".into(),
+                Some(200),
+                "test message".into(),
+                Some(true),
+                1
+            )
+        );
+    });
+}
@@ -0,0 +1,38 @@
+#![cfg(feature = "net")]
+mod common;
+use common::run_inside_app;
+use cxx_qt_io::{QNetworkProxy, QNetworkProxyFactory, QNetworkProxyProxyType, QNetworkProxyQuery};
+use cxx_qt_lib::{QList, QUrl};
+
+// Installing an application-wide proxy factory affects every other test in the same process, so this test gets its own binary.
+#[test]
+fn set_application_proxy_factory() {
+    init_crates!();
+    run_inside_app(|| {
+        QNetworkProxyFactory::set_application_proxy_factory(|query: &QNetworkProxyQuery| {
+            let proxy_type = if String::from(&query.peer_host_name()) == "localhost" {
+                QNetworkProxyProxyType::NoProxy
+            } else {
+                QNetworkProxyProxyType::Socks5Proxy
+            };
+            let mut proxies = QList::default();
+            proxies.append(QNetworkProxy::from(proxy_type));
+            proxies
+        });
+        let query_type = |url: &str| {
+            let query = QNetworkProxyQuery::from(&QUrl::from(url));
+            let proxies = QNetworkProxyFactory::proxy_for_query(&query);
+            proxies.get(0).map(QNetworkProxy::proxy_type)
+        };
+        let local = query_type("http://localhost/");
+        let remote = query_type("http://example.com/");
+        QNetworkProxyFactory::clear_application_proxy_factory();
+        assert_eq!(
+            (local, remote),
+            (
+                Some(QNetworkProxyProxyType::NoProxy),
+                Some(QNetworkProxyProxyType::Socks5Proxy)
+            )
+        );
+    });
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "request")]
+mod common;
+use std::future::IntoFuture;
+
+use common::{block_on, run_inside_app};
+use cxx_qt_io::{QNetworkAccessManager, QNetworkReplyNetworkError, QNetworkRequest};
+use cxx_qt_lib::QUrl;
+
+#[test]
+fn await_reply() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager = QNetworkAccessManager::new();
+        let url = QUrl::from("data:text/plain,test%20message");
+        let request = QNetworkRequest::from(&url);
+
+        let response = block_on(async { manager.pin_mut().get(&request).await });
+
+        assert_eq!(
+            (response.error(), response.text().into_owned()),
+            (
+                QNetworkReplyNetworkError::NoError,
+                "test message".to_owned()
+            )
+        );
+    });
+}
+
+#[test]
+fn await_destroyed_reply() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut manager = QNetworkAccessManager::new();
+        let url = QUrl::from("data:text/plain,test%20message");
+        let request = QNetworkRequest::from(&url);
+
+        let future = manager.pin_mut().get(&request).into_future();
+        drop(manager);
+        let response = block_on(future);
+
+        assert_eq!(
+            (response.error(), response.body(), response.url()),
+            (
+                QNetworkReplyNetworkError::OperationCanceledError,
+                &[][..],
+                &url
+            )
+        );
+    });
+}
@@ -0,0 +1,91 @@
+mod common;
+use std::io::Write;
+use std::time::Duration;
+
+use common::run_inside_app;
+use cxx_qt_io::{QIODevice, QProcess, QProcessExitStatus};
+use cxx_qt_lib::{QList, QString, QStringList};
+
+const TIMEOUT: Option<Duration> = Some(Duration::from_secs(500));
+
+fn string_list(items: &[&str]) -> QStringList {
+    let mut list = QList::default();
+    for item in items {
+        list.append(QString::from(*item));
+    }
+    QStringList::from(&list)
+}
+
+#[test]
+fn echo() {
+    init_crates!();
+    run_inside_app(|| {
+        #[cfg(windows)]
+        let (program, arguments) = ("cmd", ["/C", "echo", "test message"].as_slice());
+        #[cfg(not(windows))]
+        let (program, arguments) = ("echo", ["test message"].as_slice());
+
+        let mut process_ptr = QProcess::new();
+        let mut process = process_ptr.pin_mut();
+        process.as_mut().start(
+            &QString::from(program),
+            &string_list(arguments),
+            QIODevice::ReadOnly,
+        );
+
+        assert!(
+            process.as_mut().wait_for_finished(TIMEOUT),
+            "process did not finish"
+        );
+
+        let output = process.as_mut().read_all_standard_output();
+        assert_eq!(
+            (
+                String::from_utf8_lossy(output.as_slice()).trim_end(),
+                process.exit_status(),
+                process.exit_code(),
+            ),
+            ("test message", QProcessExitStatus::NormalExit, 0)
+        );
+    });
+}
+
+#[cfg(unix)]
+#[test]
+#[allow(clippy::unwrap_used)]
+fn write_stdin() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut process_ptr = QProcess::new();
+        let mut process = process_ptr.pin_mut();
+        process.as_mut().start(
+            &QString::from("cat"),
+            &QStringList::default(),
+            QIODevice::ReadWrite,
+        );
+        assert!(
+            process.as_mut().wait_for_started(TIMEOUT),
+            "process did not start"
+        );
+
+        process.write_all(b"test message").unwrap();
+        process.flush().unwrap();
+        assert_eq!(process.bytes_to_write(), 0);
+        process.as_mut().close_write_channel();
+
+        assert!(
+            process.as_mut().wait_for_finished(TIMEOUT),
+            "process did not finish"
+        );
+
+        let output = process.as_mut().read_all_standard_output();
+        assert_eq!(
+            (
+                output.as_slice(),
+                process.exit_status(),
+                process.exit_code(),
+            ),
+            (&b"test message"[..], QProcessExitStatus::NormalExit, 0)
+        );
+    });
+}
@@ -0,0 +1,143 @@
+mod common;
+use std::io;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use common::run_inside_app;
+use cxx_qt_io::{IODeviceBackend, QIODeviceOpenModeFlag, RustIODevice};
+use cxx_qt_lib_extras::QEventLoop;
+
+const TIMEOUT: Duration = Duration::from_secs(500);
+
+struct ChannelBackend {
+    receiver: Receiver<Vec<u8>>,
+    pending: Vec<u8>,
+    at_end: bool,
+}
+
+impl IODeviceBackend for ChannelBackend {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        if self.pending.is_empty() {
+            match self.receiver.try_recv() {
+                Ok(data) => self.pending = data,
+                Err(TryRecvError::Empty) => return Err(io::ErrorKind::WouldBlock.into()),
+                Err(TryRecvError::Disconnected) => {
+                    self.at_end = true;
+                    return Ok(0);
+                }
+            }
+        }
+        let n = buf.len().min(self.pending.len());
+        buf[..n].copy_from_slice(&self.pending[..n]);
+        self.pending.drain(..n);
+        Ok(n)
+    }
+
+    fn at_end(&self) -> bool {
+        self.at_end
+    }
+}
+
+#[test]
+#[allow(clippy::unwrap_used)]
+fn read_after_would_block() {
+    init_crates!();
+    run_inside_app(|| {
+        let (sender, receiver) = mpsc::channel();
+        let mut device_ptr = RustIODevice::new(ChannelBackend {
+            receiver,
+            pending: Vec::new(),
+            at_end: false,
+        });
+        let mut device = device_ptr.pin_mut();
+        assert!(
+            device
+                .as_io_device_mut()
+                .open(QIODeviceOpenModeFlag::ReadOnly.into()),
+            "failed to open device"
+        );
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let finished = Arc::new(AtomicBool::new(false));
+        {
+            let received = received.clone();
+            device
+                .as_io_device_mut()
+                .on_ready_read(move |device| {
+                    received
+                        .lock()
+                        .unwrap()
+                        .extend_from_slice(device.read_all().as_slice());
+                })
+                .release();
+        }
+        {
+            let finished = finished.clone();
+            device
+                .as_io_device_mut()
+                .on_read_channel_finished(move |_| finished.store(true, Ordering::SeqCst))
+                .release();
+        }
+
+        let mut buf = [0; 16];
+        let initial = device.as_io_device_mut().read(&mut buf).unwrap();
+
+        let notifier = device.notifier();
+        let producer = thread::spawn(move || {
+            sender.send(b"test message".to_vec()).unwrap();
+            notifier.ready_read();
+            drop(sender);
+            notifier.read_channel_finished();
+        });
+
+        let now = Instant::now();
+        let mut event_loop_ptr = QEventLoop::new();
+        let mut event_loop = event_loop_ptr.pin_mut();
+        while !finished.load(Ordering::SeqCst) {
+            assert!(now.elapsed() < TIMEOUT, "timed out waiting for data");
+            event_loop.as_mut().process_all_events();
+        }
+        producer.join().unwrap();
+
+        assert_eq!(
+            (initial, received.lock().unwrap().as_slice()),
+            (0, &b"test message"[..])
+        );
+    });
+}
+
+#[test]
+fn read_channel_finished_at_end() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut device_ptr = RustIODevice::from_reader(&b"test message"[..]);
+        let mut device = device_ptr.pin_mut();
+
+        let finished = Arc::new(AtomicBool::new(false));
+        {
+            let finished = finished.clone();
+            device
+                .as_io_device_mut()
+                .on_read_channel_finished(move |_| finished.store(true, Ordering::SeqCst))
+                .release();
+        }
+
+        let data = device.as_io_device_mut().read_all();
+
+        let now = Instant::now();
+        let mut event_loop_ptr = QEventLoop::new();
+        let mut event_loop = event_loop_ptr.pin_mut();
+        while !finished.load(Ordering::SeqCst) {
+            assert!(
+                now.elapsed() < TIMEOUT,
+                "timed out waiting for end of stream"
+            );
+            event_loop.as_mut().process_all_events();
+        }
+
+        assert_eq!(data.as_slice(), b"test message");
+    });
+}
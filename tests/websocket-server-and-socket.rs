@@ -0,0 +1,125 @@
+#![cfg(feature = "websockets")]
+mod common;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use common::run_inside_app;
+use cxx_qt_io::{
+    QAbstractSocketSocketState, QHostAddress, QHostAddressSpecialAddress, QWebSocket,
+    QWebSocketServer, QWebSocketServerSslMode,
+};
+use cxx_qt_lib::{QByteArray, QString};
+use cxx_qt_lib_extras::QEventLoop;
+
+const TIMEOUT: Duration = Duration::from_secs(500);
+
+#[test]
+#[allow(clippy::expect_used)]
+#[allow(clippy::unwrap_used)]
+fn websocket_round_trip() {
+    init_crates!();
+    run_inside_app(|| {
+        let mut server_ptr = QWebSocketServer::new(
+            &QString::from("cxx-qt-io-echo"),
+            QWebSocketServerSslMode::NonSecureMode,
+        );
+        let mut server = server_ptr.pin_mut();
+        let address = QHostAddress::from(QHostAddressSpecialAddress::LocalHost);
+        assert!(server.as_mut().listen(&address, 0), "failed to listen");
+
+        let mut client_socket_ptr = QWebSocket::new();
+        let mut client_socket = client_socket_ptr.pin_mut();
+
+        let text_received = Arc::new(Mutex::new(None));
+        let binary_received = Arc::new(Mutex::new(None));
+        {
+            let text_received = text_received.clone();
+            client_socket
+                .as_mut()
+                .on_text_message_received(move |_, message| {
+                    *text_received.lock().unwrap() = Some(message.to_string());
+                })
+                .release();
+        }
+        {
+            let binary_received = binary_received.clone();
+            client_socket
+                .as_mut()
+                .on_binary_message_received(move |_, message| {
+                    *binary_received.lock().unwrap() = Some(message.as_slice().to_vec());
+                })
+                .release();
+        }
+
+        client_socket.as_mut().open(&server.server_url());
+
+        assert!(
+            wait_until(|| server.has_pending_connections()),
+            "failed to acquire connection"
+        );
+
+        let mut server_socket_ptr = server.as_mut().next_pending_connection();
+        let mut server_socket = server_socket_ptr.as_mut().expect("received null socket");
+
+        server_socket
+            .as_mut()
+            .on_text_message_received(|socket, message| {
+                socket.send_text_message(message);
+            })
+            .release();
+        server_socket
+            .as_mut()
+            .on_binary_message_received(|socket, message| {
+                socket.send_binary_message(message);
+            })
+            .release();
+
+        assert!(
+            wait_until(|| client_socket.state() == QAbstractSocketSocketState::ConnectedState),
+            "client failed to connect"
+        );
+
+        client_socket
+            .as_mut()
+            .send_text_message(&QString::from("test message"));
+        client_socket
+            .as_mut()
+            .send_binary_message(&QByteArray::from(&b"binary message"[..]));
+
+        assert!(
+            wait_until(|| {
+                text_received.lock().unwrap().is_some() && binary_received.lock().unwrap().is_some()
+            }),
+            "timed out waiting for echo"
+        );
+
+        assert_eq!(
+            (
+                text_received.lock().unwrap().take(),
+                binary_received.lock().unwrap().take(),
+            ),
+            (
+                Some("test message".to_owned()),
+                Some(b"binary message".to_vec()),
+            )
+        );
+    });
+}
+
+fn wait_until<F>(mut condition: F) -> bool
+where
+    F: FnMut() -> bool,
+{
+    let now = Instant::now();
+    let mut event_loop_ptr = QEventLoop::new();
+    let mut event_loop = event_loop_ptr.pin_mut();
+    loop {
+        event_loop.as_mut().process_all_events();
+        if condition() {
+            return true;
+        }
+        if now.elapsed() > TIMEOUT {
+            return false;
+        }
+    }
+}